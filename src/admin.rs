@@ -0,0 +1,126 @@
+use std::pin::pin;
+
+use futures_util::TryStreamExt;
+
+use crate::{
+    employees::EmployeeDirectory,
+    seatalk_api::{
+        api::{
+            get_group_info::GroupInfoResponse, ApiError, GetGroupInfo, GetJoinedGroupChats,
+            GroupInfo, Markdown,
+        },
+        paged,
+        query::AsyncQuery,
+        seatalk::{AsyncSeatalk, RestError},
+    },
+    webhook::WebhookError,
+    whitelist::Whitelist,
+};
+
+/// Page size used to list the groups the bot is in.
+const GROUPS_PAGE_SIZE: u32 = 50;
+
+/// A group the bot is a member of.
+#[derive(Debug, Clone)]
+pub struct JoinedGroup {
+    pub group_id: String,
+    pub info: GroupInfo,
+}
+
+/// Lists every group the bot is a member of, with its name.
+pub async fn joined_groups(
+    seatalk: &AsyncSeatalk,
+) -> Result<Vec<JoinedGroup>, ApiError<RestError>> {
+    let mut groups = Vec::new();
+    let endpoint = paged(GetJoinedGroupChats::new().page_size(GROUPS_PAGE_SIZE));
+    let mut group_ids = pin!(endpoint.stream(seatalk));
+    while let Some(group_id) = group_ids.try_next().await? {
+        let rsp: GroupInfoResponse = GetGroupInfo::new(&group_id).query_async(seatalk).await?;
+        groups.push(JoinedGroup {
+            group_id,
+            info: rsp.group,
+        });
+    }
+    Ok(groups)
+}
+
+/// Changes whether the group named `group` is whitelisted, `group` is the group name or id.
+pub async fn set_whitelisted(
+    seatalk: &AsyncSeatalk,
+    whitelist: &Whitelist,
+    employees: &EmployeeDirectory,
+    employee_code: &str,
+    group: &str,
+    whitelisted: bool,
+) -> Result<Markdown, WebhookError> {
+    let groups = joined_groups(seatalk).await?;
+    let matches: Vec<_> = groups
+        .iter()
+        .filter(|joined| {
+            joined.group_id == group || joined.info.group_name.eq_ignore_ascii_case(group)
+        })
+        .collect();
+    Ok(match matches.as_slice() {
+        [] => Markdown::new()
+            .text("I'm not in a group named ")
+            .bold(group),
+        [joined] if whitelisted => {
+            whitelist.add(&joined.group_id).await?;
+            tracing::info!(
+                "{} whitelisted group {} ({})",
+                employees.describe(seatalk, employee_code).await,
+                joined.info.group_name,
+                joined.group_id
+            );
+            Markdown::new()
+                .text("Whitelisted ")
+                .bold(&joined.info.group_name)
+        }
+        [joined] => {
+            whitelist.remove(&joined.group_id).await?;
+            tracing::info!(
+                "{} removed group {} ({}) from the whitelist",
+                employees.describe(seatalk, employee_code).await,
+                joined.info.group_name,
+                joined.group_id
+            );
+            Markdown::new()
+                .text("Removed ")
+                .bold(&joined.info.group_name)
+                .text(" from the whitelist")
+        }
+        several => several.iter().fold(
+            Markdown::new()
+                .text("Several groups are named ")
+                .bold(group)
+                .text(", use the group id instead:"),
+            |reply, joined| reply.newline().text("- ").code(&joined.group_id),
+        ),
+    })
+}
+
+/// Lists the groups the bot is in and whether they are whitelisted.
+pub async fn list_groups(
+    seatalk: &AsyncSeatalk,
+    whitelist: &Whitelist,
+) -> Result<Markdown, WebhookError> {
+    let groups = joined_groups(seatalk).await?;
+    if groups.is_empty() {
+        return Ok(Markdown::new().text("I'm not in any group"));
+    }
+    Ok(groups
+        .iter()
+        .fold(Markdown::new().text("Groups I'm in:"), |reply, group| {
+            let reply = reply
+                .newline()
+                .text("- ")
+                .bold(&group.info.group_name)
+                .text(" ")
+                .code(&group.group_id);
+            if whitelist.contains(&group.group_id) {
+                reply.text(" (whitelisted)")
+            } else {
+                reply
+            }
+        }))
+}
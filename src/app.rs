@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use axum::{extract::FromRef, routing::post, Router};
+use tower_http::trace::TraceLayer;
+
+use crate::{
+    dedupe::Dedupe,
+    employees::EmployeeDirectory,
+    seatalk_api::{seatalk::AsyncSeatalk, webhooks::WebhookVerifier},
+    telegram::TelegramStickerDownloader,
+    webhook::{message_received, Conversions},
+    whitelist::Whitelist,
+};
+
+#[derive(Debug, Clone)]
+pub struct AppState {
+    pub telegram: Arc<TelegramStickerDownloader>,
+    pub seatalk: Arc<AsyncSeatalk>,
+    pub verifier: WebhookVerifier,
+    pub conversions: Conversions,
+    pub whitelist: Whitelist,
+    pub employees: EmployeeDirectory,
+    pub dedupe: Dedupe,
+}
+
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/", post(message_received))
+        .with_state(state)
+        .layer(TraceLayer::new_for_http())
+}
+
+impl FromRef<AppState> for Arc<TelegramStickerDownloader> {
+    fn from_ref(input: &AppState) -> Self {
+        input.telegram.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<AsyncSeatalk> {
+    fn from_ref(input: &AppState) -> Self {
+        input.seatalk.clone()
+    }
+}
+
+impl FromRef<AppState> for WebhookVerifier {
+    fn from_ref(input: &AppState) -> Self {
+        input.verifier.clone()
+    }
+}
+
+impl FromRef<AppState> for Conversions {
+    fn from_ref(input: &AppState) -> Self {
+        input.conversions.clone()
+    }
+}
+
+impl FromRef<AppState> for Whitelist {
+    fn from_ref(input: &AppState) -> Self {
+        input.whitelist.clone()
+    }
+}
+
+impl FromRef<AppState> for EmployeeDirectory {
+    fn from_ref(input: &AppState) -> Self {
+        input.employees.clone()
+    }
+}
+
+impl FromRef<AppState> for Dedupe {
+    fn from_ref(input: &AppState) -> Self {
+        input.dedupe.clone()
+    }
+}
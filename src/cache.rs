@@ -0,0 +1,119 @@
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    fmt,
+    hash::Hash,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// A map whose entries expire `ttl` after they were inserted.
+///
+/// Clones share the same entries. Expired entries are dropped when they are read and when new
+/// entries are inserted.
+pub struct TtlCache<K, V> {
+    ttl: Duration,
+    entries: Arc<Mutex<HashMap<K, (Instant, V)>>>,
+}
+
+impl<K, V> TtlCache<K, V>
+where
+    K: Eq + Hash,
+    V: Clone,
+{
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Default::default(),
+        }
+    }
+
+    /// Value of `key`, `None` if it is missing or expired.
+    pub fn get<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(key) {
+            Some((inserted, value)) if inserted.elapsed() < self.ttl => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, (inserted, _)| inserted.elapsed() < self.ttl);
+        entries.insert(key, (Instant::now(), value));
+    }
+
+    /// Inserts `value` unless `key` has a value that didn't expire yet, returns whether it was
+    /// inserted.
+    pub fn insert_new(&self, key: K, value: V) -> bool {
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, (inserted, _)| inserted.elapsed() < self.ttl);
+        if entries.contains_key(&key) {
+            return false;
+        }
+        entries.insert(key, (Instant::now(), value));
+        true
+    }
+
+    pub fn remove<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.entries
+            .lock()
+            .unwrap()
+            .remove(key)
+            .map(|(_, value)| value)
+    }
+}
+
+impl<K, V> Clone for TtlCache<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            ttl: self.ttl,
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl<K, V> fmt::Debug for TtlCache<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TtlCache")
+            .field("ttl", &self.ttl)
+            .field("len", &self.entries.lock().unwrap().len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expires_entries() {
+        let cache = TtlCache::new(Duration::from_secs(60));
+        cache.insert("a".to_owned(), 1);
+        assert_eq!(cache.get("a"), Some(1));
+        assert_eq!(cache.clone().get("a"), Some(1));
+        assert_eq!(cache.remove("a"), Some(1));
+        assert_eq!(cache.get("a"), None);
+
+        assert!(cache.insert_new("a".to_owned(), 2));
+        assert!(!cache.insert_new("a".to_owned(), 3));
+        assert_eq!(cache.get("a"), Some(2));
+
+        let cache = TtlCache::new(Duration::ZERO);
+        cache.insert("a".to_owned(), 1);
+        assert_eq!(cache.get("a"), None);
+        assert!(cache.insert_new("a".to_owned(), 1));
+    }
+}
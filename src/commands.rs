@@ -0,0 +1,371 @@
+use regex::Regex;
+use thiserror::Error;
+
+use crate::seatalk_api::api::content::TextContent;
+
+/// Where a command is sent from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chat {
+    /// A mention of the bot in a group.
+    Group,
+    /// A message sent to the bot directly.
+    Direct,
+}
+
+/// A command the bot understands, e.g. `/convert <sticker set URL> [zip]`.
+#[derive(Debug)]
+pub struct Command<T> {
+    /// Name of the command, with its leading `/`.
+    pub name: &'static str,
+    /// Arguments of the command, as shown in its usage.
+    pub args: &'static str,
+    pub help: &'static str,
+    /// Chats the command can be used in.
+    pub chats: &'static [Chat],
+    /// Whether only admins can use the command.
+    pub admin_only: bool,
+    /// Parses the words following the name, errors are shown to the sender.
+    pub parse: fn(&[String]) -> Result<T, String>,
+}
+
+impl<T> Command<T> {
+    /// Name and arguments of the command.
+    pub fn usage(&self) -> String {
+        if self.args.is_empty() {
+            self.name.to_owned()
+        } else {
+            format!("{} {}", self.name, self.args)
+        }
+    }
+
+    fn is_available(&self, chat: Chat, is_admin: bool) -> bool {
+        self.chats.contains(&chat) && (is_admin || !self.admin_only)
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum RouteError {
+    /// The message doesn't start with a command.
+    #[error("Not a command")]
+    NotACommand,
+    /// The command doesn't exist, or can't be used by the sender in this chat.
+    #[error("Unknown command {0}")]
+    UnknownCommand(String),
+    #[error("{reason}")]
+    InvalidArguments {
+        usage: String,
+        help: &'static str,
+        reason: String,
+    },
+}
+
+/// Commands registered by name, shared by group mentions and direct messages.
+#[derive(Debug)]
+pub struct CommandRouter<T> {
+    commands: Vec<Command<T>>,
+}
+
+impl<T> Default for CommandRouter<T> {
+    fn default() -> Self {
+        Self {
+            commands: Vec::new(),
+        }
+    }
+}
+
+impl<T> CommandRouter<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn command(mut self, command: Command<T>) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// The command named `name`.
+    pub fn get(&self, name: &str) -> Option<&Command<T>> {
+        self.commands.iter().find(|command| command.name == name)
+    }
+
+    /// Commands the sender can use in any chat, in registration order.
+    pub fn all(&self, is_admin: bool) -> impl Iterator<Item = &Command<T>> {
+        self.commands
+            .iter()
+            .filter(move |command| is_admin || !command.admin_only)
+    }
+
+    /// Commands the sender can use in `chat`, in registration order.
+    pub fn commands(&self, chat: Chat, is_admin: bool) -> impl Iterator<Item = &Command<T>> {
+        self.commands
+            .iter()
+            .filter(move |command| command.is_available(chat, is_admin))
+    }
+
+    /// Parses the command in `words`, as returned by [`tokenize`].
+    pub fn route(&self, chat: Chat, is_admin: bool, words: &[String]) -> Result<T, RouteError> {
+        let Some((name, args)) = words
+            .split_first()
+            .filter(|(name, _)| name.starts_with('/'))
+        else {
+            return Err(RouteError::NotACommand);
+        };
+        let command = self
+            .commands(chat, is_admin)
+            .find(|command| command.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| RouteError::UnknownCommand(name.clone()))?;
+        (command.parse)(args).map_err(|reason| RouteError::InvalidArguments {
+            usage: command.usage(),
+            help: command.help,
+            reason,
+        })
+    }
+}
+
+/// Splits a text message into words, leaving out the users it mentions.
+///
+/// Mentions missing from `mentioned_list` at the start of the message are left out as well.
+pub fn tokenize(text: &TextContent) -> Vec<String> {
+    let text = text
+        .mentioned_list
+        .iter()
+        .fold(text.text().to_owned(), |text, mention| {
+            text.replace(&format!("@{}", mention.username), " ")
+        });
+    text.split_whitespace()
+        .skip_while(|word| word.starts_with('@'))
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Commands of the bot.
+#[derive(Debug, PartialEq)]
+pub enum BotCommand {
+    /// Converts the sticker set, or the one linked in the quoted message if `set_name` is not set.
+    Convert {
+        set_name: Option<String>,
+        zip: bool,
+    },
+    /// Lists the groups the bot is in.
+    Groups,
+    Whitelist {
+        group: String,
+    },
+    Unwhitelist {
+        group: String,
+    },
+    /// Lists the commands the sender can use.
+    Help,
+    /// Lists the sticker formats the bot converts.
+    Formats,
+}
+
+/// Every command of the bot.
+pub fn bot_commands() -> CommandRouter<BotCommand> {
+    CommandRouter::new()
+        .command(Command {
+            name: "/convert",
+            args: "[sticker set URL] [zip]",
+            help: "Converts a Telegram sticker set, add zip to get the whole set as one zip \
+                   file. Quote a message with the URL instead of adding it.",
+            chats: &[Chat::Group],
+            admin_only: false,
+            parse: parse_convert,
+        })
+        .command(Command {
+            name: "/groups",
+            args: "",
+            help: "Lists the groups I'm in and whether they are whitelisted.",
+            chats: &[Chat::Direct],
+            admin_only: true,
+            parse: |_| Ok(BotCommand::Groups),
+        })
+        .command(Command {
+            name: "/whitelist",
+            args: "<group name or id>",
+            help: "Lets a group convert stickers.",
+            chats: &[Chat::Direct],
+            admin_only: true,
+            parse: |args| {
+                Ok(BotCommand::Whitelist {
+                    group: group(args)?,
+                })
+            },
+        })
+        .command(Command {
+            name: "/unwhitelist",
+            args: "<group name or id>",
+            help: "Stops converting stickers in a group.",
+            chats: &[Chat::Direct],
+            admin_only: true,
+            parse: |args| {
+                Ok(BotCommand::Unwhitelist {
+                    group: group(args)?,
+                })
+            },
+        })
+        .command(Command {
+            name: "/help",
+            args: "",
+            help: "Lists the commands you can use.",
+            chats: &[Chat::Group, Chat::Direct],
+            admin_only: false,
+            parse: |_| Ok(BotCommand::Help),
+        })
+        .command(Command {
+            name: "/formats",
+            args: "",
+            help: "Lists the sticker formats I convert.",
+            chats: &[Chat::Group, Chat::Direct],
+            admin_only: false,
+            parse: |_| Ok(BotCommand::Formats),
+        })
+}
+
+fn parse_convert(args: &[String]) -> Result<BotCommand, String> {
+    let (set_name, zip) = match args {
+        [] => (None, false),
+        [zip] if zip == "zip" => (None, true),
+        [url] => (Some(url), false),
+        [url, zip] if zip == "zip" => (Some(url), true),
+        _ => return Err("Invalid Telegram sticker set URL".into()),
+    };
+    let set_name = set_name
+        .map(|url| sticker_set_name(url).ok_or("Invalid Telegram sticker set URL"))
+        .transpose()?;
+    Ok(BotCommand::Convert { set_name, zip })
+}
+
+fn group(args: &[String]) -> Result<String, String> {
+    if args.is_empty() {
+        return Err("Missing group name".into());
+    }
+    Ok(args.join(" "))
+}
+
+/// Name of the sticker set at `url`, e.g. `https://t.me/addstickers/<name>`.
+pub fn sticker_set_name(url: &str) -> Option<String> {
+    let re = Regex::new(r"^https://t\.me/(?:addstickers|addemoji)/([^/\s]+)/?$").unwrap();
+    Some(re.captures(url)?.get(1)?.as_str().to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::seatalk_api::api::content::Mention;
+
+    fn words(plain_text: &str, mentioned: &[&str]) -> Vec<String> {
+        tokenize(&TextContent {
+            content: None,
+            plain_text: Some(plain_text.into()),
+            mentioned_list: mentioned
+                .iter()
+                .map(|username| Mention {
+                    username: (*username).into(),
+                    seatalk_id: "1".into(),
+                })
+                .collect(),
+        })
+    }
+
+    #[test]
+    fn tokenizes_without_mentions() {
+        assert_eq!(
+            words(
+                "@Sticker Bot /convert  zip @Alice",
+                &["Sticker Bot", "Alice"]
+            ),
+            ["/convert", "zip"]
+        );
+        assert_eq!(words("@Stickers /convert", &[]), ["/convert"]);
+        assert!(words("@Stickers", &["Stickers"]).is_empty());
+    }
+
+    #[test]
+    fn routes_commands() {
+        let router = bot_commands();
+        let route = |chat, is_admin, text| router.route(chat, is_admin, &words(text, &[]));
+
+        assert_eq!(
+            route(
+                Chat::Group,
+                false,
+                "@Stickers /convert https://t.me/addstickers/Cats"
+            ),
+            Ok(BotCommand::Convert {
+                set_name: Some("Cats".into()),
+                zip: false
+            })
+        );
+        assert_eq!(
+            route(
+                Chat::Group,
+                false,
+                "@Stickers /convert https://t.me/addemoji/Cats/ zip"
+            ),
+            Ok(BotCommand::Convert {
+                set_name: Some("Cats".into()),
+                zip: true
+            })
+        );
+        assert_eq!(
+            route(Chat::Group, false, "@Stickers /convert zip"),
+            Ok(BotCommand::Convert {
+                set_name: None,
+                zip: true
+            })
+        );
+        assert_eq!(
+            route(Chat::Group, false, "@Stickers /convert https://t.me/"),
+            Err(RouteError::InvalidArguments {
+                usage: "/convert [sticker set URL] [zip]".into(),
+                help: router.get("/convert").unwrap().help,
+                reason: "Invalid Telegram sticker set URL".into()
+            })
+        );
+        assert_eq!(
+            route(
+                Chat::Group,
+                false,
+                "@Stickers https://t.me/addstickers/Cats"
+            ),
+            Err(RouteError::NotACommand)
+        );
+        assert_eq!(
+            route(Chat::Direct, true, "/whitelist Stickers Lounge"),
+            Ok(BotCommand::Whitelist {
+                group: "Stickers Lounge".into()
+            })
+        );
+        assert!(matches!(
+            route(Chat::Direct, true, "/unwhitelist"),
+            Err(RouteError::InvalidArguments { .. })
+        ));
+        assert_eq!(
+            route(Chat::Direct, false, "/groups"),
+            Err(RouteError::UnknownCommand("/groups".into()))
+        );
+        assert_eq!(route(Chat::Direct, false, "/help"), Ok(BotCommand::Help));
+        assert_eq!(
+            route(Chat::Group, false, "@Stickers /FORMATS"),
+            Ok(BotCommand::Formats)
+        );
+        assert_eq!(
+            route(Chat::Group, true, "@Stickers /groups"),
+            Err(RouteError::UnknownCommand("/groups".into()))
+        );
+    }
+
+    #[test]
+    fn parses_sticker_set_urls() {
+        assert_eq!(
+            sticker_set_name("https://t.me/addstickers/Cats/").as_deref(),
+            Some("Cats")
+        );
+        assert_eq!(sticker_set_name("https://t.me/addstickers/"), None);
+        assert_eq!(
+            sticker_set_name("https://example.com/addstickers/Cats"),
+            None
+        );
+    }
+}
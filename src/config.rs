@@ -1,15 +1,91 @@
+use std::{path::PathBuf, time::Duration};
+
 use config::{Config, ConfigError, Environment, File};
 use serde::Deserialize;
+use url::Url;
+
+use crate::{
+    dedupe,
+    employees::DEFAULT_TTL,
+    seatalk_api::{
+        rate_limit::{RateLimit, RateLimits},
+        retry::RetryPolicy,
+    },
+};
 
 #[derive(Debug, Deserialize)]
 pub struct AppConfig {
     pub telegram: TelegramConfig,
     pub seatalk: SeatalkConfig,
+    #[serde(default)]
+    pub whitelist: WhitelistConfig,
+    #[serde(default)]
+    pub employees: EmployeesConfig,
+    #[serde(default)]
+    pub dedupe: DedupeConfig,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct DedupeConfig {
+    /// How long event ids are remembered to recognize redelivered events.
+    pub ttl_secs: u64,
+    /// File event ids are stored in, they are kept in memory if unset.
+    pub file: Option<PathBuf>,
+}
+
+impl Default for DedupeConfig {
+    fn default() -> Self {
+        Self {
+            ttl_secs: dedupe::DEFAULT_TTL.as_secs(),
+            file: None,
+        }
+    }
+}
+
+impl DedupeConfig {
+    pub fn ttl(&self) -> Duration {
+        Duration::from_secs(self.ttl_secs)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct EmployeesConfig {
+    /// How long looked up employee profiles are kept.
+    pub cache_ttl_secs: u64,
+}
+
+impl Default for EmployeesConfig {
+    fn default() -> Self {
+        Self {
+            cache_ttl_secs: DEFAULT_TTL.as_secs(),
+        }
+    }
+}
+
+impl EmployeesConfig {
+    pub fn cache_ttl(&self) -> Duration {
+        Duration::from_secs(self.cache_ttl_secs)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct WhitelistConfig {
+    /// Groups stickers are converted in, until the whitelist file is created.
+    pub groups: Vec<String>,
+    /// Employee codes allowed to change the whitelisted groups.
+    pub admins: Vec<String>,
+    /// File the groups changed at runtime are stored in, it replaces `groups` once it exists.
+    pub file: Option<PathBuf>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct TelegramConfig {
     pub api_token: String,
+    /// Bot API base URL, defaults to api.telegram.org.
+    pub api_url: Option<Url>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -17,6 +93,157 @@ pub struct SeatalkConfig {
     pub host: String,
     pub app_id: String,
     pub app_secret: String,
+    /// Secret webhook events are signed with, set with `APP_SEATALK__SIGNING_SECRET`.
+    ///
+    /// Empty if unset, the app refuses to start without it.
+    #[serde(default)]
+    pub signing_secret: String,
+    #[serde(default)]
+    pub retry: RetryConfig,
+    #[serde(default)]
+    pub rate_limits: RateLimitConfig,
+    /// Largest difference between the timestamp of a webhook event and now that is accepted,
+    /// events are not checked if unset.
+    pub max_event_skew_secs: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct RetryConfig {
+    pub max_retries: u32,
+    pub min_delay_ms: u64,
+    pub max_delay_ms: u64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        let policy = RetryPolicy::default();
+        Self {
+            max_retries: policy.max_retries,
+            min_delay_ms: policy.min_delay.as_millis() as u64,
+            max_delay_ms: policy.max_delay.as_millis() as u64,
+        }
+    }
+}
+
+impl RetryConfig {
+    pub fn policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+            .with_max_retries(self.max_retries)
+            .with_delay(
+                Duration::from_millis(self.min_delay_ms),
+                Duration::from_millis(self.max_delay_ms),
+            )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct RateLimitSetting {
+    pub requests: u32,
+    pub period_ms: u64,
+}
+
+impl From<RateLimitSetting> for RateLimit {
+    fn from(setting: RateLimitSetting) -> Self {
+        RateLimit::new(setting.requests, Duration::from_millis(setting.period_ms))
+    }
+}
+
+/// Rate limits for SeaTalk requests, endpoints without a limit share the `default` limit.
+#[derive(Debug, Deserialize)]
+pub struct RateLimitConfig {
+    pub default: RateLimitSetting,
+    pub auth: Option<RateLimitSetting>,
+    pub group_chat: Option<RateLimitSetting>,
+    pub single_chat: Option<RateLimitSetting>,
+    pub group_info: Option<RateLimitSetting>,
+    /// Limit for edits of interactive messages.
+    pub update: Option<RateLimitSetting>,
+    pub recall: Option<RateLimitSetting>,
+    /// Limit for employee lookups, shared by both contacts endpoints.
+    pub contacts: Option<RateLimitSetting>,
+    /// Limit for reading messages and threads, shared by both endpoints.
+    pub history: Option<RateLimitSetting>,
+    /// Limit for downloads of received files.
+    pub file: Option<RateLimitSetting>,
+    /// Limit for listing joined groups and their members, shared by both endpoints.
+    pub group_list: Option<RateLimitSetting>,
+    /// Limit for messages sent to the same group.
+    pub per_group: Option<RateLimitSetting>,
+    /// Limit for messages sent to the same subscriber.
+    pub per_subscriber: Option<RateLimitSetting>,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            default: RateLimitSetting {
+                requests: 1,
+                period_ms: 2000,
+            },
+            auth: None,
+            group_chat: None,
+            single_chat: None,
+            group_info: None,
+            update: None,
+            recall: None,
+            contacts: None,
+            history: None,
+            file: None,
+            group_list: None,
+            per_group: None,
+            per_subscriber: None,
+        }
+    }
+}
+
+impl RateLimitConfig {
+    pub fn limits(&self) -> RateLimits {
+        let mut limits = RateLimits::new(self.default.into());
+        for (endpoints, setting) in [
+            (&["auth/app_access_token"][..], self.auth),
+            (&["messaging/v2/group_chat"], self.group_chat),
+            (&["messaging/v2/single_chat"], self.single_chat),
+            (&["messaging/v2/group_chat/info"], self.group_info),
+            (&["messaging/v2/update"], self.update),
+            (&["messaging/v2/recall"], self.recall),
+            (
+                &[
+                    "contacts/v2/profile",
+                    "contacts/v2/get_employee_code_with_seatalk_id",
+                ],
+                self.contacts,
+            ),
+            (
+                &[
+                    "messaging/v2/get_message_by_message_id",
+                    "messaging/v2/group_chat/get_thread_by_thread_id",
+                ],
+                self.history,
+            ),
+            (&["messaging/v2/file"], self.file),
+            (
+                &[
+                    "messaging/v2/group_chat/joined",
+                    "messaging/v2/group_chat/members",
+                ],
+                self.group_list,
+            ),
+        ] {
+            if let Some(setting) = setting {
+                limits = limits.endpoints(endpoints.iter().copied(), setting.into());
+            }
+        }
+        for (endpoint, setting) in [
+            ("messaging/v2/group_chat", self.per_group),
+            ("messaging/v2/single_chat", self.per_subscriber),
+        ] {
+            if let Some(setting) = setting {
+                limits = limits.per_target(endpoint, setting.into());
+            }
+        }
+        limits
+    }
 }
 
 impl AppConfig {
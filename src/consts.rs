@@ -1,2 +1 @@
-pub const WHITELIST_GROUP_IDS: [&str; 2] = ["ODI2OTIxNTk5OTQ0", "MDAzNTgzMDc0NDk1"];
 pub const GROUP_INV: &str = "/9j/4AAQSkZJRgABAQAAAQABAAD/2wBDAAMCAgICAgMCAgIDAwMDBAYEBAQEBAgGBgUGCQgKCgkICQkKDA8MCgsOCwkJDRENDg8QEBEQCgwSExIQEw8QEBD/2wBDAQMDAwQDBAgEBAgQCwkLEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBD/wAARCAGdASwDAREAAhEBAxEB/8QAHgABAAICAwEBAQAAAAAAAAAAAAgJBgcBBAUDAgr/xABrEAAABQMDAgMDBgoFBgkDDREBAgMEBQYHEQAIEhMhCRQxIkFRFRYyYXGRGSM4U1Z2gZS01BcYJDNSOUJ1lqHTJSY0VFeXstHScrGzJyk2Q1VjZGZzlaS18DdERUZiZXSCg4SSoqOmwcTj/8QAGwEBAQEAAwEBAAAAAAAAAAAAAAECAwQFBgf/xAA1EQEAAgIBAwICBwcEAwAAAAAAARECAwQFITESQRNRBiJhcYGx0QcUMjRSkaEzYnLBFSPw/9oADAMBAAIRAxEAPwCEWtsGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGg7UVGPpuUZw0YgKzx+4Tat0wHHNQ5gKUPvENY2bMdWE7M/ERc/guMTlMRCRqGyWdTUK2lq2QQX45OCLETkyA4MBRMoUTYH6tfIx9LPXMzr0zMfbPf8AJ6f/AI2vOXf7m04vwuJOdjm7+IvO1EVCFMqk5gjpimIhnGQVEDfs17nE6hnzMPXrwr5xMz+jp7NOOuamXuJ+Eu8FMgq30SKoIByAlOiYoD78D5gMh+zXpRlNeHF6Y9n4eeExIJtjHZXxRWcBjCalPimUf/1gXEQ+7ScsquI7nphr+4Hh5O6AjSqObqBISQhzO1bQR+kmX61RV7iI+gAH24153J6jlxo767n7L/RzYcf1zVtXyG1SoWSgNwngKsqTmgDhkZMqnx78hEA+vAhroZfSD4c/+zVMR9/f8nN+5X/Dk0k+ZuY564j3iYprtVToqkH/ADTlEQEPvDX0GGcbMYzx8T3dHKJxmpfHW0NA0DQNA0DQNA0DQNA0DQNA0DQNA0DQNA0DQNA0DQNA0DQNA0GYWcKJruUSUvqNQx4B+8E10uoxfD2x/ty/Jy6P9XH74XD0ZRjlzKlcNajTaFU7qtSIdYTgHqIlMUSB9o+mdfFdB4GU7LjbX2R3evzN0RjXpblTSAhCkKUAAvYOwB2/Zr73HGMfDxp7vyJkgVFMTAGAyPf6s/8Am760NZ1LfSmqTuCFFTp026BkCG82JuxDm9AH6vr9w68nf1LHjcn4WfivL19HStnJ43xtfeWevEyz8QYsdLrtiLk5JumnE44+JcgID/8AbGu/lHxsLifPvDypideUxMd0Wq8tu2ayLlwnVYzbk4iPM7ZQqxTZ/wA8xu2f26+P6hpw1ZT9f1T93d6mjOZx7RSr65qfRuPVSWMcJp6XHwwsbX1/A/ldf/GPyeVt77MvvY1rtuM0DQNA0DQNA0DQNA0DQNA0DQNA0DQNA0DQNA0DQNA0DQNA0DQZzYlPq3tt+kAAPOp4wvf63JNdXnTXF2T/ALZ/Jy6P9XH74/NeDSvmWCY+cFNuU+QTTBMqfIPebABkftHXk9Cw2Rq9eyIi/EVX4uxyssZyqJtrW5V+qqpm9dIWogqUB4hUC4JOXXZQ6JDGKAKgQDAPEAE58YEOCJxMYnYNfSfBidU53+T5zb1Pbh1DHh44dp8z3uqnvHaqjxNzffs9Fn87IKsywM+icFlklXycsmoY7Z+kXPXLxH2kVRAU8h3AoAXjkPXoeqdfl9NhGG3Ht5aZr2xb24zabvA0mHi8THxCz1sRiXquJlZJMwh0iiGCFNxLkwchMbkBSD6j093TNXM2/Ezmnp49dz6XonVowjKYif7/ACbS2fV7IVbaJkEggcq0Zya5OomZTBTmAnLpmMXIkApiiA9yHIIgUchr1f3THh446sJuPw/6+b5Lh9Y29bjLk7dcYzddrqe3evVU3HiftZfcFt544OywzJ2iqAGTcC1A5wD4cyiAiIfAc6+c6zlOEX6In7ae1xe816lKl5k+jd+uEuPHhUUiXGMYw4Pr2+F/K67/AKY/J093bZlH2sO12nGzWjbM3Jr2JNP05TxDRZXAtCvnr9sxbqrgACKSajhQgKHABDJS5EMhnGdRXkylAVtCys5CS1LSLV/TSAupduojg7JEDEL1VPcBMqJgBgyA8y4EQHVHSnqdlqacNWsygmio8Yt5JECLpqgLdcnNIwiQRAoiUe5RwYvoIAOiP1F0xOTMPNT8ay6zCnkUHEkr1Cl6CaywIpjgRybKhgD2QHGcj276Dy/j9Xr9WgaB/wD59NB3IWHkKhmWEBEpEVfSblJm2IdUiRTKqGApAE5xApQERDuYQAPURANB13bZVk6XZOQKVZsqdFQAMBgA5TCUQAQ7D3Ae4dh92g7KULLrw7qoUY1weMZOEWjl2BPxSSyoGFNMw+4xgTOIB7+I6DpaB6+mgevpoO3EREpUEqzg4Rgs+kJBcjZq2QLyUWVMOCkKHvMI9gDQdVQh0lDpKFEp0zCQxR9QEBwIfeGg40D19NBx2xnPbQO3x0HOgaBoGgaBoGgaBoGg2Bt7KY1+rblKXkYasiQAPiPmk9dXm3PG2V59M/k3rmIzi/muPuDLIUg2WqicXK3aswKZYU8qARPl35AHvERDt8PXXk9A2+nHKNs+fMzPefs+6He3Vs7Yw+lMzEbPruK3JKGTGQfmbxzluPtqNxxwSEMDzATcjYEO2RHtgR16G/dOOf1fDl/do+HETDXe5WtWQ00d/DXxi4WQpx+m3cCJSimio5IcgJLqpFMKZzEA/Eg45ABsgPbHHnsz241TfE4+OG6Jzi4p7Nvq4ZzFoSQ9sbyQ9SP6fjk0QUj0W6Z0yplDsYhgHgGAwBhKHoAj3zqzv2YxRr4euN0RuifTMsNpW5DCg4qlHVTSjlGRqFFdeScHDqLKrA7BIDqY7GPk+BH4FAPdrm4O/Gccsds+Xc6xxdevOcdMfVjx/a0ik2qzlIiyooCCuBMZDIFOPxx/t18t1Pk547csYuPnH6PK0xER3UfbgidK/Fxk/wDDVcqH/wBKU19bw/5bX/xj8nS2fxywDXZYbvCWhG9nKEhr12ykJWmjDJr0xPwEwVu5QIo4w6brJqJqIHOCpeXA/E4FMHfiIanurYxjzdvE7kdGozVIykbQR0xFq1NCtzSLNl5psi3aOEzlMBemmY2CgIlMHSPjJS4Dy5inaXpGnpu4sRRMDJS1P0HRCjVk8jyOGiB36Qg7klW/0VTgJCF5KAJQMsBjZHGhLsUOhR89T9eTV0qUUtzT01SFNyMmMI0yEgkEwBRdtG3o3BfhgC/RKJTHAMCAaSQ7Y0ZTNPVFdap6piKOhzUeWGaU0UlOqS8S3iXZlBRkQbJ+06FRIqQA4W5AB1jCfvxAA+CkXa4ktJVizouLkzHtTIzzpurTq0XFu36TxMiDxq2VNzTTOUA5AQQKIlUAuCmHUux5ttJOlJKnINxLUtRMVUdW1A8FQ1TUcotEVCkKiaabNk5bFN8ngmYTEMCaYCBjgbl2xqyNV2+pgjPcZTlGVJCNCkb1s1i38acwOUAAr4qaiAiOQVIAAJcjnkHr66SQz+UqGHtpaqKmoW3tHP5aQrupmKjuXhUXvFi3VbiVuUhw4gX28AbHIpQECCXkOnmT2ZfWjSIpmn7xWxhmMPEU2Nz4KKTMaPQUOyauyLioJFlCicBTD+7MJsphyAogBjZg5ufB2lbnunb1KMhQQo6Mfmim0RQjxtJxC7cxSoOHUkY2Fk1BwCh1MkP1QMmAezqwS/RYCgJq9rK3zqiqbZQ8HRTeqjpJxBllZWTJCJLAVYERBZVHJhOLZIS8+mYe5jCOkdh5hWVqqwCg6mdUinWTgKuMxeBSNErwraWjiNTLKNhRMYCLLpGKU+E+JjJGMUw5wOg9qiDtnlxLVV/Sze3MvGlrtGBWmISmjxDlMVyAcrRwwVJ0wOQhTHI4TExyjkBMHYdQR0YoNbhXlZsaicM49rP1MizerNG6TVFBJV0VI5iETACJgBREewAGe+r7DdEytHT9LX/hntmaXgk6BjxbQzllDA3dRmJNNAEVVh9pVQ6ZOXUPlT6YgPEwhor2Kkj6PWvXW1Ox9saYVZ2/pZxUkXDpRpQGWkysWomFwJMHWRJ1VFvLhgmEh7dzCJHWtnEU5c5zaqu6koGm2UnKVfK08s3j4lNqym2aUaKxFRalDpidJU3TExCgBslAfaLp4GIWCo+IlqIi5Wdpxo5I5uxS0QDp00Kfmgci4uW/IwdyDkgnJ6D7OQ0Ie1UoxlapXqpSatJTNKtLes3T6GdRsQDN1GroPyIpNV1w9pwDghzBhTIibBiYAMagjSIYEQ1pDQNA0DQNA0DQNBmFm51pS93aIqZ+moo2iahj3yxU8cjETcEMYAz78Brh5GE7NWWEe8TDWM1Npq3735u6thpWkqYpRozYPUTtlVXJuqsYDCA9vQpR7BkcD7teZw+lfC75ZXLl+P6crxc29laiuXtJqYtMTEpHTdKOF5eKVarGSVVaCmYq6ACXuJRKVwTt7wLreerPXl9eH0OjPHbjGWPuichYy7MxAuZWOkHj2El1k3oskXSxUVFAAeBzlL7KhigYcG92R79x12ccsYinPl0bdty+L6u0+PsenRFkrtUPXcCnHTTuMkpp6kxY+WcKJqpmUNgxuXbJSk5CPux6hrj2ZY5Q3r6bt40zltyuPxSA3OV03thcSkI+DQM5b05Gt02hHHtAc4K9dTnnsIhzSAQ+P7dcenjTu8/wuh1HkRhj6fdte1m/cFo9BnUtEtgIQpSiswXMUTCHbIpmyAD8cCHfXS5nRs84vXl4+bxMN8TP1leF5ZxvU13q3qNomZNCVqKQepkP9IpVHBzAA/Xgde3xsJ16cMJ8xEQ4cpuZmGH652WXUXd26FumbiOoavZmEaOj9VZs1cfiTqYxz6ZgEoHx25AAD9eor4Mro3GjqkkqwaVvMFm5hBRrIv1HIqrO0VAADpqifPMo8S9h9OIYxgNKgfqLunceEnWtTQ9bSzOVZRyUQg6RW4nKxSIBE24hjiZMpQAOJgEOwZzjQfCVuNXs6vNuZqr5R+tUiaCMsdwvzM8TQOB0SHEf80hilEoBgAwGO3bQdyIu5c+BdRT2GruYZuISONEMFEVwAUGJjicW3p7aXIRHgfkACPYA0qC3Uf3Ir6VlpedlKwlXkhPMTRkk5XX5qOmhuOUDCPon7BPZDABxDGNKHcpK8F1KDiloOjLgzkNHuFTLnbtHPEgKiGBUKAgPTOIAACYnERwHfQY7FzUtCzLSooqRXbSjF0R63dkNlVNwQ4HKoAjn2gMGcjnvqo+8jVNRS0YhDScy5csWz1zIot1DBwI5cCUV1Q7fSOJCcv8AyQ9NB6ji6NxHfzlBzWMkqFZcfl8pzlEskJfoiqHHAiHuEMCGR799RX2nbvXSqemU6MqK4M7IwaRUyAxcOxMmYqf92U/+ccC/5oHEQL7sY1R5q1c1ivUjSsT1PJBOsCtyNZFNcU3CAIJlTRAhy4EvAhClDHuDHfRHoVBdq51VTUbUdQ19NvZOGN1I10d0JDsz8uXJHhxBMwmABESgAiIdxHUV9agvJdeqpOLmaiuFOP3sIuDuNWUc4FovkB6qYFAClPkoCJschwGRHQeFK1JKVFUa1U1Q4NLvnjkrp6dwPEXRshyA4p8ccgDAiXA98h31UbluFuQY1JQ89ScItXbsKjRQZGRqSYRdtohkmsRYUGwkKCi5jCkkTrLiJwTJx7iIjqUttSmuBXBqvJcAKrkyVKmoRUsqmuKbkpyEBMogcuMYIUC/WAYHPfVR2p+6tyqpqCOquoa6mX0xEcRj3h3IlUZiU3IOjwwCftd/ZAMj3HOisqjNxdy31XUxMXCrObqGIgZ2PmVY86iYFUFssJ8lKBQKCggZQOQ+on9oR90LeNXl6bk1+g6g5yuJ17T5n6rxtGO3YnTTAVDGSA2PpiQpgKHITccezjSqGC6qGgaBoGgaBoGgaDtRKvQlWa2foOEzfcYNawi8ohJ8Pcn3qpVTqpmE6ZxER94hrtxq9MuOMr7N27fLrVJb+j4qrqVWI5JDSTxhNMFP7pw1cCQ5SKh7gHBgKfHsmAPrAejz9U543Hs9bpm6NeU45e7d6MFPlpoLhbf6lhApB6qYXkJNEV6kI7EfbRKZMph4CIgIB7s9slENeR6q7S+r18vOIrHw7M/JK2Hi0rg1/LMKgunLteMCwQSMVlAthH2nAEMAGMYw47mAOQhxAMAYdL9U1Hhxb+Vnn3zlFG/lTu3bulIKUerPJ5qyWeyaqxuSgrOleoHMf8Ql9oQ93IA17+jCteONeHx3K3fH25Zx4ebTU2EagQvPJs5EfiPv1z5aIiKdOJ92Azi/mZp+4/OuVT/eYR10soqac0OlqBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGg5IYSHA4epRyGrjPpmyYuKdgX6igcVM/t1343658y4/TT0KJrWft/ISD6ESbrpv0VG7lo6Dkg5TEBEoGL9QjkB+33Drjy2asomLbxuJi/DYcBdyYoDyzygbky0W6fskRkfIc0eKwB7SZimKJTAURHiPcQAfXXlbePEz9V7OHPxiZiJqHZ/pViF1XNcVXOPanqADlFuzdGUMZZQPoqLqnDHTL7iFyP2a3xuPhhPqzdblc3LZHpw/u1I8kXktLO56XXM5kH6xl3CpvUxjDkfsD3Y+AAGvXx3avMy8yMZfr5UWLgC57amXI1+bPQ6hziocyhvUwiI66GU+qZlyR2cagaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgAIgOQHAhoP31lvzpvv0DrLfnTffoHWW/Om+/QOst+dN9+gdZb86b79A6y350336B1lvzpvv0DrLfnTffoHWW/Om+/QOst+dN9+gdZb86b79A6y350336B1lvzpvv0DrLfnTffoHWW/Om+/QOst+dN9+gdZb86b79A6y350336B1lvzpvv0DrLfnTffoHWW/Om+/QOst+dN9+gdZb86b79A6y350336B1lvzpvv0DrLfnTffoHWW/Om+/QOst+dN9+gdZb86b79A6y350336B1lvzpvv0DrLfnTffoHWW/Om+/QfkyihwwY4iH1joONA0DQNA0DQNA0DQNA0DQNA0DQNA0DQNA0DQNA0DQNA0DQNA0DQNA0DQNA0DQNA0DQNA0DQcD2DPw0EtKG8NO+lf0fC1pEVVQ6DOdjm0m3ScvHRViIrpgoTmBUBDODBnAiGchntqWtPd/BSbhf0yt9+/PP5bSyj8FJuF/TK33788/ltLKPwUm4X9Mrffvzz+W0so/BR7hv0xt9+/PP5bSyj8FHuG/TG33788/ltLKc/go9w36Y2//fnn8tpZTj8FJuF/TK33788/ltLKc/go9w36Y2//AH55/LaWUfgo9w36Y2//AH55/LaWUfgo9w36Y2//AH55/LaWU4/BSbhf0yt9+/PP5bSynP4KPcN+mNv/AN+efy2llOPwUe4b9Mbffvzz+W0so/BR7hv0xt9+/PP5bSynP4KPcN+mNv8A9+efy2llH4KPcN+mNv8A9+efy2llOPwUe4b9Mbffvzz+W0so/BR7hf0xt9+/PP5bSynP4KPcN+mNv/355/LaWUfgo9w36Y2//fnn8tpZR+Cj3Dfpjb/9+efy2llH4KPcN+mNv/355/LaWUfgo9w36Y2//fnn8tpZR+Cj3Dfpjb/9+efy2llH4KPcN+mNv/355/LaWUfgo9w36Y2//fnn8tpZTj8FJuF/TK33788/ltLKPwUm4X9Mrffvzz+W0so/BSbhf0yt9+/PP5bSyj8FHuG/TG33788/ltLKR6v7YGsdulYM6KrWRiHzx9HElEVotVRREUTqKJh3UIQc8kje7GMDnSJsqmtNVDQNA0DQcG+iP2DoLaLy3Mrazfhpw1zLcTXyRUkLSFMeSeg3SXFLqnZpKBwVKYhskOYO5R9QEO4AOsS1CENutzfiy3apwtW26nJeciDqnQK7bU5FCQTl+kXuh7tFZR/Sb40H/N6i/wBWon/caB/Sb40IjgG9Rd//AItRP+40GS7Ht4e764G7ZpZe+VequmrZN8lJRasOxbnIuimI8ROiiUwCBg9w6DYm8CuPEuhb6zMftzSmDUMRBsLEW0JHuCcxSDqYOskY4+1n1HQbr2U1Fu5nbTVa83OEflqtF2qWGBzHNWx+l0AEuCIEKU34zPqA6CvW7e73xRbJlbO7l1bJU80kV1UmR3dORQArxHuAf2cfQBDQW92LqSarGzND1ZUbzzcpMU+wevV+mUnVWURKY5uJQAoZERHAAAaDFd4Ff1ba7bfXNe0LK/Js7DxwrsnXQTW6R+ZQzwUKYo9h94DoIdbSvEYTmNu9XSe4O+MaW4SLt6WGBwybt1OkDYgo4TRTKmb8by9QHPoOQ0ELx8ULe/5gQC9n4vnj/wBj8X9HP/6P8NBcTt23R2ovXEQtPU/cmMn6uLCoPpRqh7KhTgUoKmEoABQ9s3oHbvoIYT2/C61A+IVI2xr+7KEXaiNlFUHLVeNaAmkl5YTEAVgS6394Je/PQdO+PiEXCf7wqKoqwF5Gjy3ks7imrxFrHNViKHUX4rF6qqQqAIlEA7GDHu0FobtQ6bJZUhsGIkYwD8BAojoK6fDs3abgL6bh68oa6de/LUJDMXKzJr8mNG/SOV0UhR5oplMOCiIdxHQa63c7gvEqs7Xlb1NCTMnD21jZUyUa8PAxqiJG5jgVL2zomObIiAZERHQaXsv4nO6x9dqkGdy72phSi0y1JNCpBxyZPJioHVyciAGKHHPcogIaC5S1167XXpj3kra+smFQtGCwIOVWhhEqZxDIFHIfDQRV8UjcdeXbpQ1HTFnaw+QHkrJLN3ankGznqJlTyAYXTOAd/eAaD97j9xl46C8Puj73UrV/kq0lY2FXdyXkWynUOumUyo9I6YpByER9CgAe7Gg9HY3vgpC6FqKQg7t3ejJC6Mw4cN12h0E0F1T9c4JF6aRCkD2AL6AGg3/c/cvY2zE03p651xoqn5F03B0i3dGEDHSEwl5BgPTICH7NBBff14jbunWNHm2k3wjTLrrOgmvKMW7vBAAnSz5hI3Hvy+jjOgl9tr3U2rvTT1O09F3LjZ6tTwbd5KtUQAqoKgmXrGEoABS4OI9g7aDtb0ri1jafbRW1wKAl/kueiGaarN30E1ukYVSFEeChTEHsI+oDoKwbabpPFdvFT56ptpUMtPxSTgzU7prTkUJAVKACJe7f1wIffoMt/pN8aD/m9Rf6tRP+40D+k3xoP+b1F/q1E/7jQers/wB4O8qst5dMWGvzXayzJVWRQmIdaGYNz80mC6xCmOiiU4YOQg9jd8YHICIaDteK3+ULT36ntv4x3rUMyhfqoaBoGgaDg30R+wdBaBuz/wAkul+p9I/xDDWJah4vh5VlJ278Oipq8hUUFn9PFmpNsm4ARSMqimJygcAEBEuQDOBDRUYfw0O5r9DaA/cXX+/0EsfDy35Xa3Y3AqOlbgwVNMWkRFleoni0FkzmOKgFwbmoYMYHQRz2y/5XCr/9NT3/AGT6Da+8fxIL+2G3Gy1mrdUfS8q0aJNBaA6ZOFnSp1UimEv4tUoD3HsABoNLT3i87vaXcJtKltdR8UuqXmRN5EvETGL8QAywZDQZ3ZGonvi1PpOlNwxEoFpQSRH8aelQFudVRcRIcFRXFUBAAIGMAGgsjtq4t7SsFEWppSr2MgrTLBKMTbi9SVdARAgEyoUvfkGO/YO+ggpuD3NV1dHdvL7Cp+Mh0KCqNy2inUg1SULJkSUbkWMJFBOKYG5DgMkHtoPtO+E1swph2VjUl4qoi3JyAoVF5NMUjmJkQAwAZEBxkB7/AFaCGe/va7YfbkSjBsrcB7Upp0XoPwcyLd10el0+njolLxzzN6+uNBtLweqKrCC3ISkxNUtKsWK9KOQScuWZ00jiZZEQ4mEMDkAzoNO79rZ3Fmd21xZKJoWeeNF5Ipkl0I9VRM4dMvcDAXA6DX+3y1NzWV9bfPHlvaiQQRqWOOoopGLFKQoLkERERLgAAPfoP6F6qrGk6YaChUdSxcWo5RU6JXjsiIqYL/mgYQz+zQVe+FNRtWwW6S5EtNUzKMGTuMddBw5aHTSVy8KIcTGDA5Dv20Hhb9tz26C4cncDbj/RImtRyUsCDaQZw7szlRJFUDkMCnISDkS9xAughBaS1StVXyo21ddsZSHSqCbZx7wgpCi5TRWUAomKBw7DgewiAhoL1tvVg7F7JIWTpOCuAsinUDkHxwqGSblUESl4+xgpPZ/ZoIh+MtWtH1VbigkKaqmJlVUZdwZQjN4msYgCl6iBRHAaCF1yN9t1rnbfYfbjOQNNI05CtmTVBy2bqldmK1KBUxMYVBLkQAM4KGg42JUTWLnc1bGpW9KyysSWdSOL4jM4oABREBHqY49h7euglN4s9uakrfdBQoM6cl3cSrBNmzx20anOREou1OYicAEpRAo576DYCXhg7ExQIc9+5opxIBjB84Y/sOPh0tBqHw6aNTsxvOqheZTdxVJsmMmwjpiVL0UHKYLlBIQWMAEMJilyGPX3aCefiBS0ZObKLiSkPINnzNePTFJduqCiZw65A7GDsOg0L4WlRvqP2L1vVkamko7h5SVfIEWARTMdNqmYoGABAcZAM4HQRuP40G5opxL8zaA7CIf8idf7/QSg8PnxArwbrLuy9B1/AUwxYMINSTTUi26yaoqlWTIACJ1DBxwcfd8NBoW13+WeW/03Nf8A1U50GReK5+ULT36nNv4x3rUMyhfqoaBoGgaDg30R+wdBaBuz/wAkul+p9I/xDDWJahg+zD/JY3E/0ZUX8ObRVQQkMPcCj6fDQWOeCgAheWucgIf8X0//AE5dB9dsv+Vvq/8A01Pf+Y+gwzfdcL+iXxIz3LND/KhabXiZDyYqdMF+miQeHPA8c/HA6DcRbVE8XkRvISWLbD5of8X/AJPBuEn5nP43q9TKXH6fHjgfT10HPzZHweB+d4Ov6UP6Qv8Ag/ocPkryXl/b58sq8+XPGMBjGg3ftC2cqU/dom8Y1xDOAr+PWmfm+LEQ8n5/C3T6/UHnw5cc8Azj3aCMlcf5ZZt/ptj/AABNBKzed4dam7S50fcQl3DUuDGITivKBF+Z58FVD8+XVJjPUxjHu9dBW3vf2Rn2cjR6p7kmqz5ymd9hjxa9DodP/wB8Py5dT6sY0EiKI8ZtKjqRhaXLt9TcDER6DEVwnwIKvTIBeWOgOM4zjI6Cybb3dZvfmz9N3YPTycQaomouRZCsC4o4MJcc+IcvT4BoIx7wPEjQ2pXaC2JbOp1EPyci/B78qg2/vBMHHh0jenH1zoK5N6m9lbd3N0nMEt+el/mymsmKZZIXPmOZymzngTjjj9froJPQPjUkgYVhEl27kOLJqk2FUKg48+BQLnHQ7ZxnQbm2t+KGhuVvVDWjNZROCGYTcH8+MuDgU+kkY/0OiXOeOPX36CPO6khS+LHQ/TIAF+Waf+iHb6RPhoOx40KSzm81u2iax0wXiDp8iiPbkvjP+3QZI28EpR82RcG3FmEFUyqcRp4RxkAH8/8AXoP3+A+AO/8AWI//ALc//wC+g/P9dQnhvn/qhjbgleHov8Z8v+fBgLrzX9o/uempw49Xj9Mc8c6Dz6m8aYlQwElCKbeCJGkGizUFTVByEnMgl5Y6HfGc4zoKyDvnCzkyoLKFA5xNjmPbI6C+a4O2IN1ezm3Vt0qv+bJ042IkRfFZeYE3Btjhx5F9eXrn3aDGdw1nDWB8NCprSnqE04MBFAkL8UOiK3J2B88ORsY5Y9R9NBrDw4P8nfc7/wCXm/4ImgqIWIbqn9kfpD7vr0E//BcAQ3J1LkP/AMUV/wCKQ0GR2t/yzy3+m5r/AOqnOgyLxXPyhae/U5t/GO9ahmUL9VDQNA0DQcG+iP2DoLQN2f8Akl0v1PpH+IYaxLUI47G9/wBt7sJt3/ojulEzj50q9dqOUUGBF26qCuPZNyMGQEMgICGitpfhBfDb9P6DC/6ptP8Av0HuUr4nuw2hnSr2jLbykE4cEBNVWPp5ugc5M5wIkMAiH1aCNWyKuYa5nibSdwaeKuWMqB3MyDQFygVQElCGEvIAEcDgdBLXeHdjbnV1bVLtcUoBs4u5VTFOKjJdxEomTI5cJh0DGcD7ZQABDuAdtBrbbVXUN4YkY4sruJKu4n6zkU5WONAlB0gCJgKiHMxuIgPMo+700ErN3t/du1lIKmpS/lFfOFnLqqFjiDFpPOkcClEw4UH2cgIemgiRb91c3axW6+7u61YSLmylSAqaAhGL9RdRqg8HqNCeVNhNMCJ4DADgvoGg8KSoyWqy/wATxPIwyAWjQcpSpkVD4lOi3SBscOj9HPUIOPa9NB3L9O7176FHW4fajcGXp2hqWizxck0eSi0esd2hzXUOVJIRKYBTUTADCOe2NBESym3/AHI75DTScVXIzPzMBMVPnDLLH6fX5f3XIDevSHPp6BoMPsttQuZfa7M3ZujFokk7AJuVXRnbgyaIgiqCZ+JgKIj7Rgx29NBIzZkleazu++ktv1Y13JqtYF65aOY1vJqqMB/sqhwApBECiGRAfT10GX+IKxZyXiRW+jpFqk5auTQSSyKpAMRQhnIgJTAPYQEOwhoJkbk7k7LtrElARVyLJwyq1RkUO0+T6baqgAEMUB5ZxjuYNBHvxa6AtfT23yiKmoOgoKDUk51I/WYxyTdU6R2xzAUwkAO3p2+OghgttD3A2mscw3ZRdRsYqEVbIOW7iOkVE35CODAmUAAoBjPLv39NBYpsMkaHLs5LuHu9BIVJM00rISTiaetiO5IEmxhMXgqp7eSgHs9+2g3Nam8e3be3Sc3W0HQiMoNNlO0609EoiskYUxOHTEeQgHv9Q76CIvhDXGuBWl17isKurWbmmzOPTFui/fKLkSHriGSgYRAvbt20Eyrfb4bPXLvxL7eKfbThanhnDxq4Ou1KVuJ2xhKpxPyERDIDjtoK+L7REVP+L0yh5uObP2LqXiU12zlIFElSizT9kxR7CGg8HxZrb0zB7kqLpSgqZiYNOUgECAiybEbpGWO6UIBjAQADPoGcegaCPu5LZpdjas0p6QuS4hlE6kMoVp8nuTKiApgUTcslDH0g0Fzi+4mhNsu1C3dw7hpSKkYrDxTAAYogqp1DtgEOwiHb2R9+gwrdndmmb4eHdWF0KOI7JETkWVRsDpMCKgBXRSjyKAiAdyj79BDfYFvysNtysRJWwulGzbx2/mHLw6bViRdBRuomQvE3IwZ+iOQxoNxfhBfDbHuNjC5/VNp/36D2aW8TjYTRD88pR1s5KDeKpiiovH063QUMmIgIlExDAIhkAHH1aCM+1u5NP3f8V6KuTSxHJIqekZh01K5IBFQIMU5D2gARwPbQbM8Vz8oWnv1ObfxjvWoZlC/VQ0DQNA0HBvoj9g6C58P6GP6i1Jf1g/LfML5n098reY6nT+g26Oen7X990/T3/VrEtQjN/wCszf8Axc/+n6K5TJ4NKqhUkwp0THMBSh/b+4iOA0EkG/h1bLnbdJ0hZeNOksQqhDeacdyiGQH6fwHQYxZClvD5oi/p6Rs0wiGNzIgzpmZsiLoVUhIUQWLk/sDgM6D1q8/qLf1po757fJf9Mvm2fk+fmet1+IeXxx/F+mMZ/boIU+MO5RZblLbPHSnBFCIRVUNj6JSuzCI/cGgzXfVKsN+VK0bTm1FwFfSFJKKOJhFoAoi1TUTKUhh6vEByJRDtn00EOQqXeZuAxtN89JVB81/xHzdw3J5byf4vHPtnh6fSHQWrbfLdQNqNgcZQe6WFLExEYydhULN2cTAmkd2c5eQpCI9wMUew+/QRrkouv5qr4uH8OhBVexL5RFvVSUaJQbnfGPh0U/mPxuRbilnj2x6d9BP60G3Gzlg20upaqiW0ApNppjICkqofrCmBuOeRhxgTm9PjoKFJC9FyrGbg67q21tTrQUsvKyLNRwkmQ4mRM4EwlwcBD1KH3aDaOw2uqpuVv9oqt61llJOalXrpV27UKUplT+UUDIgUAD0APdoNveItOxVMeInQ1RzjsrWOjCQrt2uYBEEkiOBMYwgHfsACOglTd3cR4ZF+HcW/uvWkJPOIYpisjrJPCCiBhATY4AGciUPX4aCOnifbntvd57JUlRtnq8aTTmJmiKmaooqlFJuVA5AHJyh2DIBoIiUbdXdVf6mY3avSdQP6giFkCpM6fIREgGTb/jAADiAD7PHPc3u0FvGw+wlQ0htCJZy9tIHZqyCz9CRjF1AHqNljD2ExB9DFH3DoPcCe2ZbGgC3/AJuNob51f2ryeHCvmv8A2vln2sfD1DQRZ35wsNsVpWma32ntS0JK1W/VbyjpmIrC6RAnUKUQV5AHtDntoIsWvsD4haVVJbjLa0fLhNVUkeUJNpKNeTkjoOZlOJhwHLln09+gzqhrH7u6a3KwG6Lc/Skg2jISQQkKhn3aiAgi2SKBOZiJDkQAoAHYNBMC5t/vDAvHVkbXNx6wg5mbiUyJM3aibwpkiEOJygAFAAHBhEe4aCKnisbkrJ35h7eNbRVw2njwq70zsqKKhOiU5UwJnmUPXiP3aCIFd7ob53Kt/HWurWvXUnTEV0fJsFEkylS6ReCeBKUDdijjuOgtG2PXj2wXK2vUPtUrqpmMnOSrZZm7p9RNYplTAsdUCicoAHoAD9LQbrmvD72RU7EPZ6Ys7GN2Me3O5crGcuBBNIhRMYw4PnsADoI7j+BnARKPzcyHb/7/ANBnFo7KeFpfaoXFK2qpeDnZRo1F6s3SUeEEqIGAomycQD1MAft0GW2movw8bd7jmFF2ujIiOuvEqu0GrVIzoyyRwbKdcAEwiT+56nr7vr0EZvFc/KGp79T238Y71qGZQu1UNA0DQNBwb6I/YOgtQ3K09PVV4VrOCpmGeysk5o+lOi0ZoGWWU4rMTG4kKAiOClERx7gHWJahUHbe0si/vnRlrbkQUtClnptgxdt3CBm7gqC6xSCYoHDIDgRwONFSG8Q7ahbDalXdERFt3EqdtNIHcuhkXBVRAxFSgGBAoYDA6Ccm8/fgtYu1FGS1h6xo6el3iyTZ8gK5HgpJAhnIkIcBL7QY76D77O7R7calrWntz7Svm7q7NWR6kvKxLeWRMmm6cpiK5Stw9soFyPYR7aDRl+bZXFkvFVp2so+hZ1zBJS8Mc8kkwUM1KUqZAMIqAHEAAQ79+2gmHu02R2f3Jugr64DicTkoGIWQagxdFTTEpeSgcgEo5HI6CmTbnuwuZtMnagfWyRiVVJkpWzj5RbiqAETOYS8cGDA99BIbwt6rk673yStazJUiv52PlJByCReJAVVOBzcQ9wZEdBIe/u5O4Fzd5MpsXqJGMLbuoXTaLdqIICV+CKjYixhKqIiADyH/AA+mg/FSheTYVdGnbCbXKEk52gKiWbTEs/fxqr5RJyst0VgBZMClIAJpEHAh2znQWDVVcOhaJbtfnzWMNAi/IYEflF6m36ogAcuPMQzjIZx6Z0FTO+LaNt+a0stXO2upH1cVtMVB138bFyKckZNsqCh1FOiiAmKUD8A5egcgDQQotjcG4G2e7cdXEXEAxqemlTiVpKtTB0zHTEggomOB+iYdB29w24Wudy1ehcS4KUcnKAzTZYYoikn0yCIh2ER79x9+g1q1Imo4TTVHBDHKBhz6AI99BOndFs3sXTNp6WntsczKVxVj5dH5Wj456SSO3QMiJjHFJEvIgAfBcj2740EbLAVheGxl72E/bmkHLytofzCKcU4j1FlAEyZiqAZEuDZAoiP1aC4K1+72u5XazNVZcZeCh7wIM3546mV0xbuV1yAPlig0ObqGE44wAeue2g1JZmys94hrVe4e8Klpymp6kXKbCIRYNjxpFW44UExiKgYTDy7ZDQep4wdvqyrG2dv42iKUl507GUW6hI9odwZMnSAAEwEAcB20EY4bf5vpsXbiDpx9a1vEU9TjFtEtncpTrhMOCZAIQDnMYAEwgAaCZ6Vz7hbnfDOqGu52KTeVPUMPIIi1imxsKGTcHTKBE8iIjgodtBShVVFVfQ71KNrKmJSEdrJAsmhINDoHOnnHICnABEMgPfQeMUAEQAfeOgttsj4cmyC61H04u1uLISFSP4Zu/fsGE+gdVJQyZRU/FgUTFADDjv6aDeVu/Dk2v7b6yjb0MahnGC9MqC4I4lJRMrVMTFEmVBEoBj2vjoN7XOrCla2sLXktSFRxs0yLAyKRnDByRdMDg3MIl5FEQz3Dt9egpr2H7e9td8Wlbr3+roKeVhztgiy/KyLLrAcFBP2UD28CUvp8dBn3hZVJQFs919dfLVWxcVCowj1mydyD1NJNUCvEuAAcwgBhEpc9vhoM/s9RtXS3iyEufGUzKO6Qdysuu3nkWpzsFUzRjgpTlXAOAlEwgADnuIgGg7PiuflC09+p7b+Md61DMoX6qGgaBoGg4N9EfsHQX07cAAdvVswH9EIf+DT1iWoVk7z0FvwpFvlwRP0yydPCY/AeIfjy57+mipgb2Nj9ObuJaEqSQuaanj04yXRKki3IuC3IeWREThj00FE8pDPmMi6YlbuFCtl1EgP0je0BTCGf9mgt48PjYfAW2dUJub/pPVcvJKDB0eHUbETKmLlHAl588+zn4aD1d2Piiy+2+9kvaiOtXHzqEYi3VK+PJCmKnUTA4hxAo4xnHroNKy3jW1NLRTyLGxkcQHjdRvyCXOIl5lEucdP69BHfZFsxa7wqjquPl6veUwEIik6IZNl1uqKpzZL7QhjGNB4tmbtvdjm5+pZGFgQq09OOJGAKmqcUOsUqvDqDxA2B9nOPr0ExPmEFWxBvFhcPVGks3KMx8zenlETNx8qBPMDg3tATlnj79BMTZRuuV3Y2vkbhS9Ms6bVYzKsWDUjzrAYpUkz88mABDPUxjHu0EO/G3EXqVp/JD1+JpbPS9vHZv640EJdoG5Od2nXLeXGZUMNQqO4pWMFqsodECgc5Dc+QFH04Yx9egwm/9zZO992ajuw+p00Oefcg4O1KJjkR9kC45CAZ9NBKPZr4cVN7pLRmuXK3YWp1Ysksx8mRmRUMEAo8sicB78vh7tBvpLwSaRXARRv8+UAPXjFJjj7lNBv/AGaeH7AbTK9l60i7oL1KrJxoxxmyjQiQJh1Cn5ZA4/4cft0HZofYVAUfu6fbokblqunrx48djCi0IBSiumYgl58s9uWfTQYjuz2jRCF15HewFdKjI0I0RnUqc8uXg7MxJzKkKnLJefHGQKOM+/QaJa+NbVij5u0WsGwQ8wqQmTSpwwBjAGe6ff10FqLWVYOmiLg7puUVUynx1SjjIAPx+vQal3T2Ah90tqHNqHFWfIpHLpF35pBMqxw6Y5xxyHroPQ2zWVjdt9noSzTWpvlksMZcSulUypHV6qplPoAI4xyxoKvPGhbLq7hqVOi3UOUKUTyJSCIB/aFfhoNObF9mMNu8f1WzmLgKUwFOJNlCGI2Kr1+qJwEPaMXGOH+3QWUbQfDnp7azdNW5EXdleo1VI1WP8mdmRIAA5ijyyBxHtx9Me/QbH8RMh1NnNxyJlMYwsEsAUBEf78nw0EYvDpUK18PW5qLs4JKCvNiBFB4mH+xk9w99BUWUoqOunyEAOpxz9o6C0WlPBso6oqZiagUv85QPJskXRkgjkx4CoQDcc9TvjOg2tth3SSlu7/07sCb0Wm+i6cM8iSVQK5inXK3aquAU6QFEochJx+l79BqXxXPyhqe/U9t/GO9ahmUL9VDQNA0DQcG+iP2DoLpWd64Lbvseo+8FSxj2QjoGkKe6zZnx6x+sm2QLx5CAdjKgI9/QB1iWoYq/vdQe6DZrca9VK0kpHGJT8w3QUfIJi7SURbmwYpy5EMD3AQHtoqsLaXtWvjuqhpuqKSuoEY2plykm5SkHznkrkvP2eOfcUQ76CzPaZursduZqaUt3TNqjR8hTDEqjpw/YNxItwMCY8eORyIhnvoI8Xk8LrcVXd1KrrOlLxQ8TDTMq4esWXmnSfl0TnESk4kLxDAdsB20FeW5mzNX2Du7KW0rmo0JyXj0kFFnqKhzlOCiYGKGT+12Acd9BgNIlKeq4YhigIGkG4CAhkBDqF0F/e5DdNa7ZbTtNTU7QblyWpMtyfI7dFMwCmQojzEeOQ76CpWw+6e3Vtd2tU37q6jnsvATrmTXRjwTSUWJ5hUTk5AceOQD10Ezaj372q3iUi72pWzoSZpyWrxMYuPdPE0SM2xxHnyOCYiIB7I+ge/QQE3MWEuds4rhnbWarojheQjk5YDRDlZNECnOdPAgPH2spj7vTGg2hsY3qULt1PV5rxU1M1kE4DMGAfi3PlhS6nP8Avjezy5l9PhoLE9rW9bb/ALrLgu7d0haBeKeNI1STMs/YNemJCHIUShwyOfbDQev4kNJ0tG7NLhu4+m4tqum3aiRVFomQ5R8ymHYQDIeugofYVNUkSh5WKn5FmjyE3TQdHTLn44AcaCZuxHf3Te2OBrGLuTHVNUa1QKIHaHbrlUBECEOAgPUN2yJg9PhoPC2Z73Yjb1eSrLhV8hUs9FzrRZBq0QcAoZEx1wUARBQ3EMFDHbQbF2cXneXi8SxOtIx9Lt4SoHcq9bx7pwI9MhmxxKUxQES9vq0GU7rZmXHxT6NgRlHfya4loBNVn1jdFQpjE5FMTPEQH3gId9B5fjKwEWxvFb5jCRzNh5mIOUQQRKkAmFfACPEA0GlN1G0++m1Cl6fqir7sFlG9QLC3QTj37nkmIEA3tcsBjA47aDHNmm6sdvd62lxq9c1DPRTdk4bnZouhUMYxy4KOFDce2gy+tt7MVUe9xpuQjkakb0mg/ZOTw5nIAqJEUSkOXgBuHcSiP7dBLGufF025VnAyke5s5ULh47YrNEF3LdqcUxOUQDuJhEAARz20EJdoO1K6G6aSqpK2NctKaNBlRWdC4XWT6xVTH4gHTDvjiProMj2sbkVdnO46oH10Xc7UzWIRewSqLN0KgGXKqBeoUFTAGPYH6++gsQs34olmNwlyYSz8bbioknFRrGbkO/IgdABKUT+2AGHIez8NBC7xd3bul9yjCGpxyrFMFaYamUasjigicxlFQERITACIgAAPbQaU2m7KK83btameUZU8NEhTAoeYCQ6mVOqBxDjwKPpwH1+rQaenp2uaYm5Cm1awlTHi3KrMwpPlQIIpmEo8e/p27aC/TYnERbra9bWonMa1VlVYcpzvjolM4MYRMAiKghyERDt66CFPiuflDU9+p7b+Md61DMoXaqGgaBoGg4N9EfsHQXlWsoGkLn7T6AoevIJvMQclR8H5pk4ARTV4N0VCZx8DkKP2hrEtQhRuq2xbxIeq5W3e0SmXEXaOSjAQWi2Ek1bt1VFSiDgBIqcD+1nvorQNtNpPieWTjJJhbSnZOnmUj+NeptZpgAKiUohkcqD7s6Do+GZuEtdt0u3Wk7eyqRhEpCNFoRUWqrjqOAXAThhIphD0Hv6aCa23dXeRWe6Za50nMSb+ws8o9fwiqj1DoqslSiLUwI56pc5L2MUBD36Dbe7baDba8NC1tU0Xa6MmLkSEQojGvTmAixnBScUsHMIFDGADI9tBR1eKwl4NttSRkPdOmjQEq7RLIMyA6RXESFPgD5SMYAwYvoI+7QfG6G4O8l6GUfH3Pr2SqBvFGMdmm7MAgiIgACIYD4AGgnnu526WVonw/KFubStvoyOqeRawp3UkiUeqqZVDkoIjn/OHuOgrhpCsKkoGpGNXUhLLxkvGq9Zo7RHB0j4xkPv0Fg22TcTtGuRQr2b36T7epa9RklG7B1KxzlyqSMAhBTIBkSCUCgoKo4Hv30HU3HWHs7usLB/g7KFj5cabBwNVeTKaP6YLcfLcvNCTnnprfRzjHf10GsvDnu3QO13chUMre+cGnmzaGeQ6h/LqOOLsF08p4SAw/wCYbv6dtBbvcG6e3Ov9uDy51fyDeVtXJt01nLhdosKaqXWApBFIC9T+8Avu0EGLn3B8JJ1biqG1Dx0CWoVYh0SKFOFfFMDsUzdLBjEAAHljuPbQYV4UG3uz17aXuK5uZQMdULmNcNE2JnZREUuZFBEC4H3iAfdoNL1D4Ye8dWeklIizgAxM7WFqBZhkAdLmPDACrkO2NB6VAbAvELtbVDWtKBt46hppkByoPG80xA6YHKJTY/G+8BENBYTYWxMfStqkr+b2KQbK3JpVVeVfTz45XTlu2bjySUygJgHiUOwBkdB4FzN2/he3klWE5cyoY2ffRpOm0WdQj8TJF5csBhP499B3bm7z/DQvLHMIi51WMJ9nFn6jRF1CPxKkbGBEMJfAMaCANbeHjuJuHWE1XllLTpuaAqB+vJUwsnJNUSKRapxO2MCaigHIApiUeJgAQ94aCQVmYLYVYG3kbazeTS8NHXYiBVNNtnDBw8UIVRQVEMqtymTNlIyY+yYcZwOg1ZuW2001uOm07kbC6BZyVvoKLFrNOGhgZFTfpiZQ4Cm5EhzCCRiDkoCGgiXa6+V27HupE9sq1kKdVkuCT0WhgDqgQR4gOQH0yP36CUW66v8AZZWW3KAcWsFgvdt0uycz7kke5SXVVFIRdGOocoEERUHI4Hv7tBDii61qi3dTMaxoyYXipmNOKjV2gODpGEBARD9gjoJAQtid52+FoN3WcS9rhNqcYj5RdSLVE5RT9rp8VDlHAc85x79BYf4W+2G9m3aLuM2u7R/yGedFl5APOoL9XplVA/8AdGNjHMvr8dBW5um2mX6srJy1xrk0R8kwEzPuEWTrzyC3UOoY6hA4pnEwZKUR7gHpoJl+G/Su+ZGr7aT1QOZMbIi0dmKmaQbCh0BaLAh+KA3U/vhTx2+se2g6viuflDU9+p7b+Md61DMoX6qGgaBoGg4N9EfsHQXFVPWV1qA8Pqm6sslCuJas2NH038mtEGRnZ1OflSK4SL3NhIyg9vTGfdrEtQiVbfeH4jalwacJdC2clD0eaUbBPSDylFWyDRh1A66qixgAqZSk5CJhHAAGdFWV01cu3dzoeTcW6raDqZNqQyS54l8m6BM5ijxKYSCOBHQfzlXWtBdW3ki7mK7t1UlPMnz9cjdxJxqrdNY3Iw4KY4ABhx37aCxHYjui3LwMhRdNXoiApizDCGFBvUEpFiyZ9MqWG/8Aaz4IPIcAHfvoNq13u73Ak3TRzW3yTSUsILtkD6q20f141JuJQ8ycz4uUygQ3IBHPs40GX3/orw+90lUxlRXHvXSzqSYNgj2gMqrbpAJBOIgHEDdxETaCEniW7ObL7YaZoqVtU0kklZ125SdGdPBWAxCEKJeOfT10HqbQbsVbvemYXaTfFVu5oGHhgXbIsEgbuAM0IUiOVQ7j2Hv8dBqq423+x9Ab/wBSxk4/NEW5bSDVF04fPwTFFE7UihhMsbAB7RvUdBML+pz4VP8A00QX+uqH/i0Grb3XQtdsJGHDYvcCAmPnsKpKlzIpS3TBvx6GOI/i89VX7cfVoOzu+2HU4SwFOXls5Q1UT1eVY9aPpZFgVR2HFygdVY4IkARKXqCHf3ZDQSEstI7eJTZVTu3DcRcWBph6McVtNQklMJMJBqcq4qFIomcQOmbsUcCHoOghNdzZxSEluUpeC240zPVjal84j0JKahzHkWqZjq4cFFymAkKJSYEQEe2gtk257UbT7XmUyxta0kEEp1RJV35tyKwiZMBAuM+n0h0GqPEl3J3L2yWqp6rbYOWKD+SmwYrmdNwWKKXSObAAPoOSh30Ff1K+KJvlrmbQpujGkdOSrkDCiyj4LrrqAUMm4kLkRwACI6CfNMVJfu82wOund3KPkm1eScJMNCRYRZ2zhX2DFSKVAQ5CJu2O3fQU7NNqm5AXSPnbD18m3E5Oqc0A5KBSZDkIjw7YDOgk5u22jWKa0rTptmnna/qAy5gnWkE++V1GqXTDBlE0cimAnyGR9/bQWabTrmW7UtHb61Ra3ghrKHplizkYAHyfyg0XRQKVZNVDPMhyGAQMAhkBAc6DRniC7LbKVFQ1ydyEiykxrFCJB0RQjwQQ5oplIT8X6fRKGg1N4Ut1rMUlt0rKkbnXLpqnlpWeXAW0lKpNVlEDtkyCYoHEBx6hn4hoNk0X4dXh6XQXehb6rBqVRnhR2WKqUjkUeYjxEwEEeIDgcZ+Ggyr8EZtD/wDciov/AJ1N/wB2grF3i7S6ssxduskaItnVidvYRVLysuuxWUbAmZMmTC448RDmIhnPr20E7fCPvBaijtvbmkaruNTcRNvapcC2jn0kki5WA6aRSCVMwgY2RAQDAdx0G39+15N1lqn9FJba6LfzyMmDoZczWEO/BLiZPp5EgDwyBj+vrjQao8XR3KSG0KgX84gKMi5n2SrtMxOAkWMzWE4CX3YHPbQSc2F4HaLbIcf/AIFJ/wBodBB/xXPyhqe/U9t/GO9ahmULtVDQNA0DQcG+iP2DoL59uqyLfbtbRVdUiZC0hD5McwFAP7Il7x1iWoQi8RDfjWVs6gqzb1B0HHysPOU4ZurKiqoJkgcpGIYQ4+z7ID7/ANuioRbP999VbQoOfhKdoiMnCT7lJyoo7WOmKYkKJQAOPr66CVNFXHfeLm9XtPcJinQ7Sjk/lxB1EmFc66hh6XAwKdgDBs6DTe63dNWjO3MzsdQoZNWBol4lCNpvCouHKbI4ARQxccQE3HvjQSWsAsi18I6p2LlYiK/yNN/iVDgU/dU/+aPfv9nfQRr8PrYZSe6Wk5K4c9W0lDuKdm0m6bdsgQ5FQKUqmRE31jjQSF8atg5NQtsWzVust0XzsgiQgm7AkQO+A7aCOnhEsHzbdmkdwycJl+QXocjpGAP833iGg7O6i1bC9vihTFrZSTWj2tQyLFqo5RKBjpB5JMcgA9h9NBIhfwWLRtj8HN7ppEw9wKo3QKOP2joP218Fi065+bS9k4t0xAR6bdA2PtwOgsQouLjaYpmJo9hJEdhDMUWRTcyicxUiATkIAPb00FLN4LNRu4HxO6ktLLy68Y1nplQijtAgHUT4NeYYAew9ygGg3RVe4OpfC3kR2229pRKtIkUwnjSkjzSUBRfsYmEw44DgHf69BKPYbvYmt2kTVkjVdNxNOmp5w2RRKg5EeqChTCIjz+HHQZvu620UXu3oqJoqoa6PBoxUkEiRZoZI5jm4GJxEDD6YNoKzNlNvI+0viaIW3ipRSRaU86lmKLo+AMsUrZQAMPHt3+rQXBXmrh7bO01W3CjY8r51TsO6kkWxxHisdJMTAUcd++PdoNCbLd2dV7sbaVjUtV0c2p5aFVM0TSbnUEFCmRE3L29BDXwZnTZreG5guHCSIGjkwDmcC5/tA9u/roOjtDctieKrXzlVwiVMZioxBQxwAo5WP6DoLDt7xyvNpFzDNBBcDwKwF6Xt8h7emPXQfzqrt3DUwEcIKJGEMgChBKOP26Cz/wAELtOXUNgMgzjf+0roO5cLxg7n0jcSpKKjbQQj1ODlHTBNTzCwnUKkoJAMIAHbONBp6/Pim3Ivva2oLPytq4qNSqBErdRZBdUyyYAcD9iiHf6IaD87NtmdK11ZuR3MTdZPYyZoaSXetokyZCpuRZpkXIBhNgwAYe3bQT62F73Zvdk2rA9V0zFU782VGiaPl3Qj1uqCmRHmPu4B6fHQbH3Y7Z6U3fUDH29l6xWiUo6TJKlWY9NU5hKmcnEQEfT2/X6tBsKydrmFlrW07a+MklpBrTzMrRJysUCnVABEciAdg9dBWx4rn5Q1Pfqe2/jHetQzKF2qhoGgaBoODfRH7B0FxtRWXmdwnh/U1aKn51GHfztIU30nqoGEiXRBquOQL37gkIdvjrEtQijHVow2sU848OurI01RVVXJTx7apUeIN2xpMOkmYxT5OIEE2Rx+zRUL94OzupNoU7AQdRVZHzik81VcpnaJGTBMCGAogIG9fXQSX8E8BG8lc/q+n/6cugmtQG+a39x90cttkQtw5by0a8fNVJNYUjJKGbAImHjjl3xoK+d+dByVz/EQcWphZQsYNRqRUekceQIpGUQIHISF93x0Fj2w7aZUG0igJ6j6hqljOLS0r58izRIxCkL0yk4iBvf7Og728nd/SG0yIp2UqyinVREn3CyCJEDJgKQplAREefxz7tBuW21QQ9dUNT1fxcORglUMY3kkkhTKB0yLJgcCiIB7gHQQW3H7XZ21O5Cd8QGQqVk8p+mlW8srBJpmK6VImgRESgcfZAREM6CCm7rcm03pX7pibo6MkaaScMWlPgk5cchBUXBx6nsDjH40Pr7aCVdHLvPCNRdLXOcrV9/SgmUrMIw5kvJ+TAefPq+vLzBcY/w6CLG1jeZEWD3GVXemdp6Vl46oEHySLBJ0AHSFdwVUoiJhx2Aoh+3QfCL3fU6w3ym3XGpR8aKNIKvPkwFS9fBm4pY5emcjnQWoQ24amd1mzm4104alFYlIkNLsARecFFAMm2EeXIA9Pa0FCbJd4RcqDV2qh1TgUeBxL6jjvgdBY5C+DneGZh2MuS+EUkR82SclIZJxkoHKBgAe/wBeg3TtH8MOvdut+YO709cuImG8Um5Io3QbqAqoKqRiAPI3wE2dBYqdMiqYpqFAxTBgQMGQEPs0HUcxbc0e5YtEUkPMJHTyQgFDIlEMjj7dBQtvD2OVrtDioqq5evmMqnUb9ZummxIokZPAc8mER79h0G2LX+EZc641A03cqNu7DsSVLFtpVNM7dbqplWTA4FMYPUcD3HQbfp/epTfh5QqW0evqSkaxl6Q5GcyrNYpEHAOR8wUAKpk3YqoB3+GghvvI3F05vJvNS85SNLrU0iVi3gxScmIIioZcw8/Y934wPu0FnewHY/VO0VzVsjUVYx04Wp2zQiJGiByCl0+Yjy5euef+zQRK8Ptug48R25STlBNUv/Do8VCAYM+bL7h0H4dt2oeMiDby6PR+XCB0+BeP/IA7cfTQeR4qsA6qHebStERD0sf8twsaxKJREiZDKuFCcjFLjProNF7ttm1Y7O3VLNZmu2csNVlXOmLEqiXS6QkAeWR7/wB4H3aCU1rrfTfhiR7Dc5cScGtoqs45KDbxjAx01UFHBSuCqGMoPEQAqIh2799BIrbl4pFCbiryU/Z2GtvMxTyf8103bhymdNPotlFxyAdxyCQh+3QaA8Vz8oanv1PbfxjvWoZlC7VQ0DQNA0HBvoj9g6C4qp6NutX/AIfVN0nZKacRNZvqQpv5NdoPTNDp8PKnVwqXuXKRVA+vOPfrEtQh7NXFt5tztPUds91bT5a3CEjnbmIqNVsEi9bHUTHyRk3xsnIYhsCUQH2R9NFZT4YEWz3MULXcnf5ojceSiXiLeNc1SUJJRoQ6RhEqRluQkATYEQD3hoIgXl27bpdkAjXp6kXpJrUb1VggtT8wdJRQuROUhumIDxwHpoJ/7EdxO1Kv39F0nE0u3WvGrDcpWdWhiA7cuipZcKHd45nMbvkwjkdBFreNXMFbTxRGdfVMoqSKgX0O9eGSJzOCRESCbBfeOgtN287mLZbnKckapte6fLsYx55JcXbcUTApxA3YB9QwIaDDd4N2tsNqYmnnW5SkGM6zfOFiRhHUOR+CahSgJxADAPHICHf36Cs7cTT2822FLur7wF16ihbVzb8q1NM4+o1kgbR7gRO1SK3KYASKVPiHEOxcY0EotkO9izlyrVUXtvvBKS9XVrNmcMn5JlqZ6i6EyxzkBVRQR5gBOId/TGgxje94cdyLgXihqs2y0TS1OQrCHQSOVidOP4viLKGFQCkAPa4iT2vXsGg0vWfhy+IRckzELi1MSpSRwm8qEpUhnPQA2OXDnnjniGceuA0E57i0Xs72rWYpip74WRo4TAmziHSzem27lQ70UREwiPHI5EhhzoKmK4pGG3QbsJSmdt0GxYxtUPjDBMxRKyRTIRHkYOIdifRMOgta2qbXLp2m2XVnZGrWrBOpZtOVK1Ii5A6QiuhwTyf3d/XQV8n8JTd63KZx8k08HTAT5CVLkMd/hoN5+EtdC71UX4q+jbhXFqKcaQ0AdNJm/k1XCCKibghMkKcRAMBkAEPdoNkeID4gNvGNv61stber6khbixj9FoVyyIo26Zk1iiqBViiAgAlAQ7eugxHZr4m9qLc2QYUzfitqrmKsSduFF3ThJR4cyZj5IHUMORwHu0E57Abp7VblKcmKpto7frMYNboOxdNhRMBuHPsA+vbQQd3VVRFeJvFRNAbYTney1FvFX8oWWL5MhUjh0wEphzyHkGgr/qG9G520U4/tavems449JOVIYzRnPuAQQFAwpimmBTYAoccBjtjQSJZbnNslS7O5Ol7pQgVFe98wdomqGSigdPTqiqboCZ4fJx4p8SgOewAAaDcPhh2ntlV+1e4VW1Vb6npebi5R4LKQex6SzhtwZkMTpqGATF4m9oMD2HvoI27f0N9+52RqBjam99XKjT3AzsrqqXCIFIoYwEAuTd/ojoNkeFZGVBDb36liKscncTTKKk0JBY6oqmUcFXIChhOPcwiYB7+/QT5Vvfs9LuzC2ClCxw3bF4CPyr8gp9brdHkA+Zxyz0+2c/VoPO3Rbjdl9nbrx7G+1DsZKsEGKD5o/PAJu1kUeZunxVEMlEDFMIAA9tBkVsLt7V99zeScRVGR9VHpACJ8p+FTOLcVwHj0uoA4z0++PgGgiTHUjWm16s5iu9+bs1WWlfqrx1ORD1cZlu1emU5oGI1UyVLiiRQoGAOwDjQTmsVQO2mooKnr12htLS0R8oNzOoyQbQaDV0mQ5TENgxSgYuSiYB7+giGggR4rn5Q1Pfqe2/jHetQzKF2qhoGgaBoODfRH7B0F9W2/8nq2f6oQ/wDBp6xLUKvPE724X2uZued1NQFq6inoo8S0SK7YszKJCcoDyLkPeGitb7fI3xFdtLSQirY2pqeLYS7lJw/BeBBbPDtnJw7ABRHQbw8V2/Fqrq2coaIoq4MLPTDKV60g2ZOSqKID0BAwmKHp7WQ0GEtJbbJt120U1fXb/WkQ3vsjGsyrpDI+aUIosAFdALY4iUOwm93bQQ0uDcC6e5+6vzjqIp56r586LNNNm2KQy5ilAiZCkIGM4AA7aCznw2KngNp9sqlpHcjLN7dTMtMg/ZMZ84NVnDfpFL1ClN6l5AIZ+rQeP4mMix3a03RkVtqdp3HeQLxyvJo0+bzR2iahCgQygF9AEQHH2aCYcTt3o+8e1qgbS3np12dsxhIszpiKx26qTlFAocTCUQEBAcgIaDD4DYjs426TDW9DeGVgVKWP50si9llRRbj6cjAY2Md/foNi/wBdHal/09Ud/wDORNB+T70dqwkMCV+aOE2BwHyiT192giLbanr97yrqz1ud2NISr2z7bzMxTzkrLySSyxFQK2OVdMAE4CkocQDOBznQZDuF2j2R2Z2in9x9hqedQ9c0gmktFPXD1RymkZVQqR8pqCJTZIoYO4e/Qe1sj3/UlWdmzTG5C81MR9VhJrpAi5USan8uAF4DwDAYznvoN9Pt5+1RRmuQl+aOExkjgAfKJO48R0FFtutyVy9vV0aprWz882ZOpdVy2Ouo2IuRRAy3MMAYBD1ABzoJ2Xc2eU1efZ0juUpyhZWfvHWDVlLO1GKqhvMLrKl6xioAPEA45HAB20HRs74cNKy+zaoKwuBaifQuwgxkjx7RRZZJYyxAHy4AiA4HI47Y76CKdIXm3d7HYx5QzaNeUYlU/wDbFG0rFkE64AXgJi8wzjHbtoNf2L3O3e25zctUNq51vHPZpIEXZ1mhFwOUDcgDBwEA76CTe5yndodRbZmV46bqqLf3qqXyMnPooSpjqC8ce27Hy+eJPbMbsAYDQae2lbTK+vFcWipSetfPyNupeSBJ9JIonK3FAphKceqX0wICGdBd5ajbjZvbzb2doihIpWLpyVOs8kSruzq/SSAhzczDkA4F/wBmgrZ3B3ds/svLEvdgtew4PaqVWSqYEnQSfsJCAo5BTPT7nU9PXQTCspaXbHt/goTdZU75tTVR1jFIqSkvISJit1nLwhVlQKQw8SiYwCIAAe7QQQdXbtqfxWAuoWtYoaR+WCrfLHmA8rw8mBeXP0xy7fboJUbtC+HfflnO3HqW51OStYsYBZtGGbzok5HTKcyJQTKODDzN+3Ogr52dXG3eUEFSl2uwUhItnarYZozWKK8BPiJ+nyEwDx7CfQW4SSO2be/R0Tamu6pjKomYdJGXkIuPkRScNnRCdNQxypiAgAGUMUQ9MjoM8tdW+3e3jyK21UBXMMSWhCHYtIDz3VeEBMhlDFEBHkIgUDGHPuAdBADxXPyhqe/U9t/GO9ahmULtVDQNA0DQcG+iP2DoL6tt35Pdsv1Qh/4NPWJahmchVFNRTgWkpUEczXAAN013REzY+OBHOioZb6d8NxNv8zAQloaSh6vZzLFdR6sAKuPLmAeIBlE2A7DnvoK6dh+1akt4VzKrga3nZSGTjmIyRTRwE5CodXAlHmA9gzoJpo+DrtqcPzRbe89RqvSCIGbkcNDKgIeoCUC57aCJ6tkqW2x+I/Rds4GddO4mInYpcHkgchT/AIwpTmEwgAFAAEdBm3jNzETMXuo1aJk2j1NOnRKY7dYqgFHrnHGSiONBoDZ1uquftemKhk7aUM0qNacbooOiOEFVASKQwiAh0xAQyI+/QSVbeMluMevTRrOz1MLu08gdBNF0ZQoh65KBshjQe7C74r0buZRvtzutbNhS9KVwb5Ok5ds2cJKtEvpcymVESAOSh9LQZ6n4RO1dVQqSV+Jo5zjxKUr1mIiPwAOPfQRS8QjZVQe0AKHVompZiYGpDPfMfKPT9jodLjx4FD16g5z8NBNPw7d594L31c1tDWdu2MPBQVMAq1fot1yHWMiKSZAExx4jkoiPb4aCYG4i1FL3utBP2yrKdWh4iZTSI5epKEIZICKFOGBP7IZEoB3+OgpH3B7WLdWt3W0rYqjqxezEFPKxqS8gZVJRUguFuBwKJA45AO4aCbkj4Oe22HMmSXvJUjIyoZIDhdqmJvs5FDOgjZv62BW32oWzga3ousJ2XcS0sDA6b7pCmCYpHPyLwKHf2Q0Fm2x+ooFba/bCJRmmCj4tPtyi2K4IKoCBe4cc50G7H9U0zFuBZydQxrRcoAIpLuiEOAD9QjnQVNeMAxUrm71vjUwmpMNSxRkV1o8PMFS5L+giTIAOBz30Gn98+zW0e2ei6UqK3Vw31Qu5xyZF0i4cIKAiUEwNkATDIdxx30Grdku32m9zN8mVr6ql38awcsXDoy7Lj1QMmXIAHIBDA6CXNfbz668O2qXe0q2dNw9Q07RwEO1kJjqebW8yUHB+fTMUvYyogGA9ADQdOD8V/cPdBROlC2ggFI6bVLFunLNu5UFEi3sGEB5CACAGz30G6S+C7YxwQHhrlViU6odUQDoYARDOPoaCFu7nePW9dUevtWkaah0YChJcrBm/S5+aWIy5IpifI8ciAZHAB30G9Nr/AIYFmL1bfKYvFWFxahiHM03VWckSOgRulxVMQMCcvb6Ieo6DM618IywEJbmpK2py61SSR4WMdPE+CrZRIyiSYnAphKX6u+ghRtK3qV1tJLUkdRtNQ0qnVCjcHJpDqZS6XMoceBg9xx9fhoJd1jF0n4fFPMt11n6ibVZVVwDlj5GJknCZ27VN0UXKhiAkIHASnTAoZH0HvoNIbHrnSt5vEvpe584was39QO5d2ug2AekmYYp0GC5yOO2g3Z4rn5QtPfqc2/jHetQzKF+qhoGgaBoODfRH7B0F9W2/8nq2X6oQ/wDBp6xLUIW+IfsOn7qTtW7j2VzUotrA04dyaKFqcxlfKpGOIAcDAAcsfDRUMtlO+6J2qUrVVOzlvXNUKVEuRZNYHhU+gBUxLj2imz650G4/BkelfX3uI/AnTBzDdYC5+iBnADj/AG6CW9r9hk5b3d9M7m3FzkXzKTeyDssODYxTJg5AcF5ibA8c/DQQQ35W9Xuv4kCtuGkqWNWqJaKjyOxIJwQE6JA5YAQEcfboNqq+CTVjg2Vb+sFRL2yaKUMP+1TQSe2NbB320WcqeWmK7ZVMWfbIIETTYCl0RTMIiOTGHOc6DrWI2APrPbpal3CvbgMJRpOrSKpIorDgZIHKnMA5CYQHj6dg0Gx9/LZqhtDuUs3QSTOWIESnIQCiHtl9BD00FZGwvYrUe4+CbXnaXQJDIU5UpGx2CjY6pluiCSoiBgOABnnj00Fhm+fZFJ7vk6KJH142p35qea59ZmK/X63SxjBgxjp/7dB724S/EXsasJS8++pb5zC0UZ06YrZQrYTmBA2VMiA9vxfp9egidIb8Y7xAWZ9pcLQTyjXleB0EptZ8DgjToj1xEUylKJsgnx9Q9dBFGrtuMjtc3pW5trKVcnUSwzEO+84REyQYO5AOOBEfTj8ffoLOd72yGa3bTlIzERcdCmS00ksmdNRsZXr8zENkOJgxjj/t0Gm/GJjhh9s1vocy4LGYzqLcTh25CRqcucfXjQQy8LN04NvOoxIy6gpg3kA4iceP/JT+700Gf+IVR7+5fiGtLbspo8capDREYVwImMVEVSlLz4gIZxnONBYVsy2cPdrNDVRSE3W7Wp1Z91103ANBT6AdPhjBjCI/HQREfeClVkg6VWUv8wMVRUxwKaLUNx5Gz+c+vQRQsdc5LYruunJGUiFKrCknUjAHKgp5frmKYU+oGQHAeznGglhKbKZLxHXx93cPXTai2taYISFctBdKNvKgDccqFMUDchS5env0HbibsR/hKomsZUlOf0jOalN85SSLYQZlQKb8T0uJgOIiApCOc+/QSr2X754veE8qhiwoFzTfzbSbqGMu8Kt1uqJgwGChjHH/AG6CvLf9sHlrFRc7f11cRrKoVDUqnGOTZGTOl5gx1AyfkIDjGPTQTQ232kdX18MinrUsZ4kMvPxaqBHxiCcERK8MbPEBAR9Mevv0GWWi20SO1TZ/cW3MrWKdSLOGUtIg7IiKQFA7XjwwYwj24fH36Chfp9V10wHHNTjn4ZHQSw3Q7EJzblZOmLvSNzEZ1ConTZuRgRqdMURVQMqA8hMIDgC49PfoJUeHFsElqcqG2m7U9xGizQzN07+RwZGBTC7VZuAdTljsKnL092g8nxXPyhqe/U9t/GO9ahmUL9VDQNA0DQcG+iP2DoLr6fvHSlgdllF3ZrZN4eGg6QgPMlZpgosPWSbol4lEQAfaULnv6Z1iWoRhvb4rm2i4Fn61oaDZVYWRn4F9GtBWjyFTBVVExC8h5jgMiGR0VXzto2S3e3VxMxM21cQiaEIum3c/KDoyRhMcuQ44KOQwGgkJS3hWb36GdKvaMreDg3C5OmqrHzq6BzkzniIkIAiH1aDwr87a9+e3e3Ty51eXukDxDFVJFQrKp3SivJQwFLgo494/HQRXoUlz7x3ggI6Mq18vWM3IINGMo9fKdUiwjxTMK3cwY7d/doLwtkFor5bfrZVQ03CVeaoZFR+eQbrFkVHokbESDJQMpgQ7lEcfXoNdreMNtWRVOidjWPJMwlHEaT1AcfnNBHuoNoe/G8U7IXYtzeRdlS1YuVJyGbq1K6ROiycGFREhkygIEECGKHEOwaDsXk3PwlmdqVSbKbxyc7MXXYx52b1+URdNVFFleun/AGg5gOYATOUO4dhDGg8Lwzt7lnrA22VtHWzecPOVBVIrtTM2pVEeKyaKReRhMAgPIg57emgt9MbroZT7cyZL+0O2gqNvF4bu+O6NSzZ5O4sdJwDqWcPmLJ/PrqJpFMcwpiCYlEpRApsdvTQbnu9YJLbZ4cz9VaBhIy49MxqBVKgiUikdkVM7KAmI5AAUyJDcRHPoIhoIEUFtb3KbgbZye51lWiL1pTIODneSUsqL9PyhOoPTEQEe3qXv66DUaW4G/KqpESXjrLkcwED/AIbceojj/FoJhyfhq77LnwMerVFyY6bj1iJvG6MlULhYpBOXIG4mKIAbA40EbrbbeL1DubVsLQlQt4ivI1w6aA+bv1EEyGSTMKnFYocgASgIenfQeVuMoS89irzKQV0avXf1lGpt3YSaEiouoTIckxKsbBgEO32aDcW3O1e9fc9TE3Vtu72TCbKBW6DoH9SOkjibhz9kAzntoMn2Kb8kbDVnVLzcDWVZVCyeMyNmSfmDvQTWIoPI2FDhx7e8NBOCyW53Y/uluiNE0rZ1q4qKTTXfquZSnGwAqJfaOY5+4iYfXPv0HRlto+4iN3kR1ybeVQzgrRMn7RwFPMpJRuiVIqJQWKVqQATDkcDGx7xHOglrcKgbWzbZzVlfUFBTh4tmocVnsck4VKiQBOJSicBHHr2+I6CuWt3DPfAdvGbAUv6PHlIqHPUpwKEN5tNXAIBlvnq8RIp9L0zoJI7u9rt2L8bTaUs/AyEctVUUpHKyDh+6MCap0UTEVNzwImETDnIh30EM4Pw3PEKpmLQhKdu41jI9sAgi1aVO5SSTARyIFKUoAHcdBoW91GbubP3TirD1/eGZdy1TItypERqBys2Om4UFMpTiIh2yA5DHpoNjD4Pe6siYuBf0eHEvPISSmfTP5vQaitXZzcduwreUsXG18tJOKVRVdnbTMusZomCBwREUwHkACAnwHb0zoLRNoe5e31AStHbFpxGTNcSn268W7VRQAzAVm6Ki5+KuciHAg49n10EePFc/KGp79T238Y71qGZQu1UNA0DQNBwb6I/YOgukaNrOPNjtIN7/ABmRaEPR9P8AyqLxQxEeybYUeQkwYPx3Txj341iWoaGgbeeEbVE4wpuBRpB5JSjlNm0bpvXXJVY5uJCB7XqIiAaK8bc5Zi+22+fgI7YPRkpA0/KJGcVIWJIVciixDgBTHFbkIYJy9NBM2z+4u0N4VTwFC3AjZ6ajWiasi3bGHmkPYpxN2/xZDQVUeIFLb4FpC4DGvEp0LQlnzgxFVuiDboAt/Z8GAOWPTHfQaw2Lbc711DeW2t3Ia3sm7pFtUKCy0qQodEhE1MHERznsIDoLEd9rveoF1KXjNurebVpB2wInNlYoJHTE5lhA4GE4ZD8WPu0EWvFS202XsRSFAylsKGbQL2YduSSCiSyhxVwmQcCBjD7xH00GM0DUXiupUPAJUKhVo04SNbliRRZthT8pwDpcREuRDjjGdBsiraDtJcuxMnEXfi2sju5lWp012ThQ5JNV2Cn4gBTIIJ58uBMdvTQQEqi0t4bLXAhacqik5CAqlczd7GtlyB1TCKmEjlDuA5OUQD7NBNFGqPGEKCY9CsukAF7+Ra44/wD8Pw0G7N5G/QKa280yys7ehuhc1q+Zt59FsQp3CfFA4OCnKYolDCoBnHv0Gl7LXH3WXXNCVBu2lJWS28y5TKTz2QRSSYqIAA9ITHTADgHWAmMe/QYFuv3KR1q6mf2j2cV+hH2plYkAeR8aAKoKuFgMVfJlQE+RLxAcDoI02ssFeW8iD+RtbQklUCUQcnm1GhQEETDkS5yP/wCSP3aCQdrd0fiL3Ill6EtdWlQTD+Cb/jmTVo3E6CSYgTvkvoA4DQaMf3Tvvam+0rcKRm3sNcpm8XB+7UST6ybg4CVUBLgS5EBEPTQZBV9Ebp9x0RI7jqop6ZqaNRbHF5P9JMqZUm4YNnjgMFAPhoLAfBp/+4Zc7/SX/wDrDoIi7EY7anIXDrIm6k8SWLKgAxnyisomXrdYeWBIICPbQbH8PpKjUPEcnUbdiiNLkVmyw/RMJk/JgYelxEe4hwxjOgtbktxtlIe5SdnpK4MYhWKqqSJIkxh6wnUKBiBjGO5RAdBnc8WLPCPyznD5OFsqDvmIgXo8R55x7sZ0FQW6q+lltuKcE68P6t42CfTa7glUGiTGXFUifEUefWA2MCZTGNBIa6m7Ksrr7dKUgNp9yfl+7/QYOZhpFpkO6BIEf7SYxTl4gAKCXONBI+w9e1hQ21+DrjdJLqRM4waqKT7uSKVMyQisYpBOBAwHsiT00GI11brbVuup2VvzQMVG1nVcExWaQsu0WU5IvUCCoiQpcgUTFOYo9w9+ggetUnjBgRQot6y6WDAP9ha444/8n4aB4Pxpc+7Gtzz4H+VDU48F5zAAN1/OI8847Z5Z0E96eidkBN1Rn8ApBDevzTsTFI4WF11xbHBf2RHhno9TPb0zoIfeK5+UNT36ntv4x3rUMyhdqoaBoGgaDg30R+wdBaXueh5We8KZrFQkY6kHq1H0n027ZIyih8LsTDgpQERwACP2BrEtQ0N4f2wC3NSUHT+4W5r+rKeqSm50X4NVTptmpStVCnIZQqifICjjuPIO2it6b3t7F27STEDF7dqcgK4jpNiueTXSbLSHllAHBS8kDgBcgIj7Xw0EZvBgcKvL6XCduUwIsvC9RQoBjiYzgBEPvHQbiq24F3t4G4Gp9m92qHUhrY/KjsE5yLYLIuzFaGE6AguqJkh5CUM+z3z20E07GWaoja/aVpbunZp4aBhjLufNyy5OZQUOJzCc5QKUAAR+AdtB6cxem2jWJeu4+4dMrOkW6iiKfymkbmoBREpcAbI5HAYDQV+W2jK48T2SkqV3TUtIUdHUN/a4daBZqMjOVFjCQ4HM5BQDgBSlEOIB66CU25uu6+2j7Z4UbG0585X9PiwhGyD9so6MZsQnDmYqIlETYKHcMBoNV7Z9sMFfuoaW313SCeg7lyTgz13ENsN2CR0BM3TAEVCCqACmmURyfuIjoNEeJZRdYT2+C2ctCUtLP2SEbFAq5bNFFEiCD5URATFDAYAQHQWiTlXUtSrFuWpqkjYoXKQgj510RHqCBQzx5CGcZD79B/ODfekapjLj1ZUMhTck1i3k88Fu9WanIgsBlTmKJDiGDZDuGB7hoNot9616qq26IbRIukYR7BKtSM0jtWS6kicpFet2wcSiOQ/w+mg3Jt/8PWhbg7TauvZcZWsYOqIJGSWbsQ4IImBujzTExFExOICPr3DQbP8ABnqykacpa5rSpqnjIoXbxkVIrx2RExw4KAIl5CGfXQZjd+za/hzo/wBO21+NmKzqKs3p4yQazCQvm6TZTK4qJlblIYB5lKACIiGB0EMrXWomN0m6pWa3GwczRkJVrl3ISsgg3MxQbq9MxiFKouUxSAJwAPaz66C1e31qts1uNvMrtshbwtVqbmGzxqu5cTbUXZSuQEDiUwABQEM9vZ0EP67qaoPD3ELebN44lx4CrW5n0w8kEjSZ2rgAFMCFO04FIAl74MAjnQVq1PSFZwBvlCqKVlIkjtQwkM7ZqIlOYe4gUTAGdBbzsW2zbXLQsaI3AMrvHLVklTiKrxg+mmgN0lXCJRVL0wKBy4ERAAE2QxoMv3HbYrdEqGod+Fu5uWqCuINFOUimLZdJzGOXDZMqRSCRMvMwYL3Ap850EaG/iQ72KtXTpeobHQ7SLmDgxeuCwD4gpIKjwUOBjKCBRAphHIhgMaDSm/fa7YbbzHUfIWbr97UbmfVdfKJHEi3c9DgBBLgEilEuRMYO+fTQat2+3bvNtDq0LxU1QgZeMTx6a01HL+UOmqJTZKYolyI8Qx30Fptzrk1ruS8MKXuFKwaKlQVJE81GUSgcxBMV2BQAhBExvQufUdB43hRzkNbLbhIQFxJVpTEmpUjpwVnLrFaLGSMkkAHAiggIlEQHvjHYdB3t/niAVLt3XpBrZZzRlSpz6Tz5QFwczoURT4AQA6SheOeZvXOcaCubb3d7cpt9udN3ioW0biQkqlarIrkfQzpRuBFlSqiJOAlH1KGByPbQbF2T13VlzfE3pqu65h0ouemHkw4fM0kDolRUGKdZACHETF/aOg3P4rn5QtPfqc2/jHetQzKF+qhoGgaBoODfRH7B0Fycre8u3PYPS14z00E+EBSFOf8AB4uOgC3WK1Q+nxNjHV5eg5xjWJahCK5vjCqXItrVFv0LAGYBUcS7iwdEnROCArJiTnxBAOWM5xkPT10Vo3ZlvpX2l0vVFNr2tUqoajWIsCx5AW/Q4kEuMCmfOc592g3b4Mqyj2+1w5A7cyIOobqgUQH2eTgBxn3+ugl5bLfx/SFu4l9sf9E/kPkt6/afLnyjz6nlgH2ul0gxyx/i7aCR16bef0tWoqm23yx8lfOOMWj/ADvT6nl+Zcc+OQzj4ZDQVwfga/msHzl/rHA5+SP7d0PkTj1el7fDPmBxnjj0H10D8Nf8lf2P+rljofiefzg48uPbP/J/q0FlFtq3aXGt1TVdKs0mXzhimsn5UyxVOh1kwPw5YDOM4zgNBGqY39DDbx0tpyVrRVSO+QZhOlksFDqIFV5dEE8duWPpe7QN0m/hLblfqlrLntcSf+cTVo4+URkwQ6HWcHSxwFM2ePDOeQeugjd42j3KNol0FvUZU4gQ/wBTcfdoPAou66Hib0hCbShg07eDSLBCaGeFcJDzPlSAh0+jhPjy6vLPMcccY0EYI1Y2wzeOZwVqNbkoF8ol2KLQr3mgJc+h+GOf1+mgtVorcsnul2V3HuQNHFpcwRMwwBh5sFxHg2H2+XEvry9Me7QUQMRcIP24fjE8rE+Jc+0Ggvw3Nbt0NpNiqHrX5kpVWMp5SOFsL8G/T/s3Pny4Hz9HGMe/Qa33s3JRvB4bTq5reHJFLVG3i33kiLAqZDm5JkvMAATY+OA0EELWbCV7lbUZzcye66kcpDs5B0EIMcJzK+WAR49XqAIcsevEcfXoJceDGqZvZu4j12gKooSxFQKoHqBUM4AR+zQRD3xb8k92kFCUqS15KXGnZBZfrlkgc9bIcMY6ZePpn1HQao2k2AV3O3eaWqNWZqbK4ZrugeigK4F6Zc8eHMvr9ugshsfuQLtIuzTPh5npgtVFj35Go1WL0GwH85/aM+WEp8cOpxx1O/HPbQZrvz37tNstSktOla5GofnFT53Pnwkgb9AVDKJY4AmbOOOfUPXQUjqrOHq51sKHycT4yJsZHOgsqoK7RfEdoWntnClNlt6FMxraR+cJlfO9cWaYJcOiJU+PPnnPMcY0G6Nuu5Udv94Ka8PIKMGeTgXB4351+b6JV+ZDOOfluBgDHLjjqD6Z0Hq7/tgh78T0rfRvc80EWnqaMT5LJF9UFxbgopnqAoXHLOPojjGggHsn2OuN3iNWPl7jKUz80lG2CnjRdeY6nMfeoXjjp/X66CT/AOF+/o3/APU//q7+f+bP/BPmvlnp9fofi+px6A8eXHOMjjOg2ltd2qpXEvpTfiCBWxI41SC7l/mmDIDi38y1Vb9PzHMOXHqcs9MM4xoNReK5+UNT36ntv4x3rUMyhfqoaBoGgaDg30R+wdBb/Xdl6n3CeHZT9oqOex7SXnaQpry60gcxEC9EWi5uQlKYQyVIwBgB7iGsS1CPO3+p7O7M5Wn9nd+Lftanr+YlkhSlI6NQdMgI8UAqIGVW4qeyPqHHtoqwRxaW0zdBRc9tKaEqZDHEAikc4AM/4dBB6P8AFe2cUPJu04C0NURbkhzN1lGMMyRE4FNjGSqgIhkNB8Wni17NGMyeo2VoapbyqhjGO9ThmRVzCb6QicFsiI+/voOxU3iX2j3L0+/sHa+GrKHqyvETQkO/eooot2zpb2SHUUTVMcpQH1EoCOg1HD+GLvmaSrJy/vtCrNkXCZ1k/nA/NzIBgExcCl3yGfXQez4ydE0pSVEW0NT9MxUYsq9dEXUZtCIiphMnqJQDPf46CFW2WjL/AO46u29p7cXSexbtJgo4S89LuEm5EUgAOIdPkIdhDAAGguz2q7e3Vo7SUzA3PaQc5XMT1xdziafmFVTGWOYgguoUFBwQSlyPwxoKz/GYXWbboKbcN1jpqpUk0OQ5DYMUwOnAgID7h0EF5+sKsqroBU1SScqDbl0fOuzrdPOM8eQjjOA9PhoPhBVHUFMOzP6cm30W5MQUzLM3BkjiUe4lESiA47B20F2li6otRbbYPTN/Lt0OhUgsYwq8i5GPRdPnIncdMBEyuOQ+0HcTegaCvq/O8Gh633J05cO17Goaft9HnYDJQJCEbFcgmryXDoJn6ZuZe3ce/v0G+Ln24gvFAcsJ/ajT8VRTWhSGay5J1sViZwouIGTMmDYFAMAAQ2RNjGQ0Eet3OzncbtyoSHqO8FyI6oIh5IAxaNW0o5cikr0zGA3FUhQAOJRDIaDbu3mz1yduFE0ru+vVUrepbQIMCrKU02dKulzEclFJAPLLAVH2TmKI5N2xkNBh7/cRTt8t9VCvbVozEFQUtOxDJSAXArdBQOoUqoHbpmFMSm75Dvn36C6pnR1OwUS+jKVgY2JK9TOBitGxUSGOJRABMBQDOgp6e+DNuXdPV3Bazt+BVVTnDL512ATCP5j69BCmqIerrK3HnqRSnTtJum5BzEuHcY5OQDKJHEh+BwwYSiJR+GglHYzw89zG4+iYi/tLXGgERljqi3cyUm6B8QyKhksmMVM2O5Bxg3pjQZ/PeEJu7qd0R7Ut0KOlHCZOmRV5LPFTlJnPEBMiI4yI9tBlNrrf054Xi7+U3W09E1ohXpSN4YkE2K+FsdsIiqKgOQT4gIKExxznA6DbfiSOqWitn9O3RtRCI0ovOycc4Rcx7cjN0DdZE5+mcyWB+GQyIZDQak2weJFtqtLail4W4tvalmK4iElAeziUc1WVVUMoYQMC6ioKD7IgHf4aDf8ATni77a67qCNopCiq3MpOu0o4gOWLYUhFUwEDnhYfZ79+w9tBNWnqRpOmE1vmzTcZFFdgUVgZtSI9TAduXEAzjI+ug0VujuJtz2tUczuFca0rSTaykmWPKEbDNVVhWOQ5+RuoJQxgg985zoK8NpN8VbqeJhBSlGSU3H0VLvZZVjCLrCmkiiEY5EpBRIYUwwYAHAdshoM+8Vz8oWnv1PbfxjvWoZlC/VQ0DQNA0HBvoj9g6C+rbf8Ak9Wz/U+H/g09YlqFTHiWNqwe7+4lpb0yhanWRiCQ4pnKQ4PBNhLBjdgHnjuPbRWIXyvH4jm3p6wh7xXGqGFXmUFFWqfn26/VTAeJhykJgD19+gyDwqLO21vldiso+69JMqjbtocHaSbwBECLGWABOGPf3HQSqrGb8IegqplaLqmn6eZy8K6UZPW/yM+P0liDgxeRSCA4H3gONBAK8rijan3gNDbIyJotnLpinTIMUzNQB7wKA8QX4iUeee5sBoJGPKB8Y9i1XfOpWoiot0zKqG+XGA4KUBERwCnfsGgjjER28zfiuvTraQk69PSX9oOi7et0QaioPHkAqGKA5EuO2fTQS0+TbbJ2+h7Y7LGDeO3MwTVuzqArBMzZwmKBAI/KLhbCJsKB34mHPu0GqLfbt90Vi90ULRO6i7M3HRcI+T+cLNZQrohETpcy56IG5ZAxR9nOgsYKx2UbyoB/fJzBRNZsKcTUjXMo6ZLpnQTRL1jJgU4FMIACnLsHv0GCWUtR4a+4z5aSs7b2nZxSBBPzwfJ7lDpCryAn96Bc5EhvTPpoK9q78MXd04rWeXpWzhSwykk4NHgSXZlL5cVB6eAFXIBxx2HQZWvtL8UNzbILNrU/JnowEio/JAzbDocAPzAuOpn6XfQRHvFZO5VhasCiLp08MNMi2I7Bv5hNb8UfPE3JMxi98D79BuPaBTe9abiqlX2oOpJFk3VR+WfKP27cBPxMKeQVMAm7Ab00Gtrv7hr9XVahSF3LgSk4hEvDHBq6OUxUnBckEQwHr3ENBtvazufKrV1O2v3OV46e2XaNlUHcO7ROu1ACJiKACRMonHCgFEMaD35CVsPNeIfbt9twSbJ0WaoYUG5WzdVAnVBQvUwVQAN9L6tBYFvsp3e7K13TEhtidySNONWRjTPlZBugXqApkclVMAm9jPpoMpQ8TrZg3QTbvLwf2hMpSK5h3o+2AYN/7V8QHQRS3Jx+0DdvAS1N7P4ONqC8U5IBKnFJmuzWXT5CdyoKrgCJ+/IhnI6CH0reXeJtJfKWHdV5M0ipT2DDEt3KSiaHWDq5AxORR5c+XYffoLO/C6vjX1z7AVbW13qyeza8ROrE8279oyTYjdM4gHEO4Bkw40Gy4ar9mu/RVeNapR9emo38acjtg4R8p1hEMh1Clznp+7PpoI87zaut9uytiG1Lay6JUNZ0rKpHVgk0TtCtmrMDJKYVXAqYgQRKGAMIj7tBXZauhqZshuliKM3WwqMfDwjwSVCzXDzRCEMiJigPR5cu5i/RzoLHqMuH4RS9XQqFIx0CWcUfoFjRJCvimByJwBPAmTwA8sdx7aDZu/end5c67opbag5kUWrYjoZzyj5u3Ae6fS5AqYOXYD+mg0Xu2rZhvos/T1htvsmasrk0u/Qk6gjjEFoKJEUToLqCquBEzYWUKXBTCI5yHbQRa8PmhKotl4idF0JWkYMfNw68u3eNhUKp0lAi3WQ5EESj+wdBv7xXPyhae/U5t/GO9ahmUL9VDQNA0DQcG+iP2DoLdbn3crCxXhvQl0qDWbpTkLR9MeVO4S6iYdUzRE+Sj6+wob9usS1Cqij76V3uJ3m20uHcVdqtLq1LDNTGbIgkTgRyTj7IaKuc3E7MrL7oJWJmbospJZxDIqN2wtHYogBDmyOQD17hoIbboqDgfDFpmKuVtfTVZTNUvBiJA0sfziYtylFQAKU2MDyKHfQd2idtWxK+tIxF4rwXGjEK2rFonMzyZKlSbFI9WDkqAJCPse0I+z7tBGJK3tqbYeJNQ9H2XlUpGlWs/FHauE3pXZTGMUpjh1C9hwYR0E59+F990tuLoUzRVlKTdylMzkeUsssjDHdgQx1hIcOoUPZHh3/26DTe6GHa+GREQFYbYyHYyVeKqNpkZYfNlMVIoHKBANjj7RzaDVPh3Oaxht17y+d5IpzTURUke+fKzUo2MzYKruTAcOCp8E9rIiUAH00HfvLY024zxHnLw9NS83bepJFmmrORaRzs1EitCFMJHJAEnY5RKIgPYQENBnW5Fhe/ZVIrWD2i0LNSFBVNEmkpM54tWRP5xcToqgCwB7P4tNP2fdnOgh9aC+25zZIrKKwVMOacGsBT6vy5DnL1xQE2OnzAPTqDnHxDQT92V70dx9b3HXJuhSZ0vRSsMo5ZSUjG/JrdZ0JydMpVlMFMIkE4gUB7gGdBh+4LevvehLw1LF2PpY8/RDd0BYeRY0+d6g4S4hkxFiAJThyyGQH3aDss9v5d2m3isdy+6OmJmPuTCRsgi0ICZ2CYItkROiYURDv7Qj39+gghtx3fXf2zN5eHto9jUGtRLIi+821BYR4ZKHER9Oxh0Fl1C+HvsPu2ROQiajNNTb1sSRkG8dUJVDpnUADHESFyJQ5GENBrPfL4d+3Wwu26o7m0DHzKU1GrNCIGcPzKkAFFykNkoh37COg8Dw87F7QJC3tK3ouXXUfF15DzJ3iSLmeTbAUyCuUhMkYQ7dg+3QbF8QjxBa1tLVtPwNga3paWipSLVPIHR6b3gqJhLjkUR4+z7tBFTw2tsdr909eVhFXWbP10Itim8QBo5FEeodQQNkQ9Q0HiNKN3F7Uty1V1DYe1tTqIQkrIxcU4XhF3SZ2fUMUg544PkoB7Wg1tf2P3KXZrKavNdi2dQtHjtNM7938iLNm6ZE0ykKI5LgocShoNh7Rr8bnrfUm8oK1VMOX1FTktibdJw53BUuoUiauVQDBMJ9+/p66C2PaNYfbHZ5zUTywFTt5V3MJNwlypTBHvT48hLkCj7HcxtBHrd7a+l9h0HIbp7CJOGtcz80LF4rIqi5bik6EyivFMewDyIGNBWNdla916piW3A1pRsqqhMmK4dSzeLUTYiBQBMBA4BxAPZAPX10Eo9k1kNttUWJlrsVnUrdvcyn5NytAMTSxEjrLIJkUbADcRyplTtgPX00HFUeJRvyopZqzraGbQRH4iRAJGnjNxWKAgBuPPGcAIenx0Fjm1zaLaC0ckne2kGcinUtWw5DSJ1nQnSEXAkWU4k/zfbAMfVoOrT23jafGbqzXgh6saqXUF26WGPCaIY/VUbKJqh5bPLskY4492M6CH/iuflDU9+p7b+Md61DMoXaqGgaBoGg4N9EfsHQXMPLJstxOw2lbPyNRHgm8/SFO835UgUFHolbLh7IiADkUgL6+/WJahE1fwuKX26IqX8jbyuJx1bso1OjGGYppg9Oz/ABwIiYDiJQMJMZABxn00VKLZTvQkd1lK1VUU3RDalz08uRJNEHgqAuBiCbOTFLjGMaCsPe7v2lt1sFH0K+t61gE6dllnBHCT0ywrYAxMCAlDHx0G67M+ElSl0rUUpcZ1fRzHK1HFN5E7QrBMwICoQDCTIqBnGfhoNNwViWO2/wARehLUR1SnnkI2fi1SvjpAmJ+oBTiHEBEAxnHroLF94++6c2vXSpe3sZbRGokahapuTuzujJCiJlhTxgCiA4xn10GT7x9pEBvJp6l4+SuF82vkJVR0UyKJF+oKpChgcmDGMaD0L27Q4e7+2endvTuulItpAosEiSpW5TGV8snwAeImAA5evroIXP8AfRIeHo7PtIhKEZ1ozoUQTSm13otzuvMB5gRFMpTAXiKvH1H00EwNq28SQ3G2Aqy9T6iW0MvTTp43KxTdCqVYEG5FQETiUBDPPHp7tBE6FN+GCcLJVCX+jj+iw4GSFn/bvPecEc8uXHhx8uHpnPLQbB8WanCUhsyoylUnIrlh56OYgsJeIqAm1VLyEPdnGdBGywHiq1TZC0NOWtZ2UbzCMA2FuR6Z+oQVgEwmyJQIIB6/HQbAJ4rtQ3ycEshI2cZQiNeGCnVXwSRzGaEdj0hVAgkADcQPnAiGcaD2XHgsUWkgqsG4NyIkIY+Pk1LvgM/nNBgPg6xoQ+5m4EOVcVisoJdsCnpyAjshc4+vGg3HeTcU53gXoqjw/JWnkKYjnMks3GpU3PWVL5P8cA9EwAHtCTH0u2dBXRu32/s9td6H1qYmp1KgRatm7grwUQTE4qF5ceJRH09PXQacFhID3Mzcdvimb/u0EiNl27uY2j1NUFQRFCJ1MebZptDpHcGS6QFNy5ZKUc6Cx3aB4mE3udvQ1tTI2pZwKbhm4cmdJvzKmKKZc8eIkD10G/t867UNp1zkhWSBT5BW9gTABvd7tBE/woCgbZtdH2cj8qPsdv8A4CTQYn4KSLhGqbtdZFQmUmGOZRDP4xb46CcW7zbpCbq7YJ2zka1CnyIySUh5lNMixsplMHHiJg/xf7NBWtuO3GylhbU1L4fEXRxJyMg0SxxKnFUxFFgOcrjn0ilEoYE3H6Xu0EOLGM3qd5qGMdquUoVCwERFMwAAdcv1aC7neRstpzd+4pB4/uSNODSxFwKVFBNfr9UUx75MGMcP9ugkzS0KSnKaiqfTcCuSNZotCqiGOYJkAvLH140EXqZ2CxdObwzbtSXEdrOjvHjv5GFkUE8rtVEBDqcs9gU5enu0EVvFc/KGp79T238Y71qGZQu1UNA0DQNBwb6I/YOguLqW0VV328PymrWUTPows1NUfTflnyyhyES6QNVjZMT2gyVMxe3x1iWoVNXDpev9mG5em6dufWbmpUIB7HzL5Jk8VOk4bdQDmS4qCACIlKIYHt30VLKsKZkfFBcI1ftseEtywowpo+SbyAi3F0ot7RTFBtkBAAAQ9rQYIbwVb7mETGudRwiI5ER8xkf/AOTQeu38IXdGzQI2a30g0UUigUiabx4UpSh6AAAXABoIzTtNTuyzeFBEujLjUzqjZFjKPVmKhjmXTEoHApRVwOcDjvoJy1X4v23Gp414g7s7UTh0q1VQQWcINTmTExRABARMIhgRz20FVLqvayVcKqJ1ZMlKocxgDz6vYBERAPpaCymDv+lvzszS2z+1hpemqyiItm5czUkvxbqgzSAquDJCKgiYRyGQ+3QQ0qTancBHdcG2GXq2PeVO4eINDSiiih0BMoiVUoiJg54AogHpoJkxNz4nwyrX1JtZuqwc1LP1i3dTjWQhMeVSScpeXIQ/UEpuQGRERwGMCGg7fghnBR3d05fQ3yUb7xcaDbnjNfkvwv62Nf8A0C+g9fb3WturIeHbSl5KxodvLtYaJKq5IizSO4V5uRTDAnDv3MHqOgq63pbgqL3C3nTuPbemndNsEo5u1I3UTTSUKqmJhE4dMcB6h39e2gyvajtZvlu2iKklqOuqEUlTZ0k3BZB85yoKhTCHHhn3FH10G8PBwj3ETuVr6LdK9VZnArIKHDIgYxXRAEe/xENBsC+nhVXxuTfGrrrUpcynIpGelFnzYplHBF0iH9wiQvYcfAdBFWNsTVdiN+NvLXXGnmlRyKVRQ6q7ghzqpqJqKFECj1AyPbtgdBZpu/3aWK2pzEfSFXWoNJuqgjlnKCsexbAVMMiT2uWBzn4aCsTYzuotltoreqqluNQ7uomk22Kk1RbooqCkYFBMIiCg4Dt27aCeGz/Z3VsduJHeS3nIZKk65QdzcfEJlOV22Qf/AIxJM4AXgAlAwAOBx27aDz933hxX13CXwqS4dKXTiYyBmk2xE4504cBxBNEhDAYhQEuBEoj+3QYpbK7cJ4UEG6sTeaLdVZJ1OuNRouYECi3I3OUEemfqiUeWUjD6YwIaDJI/xjNt0P1RiLN1IxMsGDi3Qapif4Z4mDOgg1aWh7n71dwtUQNu69dwIyar6eQ+UXqwETQFXIJiBBHAgBw9O3bQXPbarAEtPZWm6AuC2hqgnolFQjyRFsC3XMZQxgHmoXkOAEA7/DQezeyjqTZWerZ4ypiKQXRp9+omqkyTKchgQMIGKIBkBAfeGgpW2mbZL37tAqV9R11PkpOlVW4uSyD5xlUFOYhx4Z9ATH1+OgtJ2k72qIvfWLmw8NTc21maOiB849diQUVxbnIgcS4MJu5hyGQ9NBLHQVQ+K5+UNT36ntv4x3rUMyhdqoaBoGgaDg30R+wdBfVtv/J6tl+qEP8AwaesS1D811twsRc2dNU9f2sp2elTplRM7fMyqKiQv0S5H3BoqJO6raxf2nalpwuxmJSouDUTMaoEYV+nHkcLAcOBjlyHMQLnQTziSOkotmk+PyckQTKsIjkROBQ5d/tzoIX2otlvWit583WNcVHJLWkWfSKjJopLkURKicDdAAQAchjt2x20EKt8ErQsH4l5Ze5rVNzSrRzEKy6SqArEO2BEnMBIH0gx7tBPKytD+HxuBpSXra2Vn6VfxUIsdF4qrB9ESGKTmIAUwAI+yOdBD/cFaeye8tpGQGwWgYYJamFlVqhBJmEZ+JP7KXtKY5+0U3poPGvhevbzZ2x0VRliSp0jfWm/KRE/IRTA7dx1kS8HhPMgGDgKhRyID30E0NgNvKIuZYmhb/19TEfO3GeC5WcVM9RBR+odNwomQxlR7iIEKUofUGght4tTqGZ7yqDeVGmRSKQgI5R8QxOQGQB6uJwEvv8AZz20GPbrt1VmKfLSZdiE85opRcVwqf5FZqR3m8dPodTIB1OOVcfDI6CaW82yV2N0Ozu39O2+ZEmagMtFyrsXLoiQnJ5Q4HOJjiACPI4ffoIYrbHfEtXt+Fq1nDo9IgmCQQw1Cj5XgBuQF4csY5d/t0EWLi7aLtWsurF2ZrKEQa1TMmbFaNiOiKEOK5+CeTgOAyOglFbzYp4k1p275pbc7inUZISmdkYVAikCwlAQDkAG74AR+/Qarr6xm8fZI3C58xIvKRPULg0ad/GSxDKrnMAqCU3ARHA8RHv7w0FhNg/FD24QNm6Sh7o3Hl3NWNYxJKWVVjl1jncAHtCJwLgw/XoIUX83KWnrrxAaXvzTk04Xo+Mkohw4dmanIcpEDFFQemIchxgfd30Fo1Ezu0TfY1c1gxpSKrUtOqfJ4uJWLEp0OQc+JeoGcDnPbQR738eHmjXNJ0012u2fpyOk2z5U8kZqKTMToiT2QExhDl392gkzD1/TW0fa3RL29rs8UlTkJGQ78UExcdN0VEpBKHDOQ5APcO2g+tT3Cebjtrk5WO2ebdKSE9HuEoF2Ai0VBwQ4kEQE+BIIGKPcdBVTdXYV4g1fmPWV1mp59eJYmDzT6dRWUSbkycShk2cB3HGgxvYTXu0638lWQbp4BhIpOUmxIkHUYZ5wUKY/VxxAePqXQaOeXVmLf3cqWs7GVI+pps7fuyRy8cYzcxWR1REhAAO4F4gXt9Wglra6hPFKvFQsXcahbl1G7g5ghlGiylSETMcpTCUclMbIdyjoPRrOwXisMKRmn1V1xOqwrdgurIENUqZymbFIIqAJeXtBxz20Gy/BJIc8Td1L/PMpGl/bxXDQdOwVA1J4f996vv7uZaEgKNqgryIjXbVQHh1XKzgFyFFNLJigKaRxyIY7aDbtjWO7C5G7aMvvGVdKvbATa714xQVkwKkdqdoqRDLYR5BhYSDgQ7CGdBp7xXPyhqe/U9t/GO9ahmULtVDQNA0DQcG+iP2DoLb7s3WrSyXhrQ1zbeyCbKfh6Ppjyi6iJVSk6p2aR8lN2HJFDB+3WJahCChd5PihXMgi1NQERIT0UZQyJXbGnElEhOX1LkC+oaKyH+sR4u/6Az/+qyf/AIdA/rD+LwI4+YNQf6rJ/wDh0GY7It7G6q5+6tlZm880iVskk9JIR5oxJuskukmI8TCUAEBAQ7hoJiXf2Cbar6V08uPcWk3r2cfkSTXWSkVUimKmUCl9ko4DsAaDJLb7cbV7bbZ1TTNqYZeOYSSDl44Is5OuJlegJc5OIiHYA7aCk7ajX+6yhKnqpfa3CP5J66KUsqVrGFeCRMFDcBEDAPHvnQWh0n4be3K5FMRVwLr0FJ/PSo2aUpUHKRWRH5QWKB18pgOCe2Y3sh2DQdu9F5LJ7M9vFR2jstcOEhKrpBkYYmGcuwcOk1VT9XAkUyJhEDibv7h0FVVal3c74pNO6j2iZisjxqJYUH0VFgVJMCCKnTHphjkHVEfj3DQatuHZe7NoTR5rk0DM02MmJ/JfKDYyXXEghy459cci5+3QWy+HVczenVNZNqdvjTsk0oBpTAGiXDiII3TOcoplRwqAAJsp8vt0Emd7N0KvsztnrK5FBPk2c5DooHaLKIlVKQTLkIOSm7D2MOgpPp++dxdwm6+3VeXOlUZCXCeiWYKpNyIl6RHJeIcSgAe8dBaxvxuPvEoWdoxDbBT8hIsXiSwzBmsSV4BDAcgEyJgHj2E2g1T4w60gvtkt64liCR8pOIHcgJeIgqLQ4mAQ93fOghvsf2WVXdm7lKuLr2pqFW28u1cOFJDpqIN1C9EwpGBUuOwmAPt0E9r0eFztmhrSVfK27t1LL1Q0hnS0QknJLqnO7KmIpgBBN7Q8sdvfoPh4Slq7j2ithWzK51GylNLupZNwinIoCiY6YJdzAA+4NB5G/rxCU6Bpem1tsN3qckJVd+slKEagk7MRIC9slMA8faz30EYbL7xpvdZWyNpN6NexP9GzpFR256hE2AeZSDKX40gAId/dnvoNmW23QSds94FMbXNudZxrqygSrZsyRRKR3zIuQFFwBwbJx/GmP7+3poJBb2q63jxF4Kco6xdMyUjQkxGpJTijaJK4IBlFzkVAVBDJR6WPQe3roI0b+PDtjKJjKNc7YbS1DIu36jkZsGqirsSYKQSZARHj3E+ggpXu3a+FroQtSXDtfUFPxh1ityunzMySYqGARAuR944HQW77eZy6FNeF/AzdmGKzusmsUqaLRRbAuc6nnDAOExAQN7OdBlFkaw3C1ts4uLL7lYl3H1SVpLIpJOmBWhxag1yQeAAHbIm76CrnZ1cTd9Qp6mT2uQEhJIPF23y0LWKK86YlE/TzyAeOQE+gnf4ujiWd7QqBdT6Zk5NafYqPCmJxEqws1hOAh7vaz20Endhf5Itsv9Ck/wC0Ogg94rn5Q1Pfqe2/jHetQzKF2qhoGgaBoODfRH7B0FoG7P8AyS6X6n0j/EMNYlqHkeHbWb+3Xh2VHX0Y3ScO6dCak0EVs9M6iKfMpTY74ES98aKjl+GrvvjvbOjc4/8AhH/j0EqfD+38XF3bV5UVKVlScHFN4eMK+SUj+ryOYVALgeZh7YHQRn2yjnxcKw/01Pf+Y+gt6VkmDc/SXet0zh6lOqUoh+wR0EHt9W924Fhbj07bGiqVh5qLqmNAXjpYFFDoioqKRgKKY8QwXv30GlLlMY7wombGuLKPy1e8uKJm0gjOKFMRsVIAUAU+jxHuJxDvn00Ep9we7SurUbRKV3A07TEbITc83jFVmSpFDIEFylzPxAo8sAPpkdBU1GP3e+XeZHK3HbBTytdyCLd6SNKICgCaAEASdTI5ECB6/HQXIWQ2+03sosbVcHQkxITSSJ3lQAeT48hWBAocPYAPZ/FF+vuOghZbR828WpzIpXwdJUaFsTkGN+QlAL5rzYiCnU6wm+j5cuOOPUdBZrSbaBpKmIml2cygshEMkWSSii5ORipkAoCOB9cBoMWvra6k9w1rZu0M7UB2bKfImmqsyWTFYvBQqgcQHIepQ92gpG3XWbhNkO5yCiaCknk2nBpsJ9E0nxydYqgmAhuAB7OSB9+g3eHjV32AMf0Z0b//AF//AB6DS26Xfvc/d7R8ZQ9UUVCx7eKf/KJFIwiplBNwMTA8hHtgw6DZFp/FPvxaS29PW1hrWwLxjTrJNigu4Qc9RQhPQTYMAZ0G37L+LBfW4t2qPoOdtfTjGOn5lrHOnKaTgpkUlVAKYwCY2AEAH39tBu3fvvtrbbTXFM0NRVPQEyzqViY7lZ2c5jpCZTp+zwNj0HPfQQm38bEqJ2x0fS1X0RUM/MuqleKEcpO0yGIkHT5+zwKA+o476CEasc/bl6jhkukTOOR0jFD7xDQWleHNsdtZUNGW93QyVcyjaoWr9d2EcCqJW3JFc6ZQEBDlgQKAj30FpIS8WYeJZJqIj6AC5e/+3Qdo4+yJg9wZ0FHu+re5dW+hKhsTOUJGs4eBqVUW7xqiv1lAbnOmTlyES9wHI4DQdOx/ijXg2/Wug7SQdv6adsqfSOkks9KsCxgMcT+0AGAPU3w0GSVh4w97KypSYpN9bmkUW8yxXYKqJ9fkQipBKIhk+MgA6Db3gkKmJFXcWD1KpGnxn3gVcdBGzepvmuTuXZO7Pz1Gw7NhTtQqukV48ipljil1Eg5ZEQwIGER7eugte2HOm6e0+2bI66ZXAQxAFETgBwHJuwl9dBCPxXPyhqe/U9t/GO9ahmULtVDQNA0DQcG+iP2DoLQN2f8Akl0v1PpH+IYaxLUMH2Yf5LG4mf8A3MqL+HNoqoEQz8Pv0FjngoY/plrkMhn5vp+//wB/LoPvtl/yuFX/AOmp7/sn0GGb9KMnLo+Is4tjDzh45eo1YqPRWMc/TSMogQOQlAfT7NBtxjXzHwtKek7I3Ojj17J1sirLtJBiIEI2TMQUQIPVybPIue3bQVeyEk5kF1FFnCyhTHMcpVFBNjI5946C+yU3CwG2TZJba5VTUu4nWZYKFZeUREoG5KNi4N7XbtjQaMsnttldy+4OnPEGpycYwdOTD4r1On10RF0mVuTyxiicvsZEyYm/boNxbwt61M2erlvtzkaOkX8jXEOVNCQRWKVJuLo6iBeRR7jgQyOPdoIkI+C3dkomXa3phEAWHmIEbLF9e/fA/XoNMbs9gNxtp9uWdxaguk0mm7yUTiwbtesQ5THIc4GyYcY9gfv0GPeGzIyC+8+3SS79wcpnLoBKZYwgP9lV92dBYFva8N2td015AuZA3BiIZsEYgx8s6bnOfkmJhE2S9sDy0Fcm7zZfUu0eYpmHqKsI6bPUqaqiR2iRiAkCZilHly/8rQWReH7sCqXbXVjy5VTVdETjSoYBNuk1RamAyQnMRQBETdvQMdtBJzcld2ldt9opa7czSISzSJUQIdq3STIc/VUKQMCIY7CbQV63j8Wi1dx7VVbQMVZyVjntQRDqOQdCqjhBRVMSgfsGewjnt30FZTaScFftnrpZVfy6hD4OcRHBTAOAz9mgvo2d75KK3eS0rScPQL6KUpxgi4UUenTVKcBHh7IAHbuGghj4ie+ihLlU1WG26Jty7jpSDqIWh5IxkumoLVYxTCAFDkAGENBoqkNjFfVFtPcbpmFxWrWHatHTwYriqCvFFUyYgAgPHuJRHQR6t3LSZq/psDybrAyzQByub86X69B/TmxH+xNx7/3RP+yGg+ZoqLOYTnjmwiYciIoFERH7tBRxujs68vh4kdTWkhJBrEuJ2TSQScKpD0khBoQ2RKX7NBq2/uz+o7C33p2xUtVbCSfVCRmdJ63SMVJPzCophkB7jgQzoLY9heyyp9oEVWyVRVcwnBqcrU6XlETk6XSIpkB5euef+zQVn7a9z1M7VNzlf17VNJuKhayHyjGEboGIBiHM7KcDe32xggh+3QTl2ybYKmuFuPp/frH1a2Z0xUR3ssjThyn66JHDRVuUgiHsdjHA3YPdoNWeK5+UNT36ntv4x3rUMyhfqoaBoGgaDg30R+wdBaBuz/yS6X6n0j/EMNYlqEfdim+jbZZPbYa0V3hkHDly9eGdtCxvmG6zdbAcTZ7GAQyAgOitk/1xfCp/6F4L/UtD/wAOgyOifEN8Om2z1eRt9RpKbdOkwSXXi6YI2OoTOeJhIACIZ740EZNlFaQlx/E7la9ps6p4qeezT9mZUnA4pKEOJch7hwOg8Lf3AVrVPiMuKety/VZVNIKRSEU4ScCgZJwKBOBgUDuQQH3hoJxbXtoVfBbap2e8GChq9q9ZdUsK/nVSyyzZuKOCkIqqAiQvUybAe8c6Cqzcps5vHtibxsvcxhGN2s65WSZeTdgsOSdxyAenYQ0GYXjshvBpPbpT9fXNrqRkLcPkmJ42PVnVHCSRFE8oACAjguC/V20FrXhj/kVW8/8Ak338YroNC+Ilsj3A7hr6wVxrSIRwNIuCbswXWfg3VTcprqnyX39gOUQHQQ63DR+/fa2enwunfCrkC1CZUrLydVOFs9EScwNg3b6ZcaC5elaMpO7Fl6NaXOpuNqpFWJYvTpzDYjspnHQD8aIKAOT+0Pf17joO3TO3mxNGTbapKSs/R0NKsxEW71jDIIrpCICA8TlKAhkBEO2gy2ramjKLpiWq6aMcsfDMln7oxC8jAkmQTGEA944AdBBGuvER8Pi6KjY1c0qNRu2hTJslJSmiOTICf/AJ88cjj0+Gg0OvtK8TmXWUlaXu9Nt4Z4cXEekWsF0yptjjySKBANgoAUQDHu0GrNyW27f3b60ctVN8blS0vSDVRAr1ovU6rwhzGUKCeUjCIGwYQH6tBzZ6+Gz2ndm1QW6rqhI55dF2xkkmMkpAprKkWUAegIOBDkXiIhgfdoN8+EJZy1FyrY1s+uDbam6jctZlJJBaUjUnJ0yCkAiUonAcBn3aDfm8DaRcF1TsEGyqFibfTRXagzDmBWLDKOW/H2CHOiBROAG74H00Gpt+dhKUt5sTjJyeoSnk7jJfJKU5PJM0zPXT0QAHCh3ABzUMc+RMYRyI99BV00vFdePo09u2Nxqkb0uch0zQ6ckqVmYpx5GKKIDxwIiIj27joLPvCFs9amvLI1DUVa24pudlGNUGI2eSEakusiUqCRigU5wEQADCIhj36D2vGEuhci2MNbU9uq7nqaF64kCORiX6jXrFKVLiBuAhnGRxn46CCthKs3tbkK1PQNtb6VotKps1HwldVO5SJ0iCAD3E3r7QaDf1u9qO4/bPeWN3a7k3yT+CpZcX07JfKYvnpyGIKRRwPtHHJih6+mg9ncTaO4niI3LY7i9q50jwEQ0RhwdP3PknKT5A5lBEhfUMAoQQMHv0HSLs88VBIQOveSdFInc4fPRcfZD1DHL4aDX2+G8e1SsrRQlC2pothHXDh5ZIJ9+jCkbKLimioRfkuAZU5K4Ec+o99BaFsLDG0W2Qf/mUn/aHQQe8Vz8oanv1PbfxjvWoZlC7VQ0DQNA0HA/RH7B0FvlI1dtXuntXo60N3rl0SvDL0tDNpWKc1Mk0WFZukicCHEipFExIqmXIZAREuB7euWmB/wBUzwl/+cUB/wBYjj+c1Cz+qZ4S/wDzigP+sRx/OaFn9Uzwl/8AnFAf9Yjj+c0LZhai1HhsWQrNtcG2NTUBDTzNNRJF38+zuOJTl4mDgs6MQcgPvDQt7FRUr4fFV3fbX4nq1t+6rhmsg4RlPntw4qIgAJj0iuQSHAAHqXv786Ftxf1kNvX/AE50B/rIz/3mhbWF70diO4xlGR947g0BPt4dQ6rInzyI16RzgAGHKDggjkAD1zoW+lcm2LXItfG2arO4lASFHxBG6bOO+eREukVAvFIOomuVQcB27mHPvzq1JcMithcHaHZuiY63dubs0BE0/FAcGjT52IL9MDnE5vbVWMccmMI9xH11C2U/1kNvX/TnQH+sbP8A3mhbVV747YVuNGHG8dfUBP8AyCKox/8AxzK16PV48/7hwTlngX6WfTtoW2RCX32005DsoCHvXQDdjHN02rZL5zNTdNIhQKUuTKCI4AA7iIjoW7v9ZHb3/wBOdAf6yM/95oW86pL37Y6ugJGl6gvRQDqMlmqrJ4h852pOqioUSnLyKqBgyAiGQEB0LRoJtP8ACaTOVQjmgAMUQMA/0iOOwh/++aFpQR+4HbhFsW8axvdQCbdqkVFEnzlaDxIUMAGRUyPYA9dC2M3VrnZ7euintvLl3WoCXgJAyZ3DT52IocxIYDFHmksU4YMAD2HQtoP+qZ4S/wDzigP+sRx/OaFtwWSU2M7dYmQg7PXFoCAZSi5XLtL54kc9RQAwBsrrnEO3uAQDQtsr+sht6/6c6A/1kZ/7zQthd3Ks2aX1o9WgrpXSoCagllk1ztfnci3yoQclHmisU4YH69C2if6pnhL/APOKA/6xHH85oW3PZWS2RbeKdd0paC5NAQMW+di+cIfPBNzzWEoFE3Jdc5g7FAMAOO3poW6F8Gmw7ccjFN7yXAoCoE4Q6p2JfnmVr0jKAAHH8Q4JyzxD1z6aFvBs5bvw5bBVWat7T1bQEHNHbHZmc/PkXP4k4gJi8VnJy9xKHfGe2hbZdxrlbSbs0ZJW/r67lASkDLpgk8afOpBHqlAwGAOaaxTh3APQQ0LePZqc2U7fqWVou0tzKAg4ZZ0d6dt870nOVjAAGNyWXObuBQ7Zx20LZ2puN28KEMQ98qAEpgEB/wCMjT0H/wDaaFowym17wpJqSdy8k+oBZ2+WO4XU/pCXLzUOIiYcA8AAyIj2DQtv2h7o7U7aUbGUFQl46Ai4WGQBuxbfOhusCSYDkC8lFRMYPtHP16Fq7fEsrmj6/vpBy9F1RETrNClW7ZZxGPU3SJFgdOTCTmmIhniYo49cGDsGtQkol6qGgaBoGgaDjBf8IfdoHEv+EPu0DiX/AAh92gcS/wCEPu0DiX/CH3aBxL/hD7tA4l/wh92g9ilKVkavmE4eMIQDmATqKnD2EiB6mN/3e8R163RejcjrvLjicbzPeZnxEe8z+nvLyutdY4/Q+JPL5HjxER5mfaI/X2juke82L1hGUgFUyELUiLPpAsLwWyXAhBD6ZkvplJ9Y47a+50/RL6N7tn7jhz5nf49qv5RFVP3eq/xfDbvpZ9I9Ov8Afc+DEaPPvdfOZu4+/wBNI51fSMjRsweJkipnyXqIrED2Fkx9DB8PgIe4dfEdc6Jyeg8ueLyO/vEx4yj5x/3HtL7bofW+P17iRyuP29pifMT8v0n3fGnqaeVK5ct2S7BuDNqo9XWeLgikmiQQAxhNgfeYO2Pfrx3sOZ6l5CnhaHdKMXTeQTMo0dMnBV0VgKbicCmD3lMGBKIAIdu3fQeSBQH6JAH7AzoAlAPUgB2z6e74/ZoHEPXiGM49Pf8ADQBKAdxKAfaGgcQzjgGfhjQdlKLersHMmkzMZqzOmmuqABhMygiBAH7eJvu0HpQ9HSUxHnl+vGR8eVby4O5F2RskotjIpkE3cxgAQEcBgAEMiGQ1B3Ebcz/Wk0ZFWKigiBQByo/eFSTHrZFISHKBgOBgDICXsIYHOg8qfp19TbpJtIeVUK5bkdt12yxVkV0TZADkOHqGSmD3CAgICGqPUdW6qJmzXcLBG+ZaNvOOY4r1Mz5BDACJzoB3AAKICIfSKA5EA0GM4L8C/doO4aJdFhgnhIl5QXfkgHkHLq9MFPo/DiId/joOnxDOBKGQ7D20DiX/AAh92gcS/wCEPu0DiX/CH3aBxL/hD7tA4l/wh92gcS/4Q+7QAAA9AANBzoGgaBoGgaBoGgaBoGgaBoN0bWZyPg68Fy7bouFElGroiCo4KsRJYDHIP1CGM/Vr9G/Z5Ovbny+JOXpz2a6xn397r7rifwfnX7QsdmvDicuMfVhr2d49u9Vf31Mfitfmr3W8Wg5JzC1s5qBZAoomZIppAmqoYD4TModMpOA8wKIgb0TDHfPLyuN9F+pY8jXhv0Rqie/qmZuIiu8RGUzcVcdvMzfaq9PlfSbp2WjZno3ztmO3piqmZvtMzERU3ETN+I7d7uqvdo9i1q4SYMUkU1Gp1wMVLHEqeSlL6dgAwkMYPt16/wC0Dfhlhw9MzeyMZyn51lUxE/5eV9AdGzHZzN0RWucoxj5XjcTMf4a1oCbZQKs+6egyOK0C6boIvE+okusYyfFMS9uWcCOMh6a/Nn6OyOkrgEdDIISSkZFOEmJG8N5dU0Y3bAK/UXKRVMpzJHUDAicfpceIiACGkxY77+vIkz1EGEsxbouasbOJDyvMpFmxEEAOqImKBhTMoQxh7ByMGePoGkRQ+kfcxRw8iAlqsA6J56WB/wBU2QNHnTICJD9u6IiKnEn0QHIgAakQOsnNwH9HriBGqmq5VKfblbt3DwxRTekUTUOQrYqQETOGFABUxzHU+PtYCj0VriQMhVUo4qSYSkYltVLV1GpHLzTTbARwUyiRAL2IBhRMYAD2sAIgI6V8h139eNmiaqpJ+PUmEIKQRSft36r1UVFVUekn5hQheRgAqhiAAexyEMgOABEUPMnquRlKWmWxKnIZ2/jYNVyQy5imeLpFOVwA9sHVDKfLPcQLnI40geQy+RaopWDhHNSMIZ1BunRVQfGMRNduuoU4qpmABAVC8RKJBxkALgfXFGSzVWU7CwsjDxj2FnytY6DYs1HbcVCPSpGXMoqCQiAkEgqAXiYclAA+OoMRrWdZSr2npll5LKcU2KuxbAJUGiyShwMiUg/3ZRwB8ZHucRz30HpVM1pSUnZ2sxrNudnJg6etmSBzlkRcLAIlQUIJeJClObBj8hKJC9siIBoMkWq+livDuZidZSlOqPYtWLhk0zHUj0kjkFbkkJQBMCkA5DAAj1RNnv66TFjssqxjmyDBKrK7jJ58nKSC7ZymqoJWoKM+DdUynT5EKCmcABRFLIdu2ATCsCuTNNZmSjjJOGbpw2YlRdOm7pR11T8zCAHXUKUVjFKIF5gGMYDI8c6RFIxHVDQNA0DQNA0DQNA0DQNA0DQNA0DQNA0DQfZk+eRrtJ8wcqN3CBgOmqmbBij8Q1zcfkbeJtx36Mpxzx7xMeYcPI4+rl6stG/GMsMu0xPiWef06V0LQGxxjjKAGAcGa5P9uM8c/s19tH7R+tRq+H9S/wCr09/zr/H4Pip/Zz0WdvxPr1/T6u396v8AywWQkHsq8VkJFyo4crm5KKKDkTD/APb3a+K5XK3c3dlyORlOWeXeZnzP/wB/h9pxeLp4WnHj8fGMcMfER4h19cDnNA0DQMj8dA0DI/HQMjoGRDQMjoGgZHGM6BoGR+OgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaBoGgaD/9k=";
@@ -1,9 +1,11 @@
 use std::{
+    fs::File,
     path::Path,
-    process::{Command, CommandArgs, ExitStatus, Stdio},
+    process::{Command, ExitStatus, Stdio},
 };
 
 use thiserror::Error;
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
 
 #[derive(Debug, Error)]
 pub enum ConvertError {
@@ -26,6 +28,11 @@ pub enum ConvertError {
         #[from]
         source: std::num::ParseFloatError,
     },
+    #[error("failed to write zip archive: {}", source)]
+    Zip {
+        #[from]
+        source: zip::result::ZipError,
+    },
 }
 
 pub fn convert_webp(
@@ -312,3 +319,25 @@ pub fn convert_tgs(
     }
     Ok(())
 }
+
+/// Packs the files in `dir` into the zip archive `out_path`, without the directory structure.
+pub fn zip_dir(dir: impl AsRef<Path>, out_path: impl AsRef<Path>) -> Result<(), ConvertError> {
+    let mut paths = std::fs::read_dir(dir)?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    paths.retain(|path| path.is_file());
+    paths.sort();
+
+    let mut archive = ZipWriter::new(File::create(out_path)?);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    for path in paths {
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or(ConvertError::Path(path.to_string_lossy().to_string()))?;
+        archive.start_file(name, options)?;
+        std::io::copy(&mut File::open(&path)?, &mut archive)?;
+    }
+    archive.finish()?;
+    Ok(())
+}
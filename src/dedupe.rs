@@ -0,0 +1,345 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    fs::{File, OpenOptions},
+    io::{self, BufRead, BufReader, Write},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::cache::TtlCache;
+
+/// How long handled events are remembered by default.
+pub const DEFAULT_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Remembers the ids of the webhook events that were handled.
+pub trait DedupeStore: Debug + Send + Sync {
+    /// Whether `event_id` was recorded and didn't expire yet.
+    fn contains(&self, event_id: &str) -> io::Result<bool>;
+
+    /// Records `event_id`, returns `false` if it was already recorded and didn't expire yet.
+    fn insert(&self, event_id: &str) -> io::Result<bool>;
+
+    /// Forgets the events that expired, for stores that don't on their own.
+    fn prune(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Keeps event ids in memory, they are forgotten on restart.
+#[derive(Debug)]
+pub struct MemoryDedupeStore {
+    events: TtlCache<String, ()>,
+}
+
+impl MemoryDedupeStore {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            events: TtlCache::new(ttl),
+        }
+    }
+}
+
+impl DedupeStore for MemoryDedupeStore {
+    fn contains(&self, event_id: &str) -> io::Result<bool> {
+        Ok(self.events.get(event_id).is_some())
+    }
+
+    fn insert(&self, event_id: &str) -> io::Result<bool> {
+        Ok(self.events.insert_new(event_id.to_owned(), ()))
+    }
+}
+
+/// Keeps event ids in a file, so redeliveries are recognized across restarts.
+///
+/// Every recorded event is appended to the file as a line of JSON, [`DedupeStore::prune`] drops the
+/// expired events and rewrites the file with the remaining ones.
+#[derive(Debug)]
+pub struct FileDedupeStore {
+    file: PathBuf,
+    ttl: Duration,
+    log: Mutex<EventLog>,
+}
+
+#[derive(Debug)]
+struct EventLog {
+    /// Unix time the events were recorded at.
+    events: HashMap<String, u64>,
+    /// The file, opened for appending.
+    file: File,
+}
+
+/// A line of the file of a [`FileDedupeStore`].
+#[derive(Debug, Serialize, Deserialize)]
+struct Record {
+    event_id: String,
+    /// Unix time the event was recorded at.
+    at: u64,
+}
+
+impl FileDedupeStore {
+    /// Loads the events recorded in `file`, a missing file is created.
+    pub fn open(file: impl Into<PathBuf>, ttl: Duration) -> io::Result<Self> {
+        let file = file.into();
+        let mut events = HashMap::new();
+        match File::open(&file) {
+            Ok(f) => {
+                for line in BufReader::new(f).lines() {
+                    // A line cut short by a crash only loses its own event.
+                    match serde_json::from_str(&line?) {
+                        Ok(Record { event_id, at }) => {
+                            events.insert(event_id, at);
+                        }
+                        Err(e) => tracing::warn!("Skipping invalid line in {:?}: {}", file, e),
+                    }
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+        let log = EventLog {
+            events,
+            file: OpenOptions::new().create(true).append(true).open(&file)?,
+        };
+        Ok(Self {
+            file,
+            ttl,
+            log: Mutex::new(log),
+        })
+    }
+
+    fn is_expired(&self, recorded: u64, now: u64) -> bool {
+        now.saturating_sub(recorded) >= self.ttl.as_secs()
+    }
+}
+
+impl EventLog {
+    fn append(&mut self, record: &Record) -> io::Result<()> {
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+        self.file.write_all(&line)
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+impl DedupeStore for FileDedupeStore {
+    fn contains(&self, event_id: &str) -> io::Result<bool> {
+        let log = self.log.lock().unwrap();
+        Ok(log
+            .events
+            .get(event_id)
+            .is_some_and(|recorded| !self.is_expired(*recorded, now())))
+    }
+
+    fn insert(&self, event_id: &str) -> io::Result<bool> {
+        let now = now();
+        let mut log = self.log.lock().unwrap();
+        if let Some(recorded) = log.events.get(event_id) {
+            if !self.is_expired(*recorded, now) {
+                return Ok(false);
+            }
+        }
+        log.append(&Record {
+            event_id: event_id.to_owned(),
+            at: now,
+        })?;
+        log.events.insert(event_id.to_owned(), now);
+        Ok(true)
+    }
+
+    fn prune(&self) -> io::Result<()> {
+        let now = now();
+        let mut log = self.log.lock().unwrap();
+        log.events
+            .retain(|_, recorded| !self.is_expired(*recorded, now));
+        let mut data = Vec::new();
+        for (event_id, at) in &log.events {
+            serde_json::to_writer(
+                &mut data,
+                &Record {
+                    event_id: event_id.clone(),
+                    at: *at,
+                },
+            )?;
+            data.push(b'\n');
+        }
+        // Replaced in one step, so a crash leaves either the old or the new file.
+        let compacted = self.file.with_extension("tmp");
+        std::fs::write(&compacted, data)?;
+        std::fs::rename(&compacted, &self.file)?;
+        log.file = OpenOptions::new().append(true).open(&self.file)?;
+        Ok(())
+    }
+}
+
+/// Filters out webhook events SeaTalk delivers more than once.
+///
+/// Events are recorded in the store once they were handled, the events being handled are only
+/// kept in memory so they are handled again after a restart.
+#[derive(Debug, Clone)]
+pub struct Dedupe {
+    store: Arc<dyn DedupeStore>,
+    in_progress: Arc<Mutex<HashSet<String>>>,
+}
+
+/// What is known about a delivered event.
+#[derive(Debug)]
+pub enum Delivery {
+    /// The event wasn't handled yet.
+    New(HandlingEvent),
+    /// The event is being handled for an earlier delivery.
+    InProgress,
+    /// The event was handled.
+    Handled,
+}
+
+/// An event being handled, it is recorded once [`HandlingEvent::finish`]ed and handled again
+/// when redelivered otherwise.
+#[derive(Debug)]
+#[must_use]
+pub struct HandlingEvent {
+    dedupe: Dedupe,
+    event_id: String,
+}
+
+impl Default for Dedupe {
+    fn default() -> Self {
+        Self::new(MemoryDedupeStore::new(DEFAULT_TTL))
+    }
+}
+
+impl Dedupe {
+    pub fn new(store: impl DedupeStore + 'static) -> Self {
+        Self {
+            store: Arc::new(store),
+            in_progress: Default::default(),
+        }
+    }
+
+    /// Starts handling `event_id` unless it is handled or being handled.
+    ///
+    /// Events are handled when the store fails, a duplicate is better than a lost event.
+    pub async fn start(&self, event_id: &str) -> Delivery {
+        if !self.in_progress.lock().unwrap().insert(event_id.to_owned()) {
+            return Delivery::InProgress;
+        }
+        let handling = HandlingEvent {
+            dedupe: self.clone(),
+            event_id: event_id.to_owned(),
+        };
+        let id = event_id.to_owned();
+        match self.blocking(move |store| store.contains(&id)).await {
+            Ok(true) => Delivery::Handled,
+            Ok(false) => Delivery::New(handling),
+            Err(e) => {
+                tracing::warn!("Failed to look up event {}: {}", event_id, e);
+                Delivery::New(handling)
+            }
+        }
+    }
+
+    /// Forgets the expired events.
+    pub async fn prune(&self) {
+        if let Err(e) = self.blocking(|store| store.prune()).await {
+            tracing::warn!("Failed to prune events: {}", e);
+        }
+    }
+
+    /// Runs `f` with the store off the async runtime, stores may block on file I/O.
+    async fn blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&dyn DedupeStore) -> io::Result<T> + Send + 'static,
+    ) -> io::Result<T> {
+        let store = self.store.clone();
+        tokio::task::spawn_blocking(move || f(store.as_ref())).await?
+    }
+}
+
+impl HandlingEvent {
+    /// Records the event as handled, so redeliveries are dropped.
+    pub async fn finish(self) {
+        let id = self.event_id.clone();
+        if let Err(e) = self.dedupe.blocking(move |store| store.insert(&id)).await {
+            tracing::warn!("Failed to record event {}: {}", self.event_id, e);
+        }
+    }
+}
+
+impl Drop for HandlingEvent {
+    fn drop(&mut self) {
+        self.dedupe
+            .in_progress
+            .lock()
+            .unwrap()
+            .remove(&self.event_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use temp_dir::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn drops_duplicates() {
+        let dedupe = Dedupe::default();
+        let Delivery::New(handling) = dedupe.start("a").await else {
+            panic!("a is new");
+        };
+        assert!(matches!(dedupe.start("a").await, Delivery::InProgress));
+        handling.finish().await;
+        assert!(matches!(dedupe.start("a").await, Delivery::Handled));
+    }
+
+    #[tokio::test]
+    async fn handles_unfinished_events_again() {
+        let dedupe = Dedupe::default();
+        drop(dedupe.start("a").await);
+        assert!(matches!(dedupe.start("a").await, Delivery::New(_)));
+    }
+
+    #[test]
+    fn persists_events() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("events.json");
+        let store = FileDedupeStore::open(&file, DEFAULT_TTL).unwrap();
+        assert!(store.insert("a").unwrap());
+        assert!(!store.insert("a").unwrap());
+
+        let reopened = FileDedupeStore::open(&file, DEFAULT_TTL).unwrap();
+        assert!(reopened.contains("a").unwrap());
+        assert!(!reopened.contains("b").unwrap());
+
+        let expired = FileDedupeStore::open(&file, Duration::ZERO).unwrap();
+        assert!(expired.insert("a").unwrap());
+    }
+
+    #[test]
+    fn compacts_on_prune() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("events.json");
+        let store = FileDedupeStore::open(&file, DEFAULT_TTL).unwrap();
+        for event_id in ["a", "b", "c"] {
+            store.insert(event_id).unwrap();
+        }
+        store.log.lock().unwrap().events.insert("c".into(), 0);
+        store.prune().unwrap();
+        assert_eq!(std::fs::read_to_string(&file).unwrap().lines().count(), 2);
+
+        store.insert("d").unwrap();
+        let reopened = FileDedupeStore::open(&file, DEFAULT_TTL).unwrap();
+        assert!(!reopened.insert("a").unwrap());
+        assert!(!reopened.insert("d").unwrap());
+        assert!(reopened.insert("c").unwrap());
+    }
+}
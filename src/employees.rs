@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use crate::{
+    cache::TtlCache,
+    seatalk_api::{
+        api::{
+            ApiError, Employee, EmployeeCodes, EmployeeProfiles, GetEmployeeCode,
+            GetEmployeeProfile, SeatalkErrorCode,
+        },
+        query::AsyncQuery,
+        seatalk::{AsyncSeatalk, RestError},
+    },
+};
+
+/// How long looked up employees are kept by default.
+pub const DEFAULT_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Looks up employees, keeping the profiles it found for a while.
+#[derive(Debug, Clone)]
+pub struct EmployeeDirectory {
+    profiles: TtlCache<String, Employee>,
+    /// Employee codes by SeaTalk id.
+    codes: TtlCache<String, String>,
+}
+
+impl Default for EmployeeDirectory {
+    fn default() -> Self {
+        Self::new(DEFAULT_TTL)
+    }
+}
+
+impl EmployeeDirectory {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            profiles: TtlCache::new(ttl),
+            codes: TtlCache::new(ttl),
+        }
+    }
+
+    /// Profile of `employee_code`, `None` if SeaTalk doesn't know the employee.
+    pub async fn employee(
+        &self,
+        seatalk: &AsyncSeatalk,
+        employee_code: &str,
+    ) -> Result<Option<Employee>, ApiError<RestError>> {
+        if let Some(employee) = self.profiles.get(employee_code) {
+            return Ok(Some(employee));
+        }
+        let profiles: EmployeeProfiles = match GetEmployeeProfile::new(employee_code)
+            .query_async(seatalk)
+            .await
+        {
+            Ok(profiles) => profiles,
+            Err(e) if e.seatalk_code() == Some(SeatalkErrorCode::UserNotFound) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let Some(employee) = profiles
+            .employees
+            .into_iter()
+            .find(|employee| employee.employee_code == employee_code)
+        else {
+            return Ok(None);
+        };
+        self.profiles
+            .insert(employee_code.to_owned(), employee.clone());
+        self.codes
+            .insert(employee.seatalk_id.clone(), employee_code.to_owned());
+        Ok(Some(employee))
+    }
+
+    /// Profile of the employee with `seatalk_id`, `None` if it is not an employee.
+    pub async fn employee_by_seatalk_id(
+        &self,
+        seatalk: &AsyncSeatalk,
+        seatalk_id: &str,
+    ) -> Result<Option<Employee>, ApiError<RestError>> {
+        let employee_code = match self.codes.get(seatalk_id) {
+            Some(employee_code) => employee_code,
+            None => {
+                let codes: EmployeeCodes = GetEmployeeCode::new(seatalk_id)
+                    .query_async(seatalk)
+                    .await?;
+                let Some(employee_code) = codes.get(seatalk_id) else {
+                    return Ok(None);
+                };
+                self.codes
+                    .insert(seatalk_id.to_owned(), employee_code.to_owned());
+                employee_code.to_owned()
+            }
+        };
+        self.employee(seatalk, &employee_code).await
+    }
+
+    /// `Name (employee_code)` of `employee_code` for audit logs.
+    ///
+    /// Falls back to the employee code alone if the profile cannot be looked up.
+    pub async fn describe(&self, seatalk: &AsyncSeatalk, employee_code: &str) -> String {
+        match self.employee(seatalk, employee_code).await {
+            Ok(Some(employee)) => format!("{} ({})", employee.name, employee_code),
+            Ok(None) => employee_code.to_owned(),
+            Err(e) => {
+                tracing::warn!("Failed to look up employee {}: {}", employee_code, e);
+                employee_code.to_owned()
+            }
+        }
+    }
+
+    /// Like [`Self::describe`], but only from the cached profiles, without asking SeaTalk.
+    pub fn describe_cached(&self, employee_code: &str) -> String {
+        match self.profiles.get(employee_code) {
+            Some(employee) => format!("{} ({})", employee.name, employee_code),
+            None => employee_code.to_owned(),
+        }
+    }
+}
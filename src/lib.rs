@@ -1,6 +1,15 @@
+pub mod admin;
+pub mod app;
+pub mod cache;
+pub mod commands;
 mod consts;
 pub mod convert;
+pub mod dedupe;
+pub mod employees;
 pub mod seatalk_api;
 pub mod telegram;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod config;
 pub mod webhook;
+pub mod whitelist;
@@ -1,20 +1,26 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use axum::{extract::FromRef, routing::post, Router};
 use seatalk_tgs::{
+    app::{router, AppState},
     config::AppConfig,
-    seatalk_api::{auth::Auth, seatalk::AsyncSeatalk},
+    dedupe::{Dedupe, FileDedupeStore, MemoryDedupeStore},
+    employees::EmployeeDirectory,
+    seatalk_api::{
+        api::ApiError,
+        auth::Auth,
+        seatalk::{AsyncSeatalk, SeatalkError},
+        webhooks::WebhookVerifier,
+    },
     telegram::TelegramStickerDownloader,
-    webhook::message_received,
+    webhook::Conversions,
+    whitelist::Whitelist,
 };
-use tower_http::trace::TraceLayer;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
-#[derive(Debug, Clone)]
-struct AppState {
-    telegram: Arc<TelegramStickerDownloader>,
-    seatalk: Arc<AsyncSeatalk>,
-}
+/// How often the rate limit and event skew metrics are logged.
+const METRICS_INTERVAL: Duration = Duration::from_secs(10 * 60);
+/// How often expired events are dropped from the dedupe store.
+const PRUNE_INTERVAL: Duration = Duration::from_secs(10 * 60);
 
 #[tokio::main]
 async fn main() {
@@ -33,29 +39,66 @@ async fn main() {
         .with(tracing_subscriber::fmt::layer())
         .init();
     let config = AppConfig::new().expect("Failed to parse config");
+    if config.seatalk.signing_secret.is_empty() {
+        // Signatures made with an empty secret can be forged by anyone.
+        tracing::error!("seatalk.signing_secret is empty, webhook events can't be verified");
+        std::process::exit(1);
+    }
     let telegram = Arc::new(
-        TelegramStickerDownloader::new(&config.telegram.api_token)
+        TelegramStickerDownloader::new(&config.telegram.api_token, config.telegram.api_url)
             .await
             .expect("Failed to create Telegram client"),
     );
-    let seatalk = Arc::new(
-        AsyncSeatalk::new(
-            "https",
-            &config.seatalk.host,
-            Auth {
-                app_id: config.seatalk.app_id,
-                app_secret: config.seatalk.app_secret,
-            },
-        )
-        .await
-        .expect("Failed to create seatalk client"),
-    );
-    let state = AppState { telegram, seatalk };
+    let seatalk = match AsyncSeatalk::builder(
+        &config.seatalk.host,
+        Auth {
+            app_id: config.seatalk.app_id,
+            app_secret: config.seatalk.app_secret,
+        },
+    )
+    .retry_policy(config.seatalk.retry.policy())
+    .rate_limits(config.seatalk.rate_limits.limits())
+    .build_async()
+    .await
+    {
+        Ok(seatalk) => Arc::new(seatalk),
+        Err(SeatalkError::Api {
+            source: ApiError::Auth { source },
+        }) => {
+            tracing::error!("SeaTalk rejected the configured credentials: {}", source);
+            std::process::exit(1);
+        }
+        Err(e) => panic!("Failed to create seatalk client: {}", e),
+    };
+    let mut verifier = WebhookVerifier::new(config.seatalk.signing_secret);
+    if let Some(max_skew) = config.seatalk.max_event_skew_secs {
+        verifier = verifier.with_max_skew(Duration::from_secs(max_skew));
+    }
+    let mut whitelist = Whitelist::new(config.whitelist.groups, config.whitelist.admins);
+    if let Some(file) = config.whitelist.file {
+        whitelist = whitelist
+            .with_file(file)
+            .expect("Failed to load whitelist file");
+    }
+    let dedupe = match &config.dedupe.file {
+        Some(file) => Dedupe::new(
+            FileDedupeStore::open(file, config.dedupe.ttl()).expect("Failed to load dedupe file"),
+        ),
+        None => Dedupe::new(MemoryDedupeStore::new(config.dedupe.ttl())),
+    };
+    tokio::spawn(log_metrics(seatalk.clone(), verifier.clone()));
+    tokio::spawn(prune_events(dedupe.clone()));
+    let state = AppState {
+        telegram,
+        seatalk,
+        verifier,
+        conversions: Conversions::default(),
+        whitelist,
+        employees: EmployeeDirectory::new(config.employees.cache_ttl()),
+        dedupe,
+    };
 
-    let router = Router::new()
-        .route("/", post(message_received))
-        .with_state(state)
-        .layer(TraceLayer::new_for_http());
+    let router = router(state);
 
     let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
     axum::serve(listener, router)
@@ -63,14 +106,42 @@ async fn main() {
         .expect("Failed to start server");
 }
 
-impl FromRef<AppState> for Arc<TelegramStickerDownloader> {
-    fn from_ref(input: &AppState) -> Self {
-        input.telegram.clone()
+/// Drops the expired events from `dedupe` every [`PRUNE_INTERVAL`].
+async fn prune_events(dedupe: Dedupe) {
+    let mut interval = tokio::time::interval(PRUNE_INTERVAL);
+    loop {
+        interval.tick().await;
+        dedupe.prune().await;
     }
 }
 
-impl FromRef<AppState> for Arc<AsyncSeatalk> {
-    fn from_ref(input: &AppState) -> Self {
-        input.seatalk.clone()
+/// Logs the time requests waited for the rate limiters and the skew of the received events,
+/// every [`METRICS_INTERVAL`].
+async fn log_metrics(seatalk: Arc<AsyncSeatalk>, verifier: WebhookVerifier) {
+    let mut interval = tokio::time::interval(METRICS_INTERVAL);
+    // The first tick completes immediately, before anything was measured.
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        let mut rate_limits: Vec<_> = seatalk.rate_limit_metrics().into_iter().collect();
+        rate_limits.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (endpoint, stats) in rate_limits {
+            tracing::info!(
+                "Rate limits of {}: {} requests, {} delayed, waited {:?} in total, {:?} at most",
+                endpoint,
+                stats.requests,
+                stats.delayed,
+                stats.total_wait,
+                stats.max_wait
+            );
+        }
+        let skew = verifier.skew_metrics();
+        tracing::info!(
+            "Event skew: {} accepted, {} rejected, {:?} in total, {:?} at most",
+            skew.accepted,
+            skew.rejected,
+            skew.total_skew,
+            skew.max_skew
+        );
     }
 }
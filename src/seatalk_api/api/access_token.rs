@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use http::Method;
 use serde::Serialize;
 
-use crate::seatalk_api::endpoint::Endpoint;
+use crate::seatalk_api::{endpoint::Endpoint, retry::RetryPolicy};
 
 use super::error::BodyError;
 
@@ -40,4 +40,9 @@ impl Endpoint for GetAccessToken {
     fn require_auth(&self) -> bool {
         false
     }
+
+    fn retry_policy(&self) -> Option<RetryPolicy> {
+        // Fetching a token has no side effects, so it is safe to send again after a timeout.
+        Some(RetryPolicy::default().with_retry_non_idempotent(true))
+    }
 }
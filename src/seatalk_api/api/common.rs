@@ -2,6 +2,14 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
+use super::error::BodyError;
+
+/// Largest file SeaTalk accepts in a file message, in bytes.
+pub const MAX_FILE_SIZE: usize = 5 * 1024 * 1024;
+
+/// Longest file name SeaTalk accepts in a file message, in characters.
+pub const MAX_FILENAME_LENGTH: usize = 100;
+
 #[derive(Debug, Deserialize)]
 pub struct Code {
     pub code: u32,
@@ -33,3 +41,56 @@ impl Display for MessageType {
         )
     }
 }
+
+/// Checks a file message before it is sent, `content` is the base64 encoded file.
+pub(crate) fn validate_file(filename: &str, content: &str) -> Result<(), BodyError> {
+    let invalid = |reason| BodyError::InvalidFilename {
+        filename: filename.into(),
+        reason,
+    };
+    if filename.chars().count() > MAX_FILENAME_LENGTH {
+        return Err(invalid("file name is too long"));
+    }
+    if !filename
+        .rsplit_once('.')
+        .is_some_and(|(stem, extension)| !stem.is_empty() && !extension.is_empty())
+    {
+        return Err(invalid("file name needs an extension"));
+    }
+
+    let padding = content.bytes().rev().take_while(|b| *b == b'=').count();
+    let size = (content.len() / 4 * 3).saturating_sub(padding);
+    if size > MAX_FILE_SIZE {
+        return Err(BodyError::FileTooLarge {
+            size,
+            max: MAX_FILE_SIZE,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::{engine::general_purpose, Engine};
+
+    use super::*;
+
+    #[test]
+    fn validates_file_name() {
+        assert!(validate_file("pack.zip", "").is_ok());
+        assert!(validate_file("pack", "").is_err());
+        assert!(validate_file(".zip", "").is_err());
+        assert!(validate_file(&format!("{}.zip", "a".repeat(100)), "").is_err());
+    }
+
+    #[test]
+    fn validates_decoded_size() {
+        let content = general_purpose::STANDARD.encode(vec![0; MAX_FILE_SIZE]);
+        assert!(validate_file("pack.zip", &content).is_ok());
+        let content = general_purpose::STANDARD.encode(vec![0; MAX_FILE_SIZE + 1]);
+        assert!(matches!(
+            validate_file("pack.zip", &content),
+            Err(BodyError::FileTooLarge { size, .. }) if size == MAX_FILE_SIZE + 1
+        ));
+    }
+}
@@ -0,0 +1,105 @@
+use serde::Deserialize;
+
+/// Content of a message received from SeaTalk, in a webhook event or read back from a chat.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "tag", rename_all = "snake_case")]
+pub enum MessageContent {
+    Text {
+        text: TextContent,
+    },
+    Image {
+        image: FileContent,
+    },
+    File {
+        file: FileContent,
+    },
+    Video {
+        video: FileContent,
+    },
+    /// Several messages forwarded as one.
+    CombinedForwardedChatHistory {
+        combined_forwarded_chat_history: ForwardedChatHistory,
+    },
+    /// A message type this crate doesn't know yet.
+    #[serde(other)]
+    Unsupported,
+}
+
+impl MessageContent {
+    /// Text of a text message.
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            Self::Text { text } => Some(text.text()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TextContent {
+    /// Text of a message sent to the bot directly.
+    pub content: Option<String>,
+    /// Text of a group message, with mentions as plain names.
+    pub plain_text: Option<String>,
+    #[serde(default)]
+    pub mentioned_list: Vec<Mention>,
+}
+
+impl TextContent {
+    pub fn text(&self) -> &str {
+        self.plain_text
+            .as_deref()
+            .or(self.content.as_deref())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Mention {
+    pub username: String,
+    pub seatalk_id: String,
+}
+
+/// An image, file or video, downloaded with [`DownloadFile`](super::DownloadFile).
+#[derive(Debug, Clone, Deserialize)]
+pub struct FileContent {
+    /// URL to download the file from, valid for a limited time.
+    pub content: String,
+    /// Name of a file, not set for images and videos.
+    pub filename: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ForwardedChatHistory {
+    /// The forwarded messages, as sent by SeaTalk.
+    #[serde(default)]
+    pub content: Vec<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parses_tagged_content() {
+        let text: MessageContent =
+            serde_json::from_value(json!({"tag": "text", "text": {"content": "hi"}})).unwrap();
+        assert_eq!(text.text(), Some("hi"));
+
+        let file: MessageContent = serde_json::from_value(json!({
+            "tag": "file",
+            "file": {"content": "https://example.com/file", "filename": "cat.webp"}
+        }))
+        .unwrap();
+        assert!(matches!(
+            file,
+            MessageContent::File { file } if file.filename.as_deref() == Some("cat.webp")
+        ));
+
+        let unknown: MessageContent =
+            serde_json::from_value(json!({"tag": "location", "location": {}})).unwrap();
+        assert!(matches!(unknown, MessageContent::Unsupported));
+    }
+}
@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+
+use http::Method;
+use url::Url;
+
+use crate::seatalk_api::endpoint::{Endpoint, MaxResponseSize};
+
+/// Downloads an image, file or video received in a message.
+///
+/// Use it with [`download`](crate::seatalk_api::download) to get the file content.
+#[derive(Debug)]
+pub struct DownloadFile {
+    path: String,
+    max_size: Option<usize>,
+}
+
+impl DownloadFile {
+    /// `url` is the `content` of a received file. Only its path is used, so the access token
+    /// is never sent to another host.
+    pub fn new(url: &str) -> Self {
+        let path = match Url::parse(url) {
+            Ok(url) => url.path().trim_start_matches('/').to_owned(),
+            Err(_) => url.trim_start_matches('/').to_owned(),
+        };
+        Self {
+            path,
+            max_size: None,
+        }
+    }
+
+    /// Fails downloads of files larger than `max_size` bytes before reading them.
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+}
+
+impl Endpoint for DownloadFile {
+    fn method(&self) -> http::Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> std::borrow::Cow<'static, str> {
+        Cow::from(self.path.clone())
+    }
+
+    fn require_auth(&self) -> bool {
+        true
+    }
+
+    fn max_response_size(&self) -> Option<MaxResponseSize> {
+        self.max_size.map(MaxResponseSize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_only_the_path() {
+        assert_eq!(
+            DownloadFile::new("https://evil.example.com/messaging/v2/file/abc").endpoint(),
+            "messaging/v2/file/abc"
+        );
+    }
+}
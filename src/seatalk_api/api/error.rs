@@ -1,7 +1,9 @@
-use std::{any, error::Error};
+use std::{any, error::Error, fmt::Display};
 
 use thiserror::Error;
 
+use crate::seatalk_api::auth::AuthError;
+
 #[derive(Error, Debug)]
 pub enum BodyError {
     #[error("Failed to serialize to JSON: {}", source)]
@@ -9,6 +11,143 @@ pub enum BodyError {
         #[from]
         source: serde_json::Error,
     },
+    #[error("file is {} bytes, SeaTalk accepts at most {} bytes", size, max)]
+    FileTooLarge { size: usize, max: usize },
+    #[error("invalid file name {:?}: {}", filename, reason)]
+    InvalidFilename {
+        filename: String,
+        reason: &'static str,
+    },
+    #[error("invalid interactive message: {}", .0)]
+    InvalidInteractiveMessage(&'static str),
+}
+
+/// Error codes documented by the SeaTalk Open Platform.
+///
+/// Codes that are not known yet are kept in [`SeatalkErrorCode::Unknown`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeatalkErrorCode {
+    /// 2: Internal server error.
+    ServerError,
+    /// 5: The requested resource does not exist.
+    ResourceNotFound,
+    /// 8: Server is busy, try again later.
+    ServerBusy,
+    /// 100: App access token is expired or invalid.
+    AccessTokenInvalid,
+    /// 101: Request was rejected by rate limiting.
+    RateLimited,
+    /// 102: Request body contains invalid input.
+    InvalidRequest,
+    /// 103: App does not have permission to call the API.
+    PermissionDenied,
+    /// 104: Bot capability is not turned on for the app.
+    BotCapabilityDisabled,
+    /// 105: App is not online.
+    AppNotOnline,
+    /// 1000: App secret is invalid.
+    InvalidAppSecret,
+    /// 1001: App id is invalid.
+    InvalidAppId,
+    /// 3000: User could not be found.
+    UserNotFound,
+    /// 3001: User is not a subscriber of the bot.
+    UserNotSubscribed,
+    /// 4001: Message exceeds the maximum length.
+    MessageTooLong,
+    /// 4002: Message could not be sent.
+    MessageSendFailed,
+    /// 4005: Quoted message could not be found.
+    QuotedMessageNotFound,
+    /// 4008: Image exceeds the maximum size.
+    ImageTooLarge,
+    /// 7000: Group chat could not be found.
+    GroupNotFound,
+    /// 7001: Bot is not a member of the group chat.
+    BotNotInGroup,
+    Unknown(i64),
+}
+
+impl SeatalkErrorCode {
+    pub fn code(&self) -> i64 {
+        match self {
+            Self::ServerError => 2,
+            Self::ResourceNotFound => 5,
+            Self::ServerBusy => 8,
+            Self::AccessTokenInvalid => 100,
+            Self::RateLimited => 101,
+            Self::InvalidRequest => 102,
+            Self::PermissionDenied => 103,
+            Self::BotCapabilityDisabled => 104,
+            Self::AppNotOnline => 105,
+            Self::InvalidAppSecret => 1000,
+            Self::InvalidAppId => 1001,
+            Self::UserNotFound => 3000,
+            Self::UserNotSubscribed => 3001,
+            Self::MessageTooLong => 4001,
+            Self::MessageSendFailed => 4002,
+            Self::QuotedMessageNotFound => 4005,
+            Self::ImageTooLarge => 4008,
+            Self::GroupNotFound => 7000,
+            Self::BotNotInGroup => 7001,
+            Self::Unknown(code) => *code,
+        }
+    }
+
+    /// Whether the same request may succeed if sent again later.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::ServerError | Self::ServerBusy | Self::RateLimited
+        )
+    }
+
+    /// Whether the error is caused by the app credentials or access token.
+    pub fn is_auth_error(&self) -> bool {
+        matches!(
+            self,
+            Self::AccessTokenInvalid
+                | Self::AppNotOnline
+                | Self::InvalidAppSecret
+                | Self::InvalidAppId
+        )
+    }
+}
+
+impl From<i64> for SeatalkErrorCode {
+    fn from(code: i64) -> Self {
+        match code {
+            2 => Self::ServerError,
+            5 => Self::ResourceNotFound,
+            8 => Self::ServerBusy,
+            100 => Self::AccessTokenInvalid,
+            101 => Self::RateLimited,
+            102 => Self::InvalidRequest,
+            103 => Self::PermissionDenied,
+            104 => Self::BotCapabilityDisabled,
+            105 => Self::AppNotOnline,
+            1000 => Self::InvalidAppSecret,
+            1001 => Self::InvalidAppId,
+            3000 => Self::UserNotFound,
+            3001 => Self::UserNotSubscribed,
+            4001 => Self::MessageTooLong,
+            4002 => Self::MessageSendFailed,
+            4005 => Self::QuotedMessageNotFound,
+            4008 => Self::ImageTooLarge,
+            7000 => Self::GroupNotFound,
+            7001 => Self::BotNotInGroup,
+            code => Self::Unknown(code),
+        }
+    }
+}
+
+impl Display for SeatalkErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown(code) => write!(f, "unknown ({})", code),
+            known => write!(f, "{:?} ({})", known, known.code()),
+        }
+    }
 }
 
 #[derive(Error, Debug)]
@@ -19,6 +158,13 @@ where
     #[error("client error: {}", source)]
     Client { source: E },
 
+    /// SeaTalk rejected the app credentials.
+    #[error("authentication failed: {}", source)]
+    Auth {
+        #[from]
+        source: AuthError,
+    },
+
     #[error("failed to parse url: {}", source)]
     UrlParse {
         #[from]
@@ -45,18 +191,36 @@ where
         source: serde_json::Error,
         /// The name of the type that could not be deserialized.
         typename: &'static str,
+        request_id: Option<String>,
     },
     #[error("seatalk server error: {}", msg)]
-    Seatalk { msg: String },
+    Seatalk {
+        msg: String,
+        request_id: Option<String>,
+    },
+    /// SeaTalk returned a non-zero `code`.
+    #[error("seatalk server error {}: {}", code, msg)]
+    SeatalkCode {
+        code: SeatalkErrorCode,
+        msg: String,
+        request_id: Option<String>,
+    },
     #[error("seatalk server error {:?}", obj)]
-    SeatalkObject { obj: serde_json::Value },
+    SeatalkObject {
+        obj: serde_json::Value,
+        request_id: Option<String>,
+    },
     #[error("seatalk internal server error {}", status)]
     SeatalkService {
         status: http::StatusCode,
         data: Vec<u8>,
+        request_id: Option<String>,
     },
     #[error("seatalk server error: {:?}", obj)]
-    SeatalkUnrecognized { obj: serde_json::Value },
+    SeatalkUnrecognized {
+        obj: serde_json::Value,
+        request_id: Option<String>,
+    },
 }
 
 impl<E> ApiError<E>
@@ -67,33 +231,148 @@ where
         Self::Client { source }
     }
 
-    pub(crate) fn server_error(status: http::StatusCode, body: &bytes::Bytes) -> Self {
+    pub(crate) fn server_error(
+        status: http::StatusCode,
+        body: &bytes::Bytes,
+        request_id: Option<String>,
+    ) -> Self {
         Self::SeatalkService {
             status,
             data: body.into_iter().copied().collect(),
+            request_id,
         }
     }
 
-    pub(crate) fn data_type<T>(source: serde_json::Error) -> Self {
+    pub(crate) fn data_type<T>(source: serde_json::Error, request_id: Option<String>) -> Self {
         Self::DataType {
             source,
             typename: any::type_name::<T>(),
+            request_id,
+        }
+    }
+
+    /// The id SeaTalk assigned to the failed request, if the error came from a response.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::DataType { request_id, .. }
+            | Self::Seatalk { request_id, .. }
+            | Self::SeatalkCode { request_id, .. }
+            | Self::SeatalkObject { request_id, .. }
+            | Self::SeatalkService { request_id, .. }
+            | Self::SeatalkUnrecognized { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The SeaTalk error code, if SeaTalk returned one.
+    pub fn seatalk_code(&self) -> Option<SeatalkErrorCode> {
+        match self {
+            Self::SeatalkCode { code, .. } => Some(*code),
+            _ => None,
         }
     }
 
-    pub(crate) fn from_seatalk(value: serde_json::Value) -> Self {
+    /// Whether the same request may succeed if sent again later.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::SeatalkCode { code, .. } => code.is_retryable(),
+            Self::SeatalkService { status, .. } => {
+                status.is_server_error() || *status == http::StatusCode::TOO_MANY_REQUESTS
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the error is caused by the app credentials or access token.
+    pub fn is_auth_error(&self) -> bool {
+        match self {
+            Self::Auth { .. } => true,
+            Self::SeatalkCode { code, .. } => code.is_auth_error(),
+            _ => false,
+        }
+    }
+
+    pub(crate) fn from_seatalk(value: serde_json::Value, request_id: Option<String>) -> Self {
+        if let Some(code) = value
+            .get("code")
+            .and_then(|code| code.as_i64())
+            .filter(|code| *code != 0)
+        {
+            let msg = value
+                .get("message")
+                .and_then(|msg| msg.as_str())
+                .unwrap_or_default();
+            return ApiError::SeatalkCode {
+                code: code.into(),
+                msg: msg.into(),
+                request_id,
+            };
+        }
+
         let error_value = value.pointer("/message");
 
         if let Some(error_value) = error_value {
             if let Some(msg) = error_value.as_str() {
-                ApiError::Seatalk { msg: msg.into() }
+                ApiError::Seatalk {
+                    msg: msg.into(),
+                    request_id,
+                }
             } else {
                 ApiError::SeatalkObject {
                     obj: error_value.clone(),
+                    request_id,
                 }
             }
         } else {
-            ApiError::SeatalkUnrecognized { obj: value }
+            ApiError::SeatalkUnrecognized {
+                obj: value,
+                request_id,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    type Error = ApiError<std::io::Error>;
+
+    #[test]
+    fn from_seatalk_keeps_known_code() {
+        let err = Error::from_seatalk(
+            json!({"code": 101, "message": "rate limited"}),
+            Some("request".into()),
+        );
+        assert_eq!(err.seatalk_code(), Some(SeatalkErrorCode::RateLimited));
+        assert!(err.is_retryable());
+        assert!(!err.is_auth_error());
+        assert_eq!(err.request_id(), Some("request"));
+    }
+
+    #[test]
+    fn from_seatalk_keeps_unknown_code() {
+        let err = Error::from_seatalk(json!({"code": 123456}), None);
+        assert_eq!(err.seatalk_code(), Some(SeatalkErrorCode::Unknown(123456)));
+        assert_eq!(SeatalkErrorCode::Unknown(123456).code(), 123456);
+    }
+
+    #[test]
+    fn from_seatalk_auth_code() {
+        let err = Error::from_seatalk(json!({"code": 100}), None);
+        assert!(err.is_auth_error());
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn codes_round_trip() {
+        for code in [
+            2, 5, 8, 100, 101, 102, 103, 104, 105, 1000, 1001, 3000, 3001, 4001, 4002, 4005, 4008,
+            7000, 7001,
+        ] {
+            assert_eq!(SeatalkErrorCode::from(code).code(), code);
         }
     }
 }
@@ -0,0 +1,83 @@
+use std::borrow::Cow;
+
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::seatalk_api::{endpoint::Endpoint, retry::RetryPolicy};
+
+use super::error::BodyError;
+
+/// Resolves SeaTalk ids, e.g. of a message sender, to employee codes.
+#[derive(Debug, Serialize)]
+pub struct GetEmployeeCode {
+    seatalk_ids: Vec<String>,
+}
+
+impl GetEmployeeCode {
+    pub fn new(seatalk_id: impl Into<String>) -> Self {
+        Self {
+            seatalk_ids: vec![seatalk_id.into()],
+        }
+    }
+
+    /// Resolves several SeaTalk ids with one request.
+    pub fn seatalk_ids<I, S>(seatalk_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            seatalk_ids: seatalk_ids.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmployeeCodes {
+    #[serde(default)]
+    pub employees: Vec<EmployeeCode>,
+}
+
+impl EmployeeCodes {
+    /// Employee code of `seatalk_id`, `None` if it is not an employee of the organization.
+    pub fn get(&self, seatalk_id: &str) -> Option<&str> {
+        self.employees
+            .iter()
+            .find(|employee| employee.seatalk_id == seatalk_id)?
+            .employee_code
+            .as_deref()
+            .filter(|code| !code.is_empty())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct EmployeeCode {
+    pub seatalk_id: String,
+    pub employee_code: Option<String>,
+}
+
+impl Endpoint for GetEmployeeCode {
+    fn method(&self) -> http::Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> std::borrow::Cow<'static, str> {
+        Cow::from("contacts/v2/get_employee_code_with_seatalk_id")
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        Ok(Some((
+            "application/json",
+            serde_json::to_string(self)?.into_bytes(),
+        )))
+    }
+
+    fn require_auth(&self) -> bool {
+        true
+    }
+
+    // A lookup, sending it twice is harmless.
+    fn retry_policy(&self) -> Option<RetryPolicy> {
+        Some(RetryPolicy::default().with_retry_non_idempotent(true))
+    }
+}
@@ -0,0 +1,82 @@
+use std::borrow::Cow;
+
+use http::Method;
+use serde::Deserialize;
+
+use crate::seatalk_api::{endpoint::Endpoint, params::QueryParams};
+
+/// Looks up the profiles of employees by their employee code.
+#[derive(Debug)]
+pub struct GetEmployeeProfile {
+    employee_codes: Vec<String>,
+}
+
+impl GetEmployeeProfile {
+    pub fn new(employee_code: impl Into<String>) -> Self {
+        Self {
+            employee_codes: vec![employee_code.into()],
+        }
+    }
+
+    /// Looks up several employees with one request.
+    pub fn employees<I, S>(employee_codes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            employee_codes: employee_codes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmployeeProfiles {
+    #[serde(default)]
+    pub employees: Vec<Employee>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Employee {
+    pub employee_code: String,
+    pub seatalk_id: String,
+    pub name: String,
+    pub email: Option<String>,
+    pub seatalk_nickname: Option<String>,
+    #[serde(default)]
+    pub departments: Vec<String>,
+    /// Unix timestamp the employee left the company, `None` or 0 while employed.
+    pub offboarding_time: Option<u64>,
+}
+
+impl Employee {
+    /// Name to address the employee with, the SeaTalk nickname if one is set.
+    pub fn display_name(&self) -> &str {
+        self.seatalk_nickname
+            .as_deref()
+            .filter(|nickname| !nickname.is_empty())
+            .unwrap_or(&self.name)
+    }
+}
+
+impl Endpoint for GetEmployeeProfile {
+    fn method(&self) -> http::Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> std::borrow::Cow<'static, str> {
+        Cow::from("contacts/v2/profile")
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        for employee_code in &self.employee_codes {
+            params.push("employee_code", employee_code);
+        }
+        params
+    }
+
+    fn require_auth(&self) -> bool {
+        true
+    }
+}
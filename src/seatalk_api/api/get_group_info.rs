@@ -0,0 +1,64 @@
+use std::borrow::Cow;
+
+use http::Method;
+use serde::Deserialize;
+
+use crate::seatalk_api::{endpoint::Endpoint, params::QueryParams};
+
+/// Looks up the name and settings of a group chat the bot is in.
+#[derive(Debug)]
+pub struct GetGroupInfo {
+    group_id: String,
+}
+
+impl GetGroupInfo {
+    pub fn new(group_id: impl Into<String>) -> Self {
+        Self {
+            group_id: group_id.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GroupInfoResponse {
+    pub group: GroupInfo,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GroupInfo {
+    pub group_name: String,
+    #[serde(default)]
+    pub group_user_total: u32,
+    #[serde(default)]
+    pub group_bot_total: u32,
+    #[serde(default)]
+    pub group_settings: GroupSettings,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct GroupSettings {
+    pub chat_history_for_new_members: Option<u8>,
+    pub can_notify_with_at_all: Option<bool>,
+    pub can_view_member_list: Option<bool>,
+}
+
+impl Endpoint for GetGroupInfo {
+    fn method(&self) -> http::Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> std::borrow::Cow<'static, str> {
+        Cow::from("messaging/v2/group_chat/info")
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push("group_id", &self.group_id);
+        params
+    }
+
+    fn require_auth(&self) -> bool {
+        true
+    }
+}
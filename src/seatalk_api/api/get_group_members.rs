@@ -0,0 +1,106 @@
+use std::borrow::Cow;
+
+use http::Method;
+use serde::Deserialize;
+
+use crate::seatalk_api::{
+    endpoint::Endpoint,
+    paged::{Page, Pageable},
+    params::QueryParams,
+};
+
+/// Lists the members of a group chat the bot is in, one page at a time.
+#[derive(Debug, Clone)]
+pub struct GetGroupMembers {
+    group_id: String,
+    page_size: Option<u32>,
+    cursor: Option<String>,
+}
+
+impl GetGroupMembers {
+    pub fn new(group_id: impl Into<String>) -> Self {
+        Self {
+            group_id: group_id.into(),
+            page_size: None,
+            cursor: None,
+        }
+    }
+
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Continues after the page that returned `cursor` as its `next_cursor`.
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GroupMembers {
+    #[serde(default)]
+    pub group_user_list: Vec<GroupMember>,
+    #[serde(default)]
+    next_cursor: Option<String>,
+}
+
+impl GroupMembers {
+    /// Cursor of the next page, `None` on the last page.
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor
+            .as_deref()
+            .filter(|cursor| !cursor.is_empty())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GroupMember {
+    pub seatalk_id: String,
+    pub employee_code: Option<String>,
+    pub email: Option<String>,
+}
+
+impl Endpoint for GetGroupMembers {
+    fn method(&self) -> http::Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> std::borrow::Cow<'static, str> {
+        Cow::from("messaging/v2/group_chat/members")
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push("group_id", &self.group_id)
+            .push_opt("page_size", self.page_size)
+            .push_opt("cursor", self.cursor.as_ref());
+        params
+    }
+
+    fn require_auth(&self) -> bool {
+        true
+    }
+}
+
+impl Page for GroupMembers {
+    type Item = GroupMember;
+
+    fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor()
+    }
+
+    fn into_items(self) -> Vec<GroupMember> {
+        self.group_user_list
+    }
+}
+
+impl Pageable for GetGroupMembers {
+    type Page = GroupMembers;
+
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+}
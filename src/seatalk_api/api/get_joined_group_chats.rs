@@ -0,0 +1,102 @@
+use std::borrow::Cow;
+
+use http::Method;
+use serde::Deserialize;
+
+use crate::seatalk_api::{
+    endpoint::Endpoint,
+    paged::{Page, Pageable},
+    params::QueryParams,
+};
+
+/// Lists the group chats the bot is a member of, one page at a time.
+#[derive(Debug, Clone, Default)]
+pub struct GetJoinedGroupChats {
+    page_size: Option<u32>,
+    cursor: Option<String>,
+}
+
+impl GetJoinedGroupChats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Continues after the page that returned `cursor` as its `next_cursor`.
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct JoinedGroupChats {
+    pub joined_group_chats: JoinedGroupIds,
+    #[serde(default)]
+    next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct JoinedGroupIds {
+    #[serde(default)]
+    pub group_id: Vec<String>,
+}
+
+impl JoinedGroupChats {
+    pub fn group_ids(&self) -> &[String] {
+        &self.joined_group_chats.group_id
+    }
+
+    /// Cursor of the next page, `None` on the last page.
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor
+            .as_deref()
+            .filter(|cursor| !cursor.is_empty())
+    }
+}
+
+impl Endpoint for GetJoinedGroupChats {
+    fn method(&self) -> http::Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> std::borrow::Cow<'static, str> {
+        Cow::from("messaging/v2/group_chat/joined")
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("page_size", self.page_size)
+            .push_opt("cursor", self.cursor.as_ref());
+        params
+    }
+
+    fn require_auth(&self) -> bool {
+        true
+    }
+}
+
+impl Page for JoinedGroupChats {
+    type Item = String;
+
+    fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor()
+    }
+
+    fn into_items(self) -> Vec<String> {
+        self.joined_group_chats.group_id
+    }
+}
+
+impl Pageable for GetJoinedGroupChats {
+    type Page = JoinedGroupChats;
+
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+}
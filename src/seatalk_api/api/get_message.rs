@@ -0,0 +1,71 @@
+use std::borrow::Cow;
+
+use http::Method;
+use serde::Deserialize;
+
+use crate::seatalk_api::{endpoint::Endpoint, params::QueryParams};
+
+use super::content::MessageContent;
+
+/// Fetches a message of a chat the bot is in, e.g. the message quoted by a mention.
+#[derive(Debug)]
+pub struct GetMessage {
+    message_id: String,
+}
+
+impl GetMessage {
+    pub fn new(message_id: impl Into<String>) -> Self {
+        Self {
+            message_id: message_id.into(),
+        }
+    }
+}
+
+/// A message read back from a chat.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChatMessage {
+    /// Missing when the message is fetched by its id.
+    pub message_id: Option<String>,
+    pub quoted_message_id: Option<String>,
+    pub thread_id: Option<String>,
+    pub sender: MessageSender,
+    #[serde(default)]
+    pub message_sent_time: u64,
+    #[serde(flatten)]
+    pub content: MessageContent,
+}
+
+impl ChatMessage {
+    /// Text of a text message.
+    pub fn text(&self) -> Option<&str> {
+        self.content.text()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MessageSender {
+    pub seatalk_id: String,
+    /// `None` for bots.
+    pub employee_code: Option<String>,
+    pub sender_type: Option<u8>,
+}
+
+impl Endpoint for GetMessage {
+    fn method(&self) -> http::Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> std::borrow::Cow<'static, str> {
+        Cow::from("messaging/v2/get_message_by_message_id")
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push("message_id", &self.message_id);
+        params
+    }
+
+    fn require_auth(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,104 @@
+use std::borrow::Cow;
+
+use http::Method;
+use serde::Deserialize;
+
+use crate::seatalk_api::{
+    endpoint::Endpoint,
+    paged::{Page, Pageable},
+    params::QueryParams,
+};
+
+use super::get_message::ChatMessage;
+
+/// Lists the messages of a thread in a group chat, one page at a time.
+#[derive(Debug, Clone)]
+pub struct GetThread {
+    group_id: String,
+    thread_id: String,
+    page_size: Option<u32>,
+    cursor: Option<String>,
+}
+
+impl GetThread {
+    pub fn new(group_id: impl Into<String>, thread_id: impl Into<String>) -> Self {
+        Self {
+            group_id: group_id.into(),
+            thread_id: thread_id.into(),
+            page_size: None,
+            cursor: None,
+        }
+    }
+
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Continues after the page that returned `cursor` as its `next_cursor`.
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ThreadMessages {
+    #[serde(default)]
+    pub thread_messages: Vec<ChatMessage>,
+    #[serde(default)]
+    next_cursor: Option<String>,
+}
+
+impl ThreadMessages {
+    /// Cursor of the next page, `None` on the last page.
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor
+            .as_deref()
+            .filter(|cursor| !cursor.is_empty())
+    }
+}
+
+impl Endpoint for GetThread {
+    fn method(&self) -> http::Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> std::borrow::Cow<'static, str> {
+        Cow::from("messaging/v2/group_chat/get_thread_by_thread_id")
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push("group_id", &self.group_id)
+            .push("thread_id", &self.thread_id)
+            .push_opt("page_size", self.page_size)
+            .push_opt("cursor", self.cursor.as_ref());
+        params
+    }
+
+    fn require_auth(&self) -> bool {
+        true
+    }
+}
+
+impl Page for ThreadMessages {
+    type Item = ChatMessage;
+
+    fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor()
+    }
+
+    fn into_items(self) -> Vec<ChatMessage> {
+        self.thread_messages
+    }
+}
+
+impl Pageable for GetThread {
+    type Page = ThreadMessages;
+
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+}
@@ -0,0 +1,218 @@
+use serde::Serialize;
+
+use super::{
+    error::BodyError,
+    text::{Text, TextFormat},
+};
+
+/// Most buttons SeaTalk shows in one button group.
+pub const MAX_BUTTONS_PER_GROUP: usize = 3;
+
+/// Most elements SeaTalk shows in one interactive message.
+pub const MAX_ELEMENTS: usize = 20;
+
+/// A message card built from titles, descriptions, images and buttons.
+///
+/// Clicking a [`Button::callback`] button sends an `interactive_message_click` event with the
+/// button's value to the webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct InteractiveMessage {
+    elements: Vec<Element>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "element_type", rename_all = "snake_case")]
+pub enum Element {
+    Title { title: Title },
+    Description { description: Description },
+    Image { image: Image },
+    Button { button: Button },
+    ButtonGroup { button_group: Vec<Button> },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Title {
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Description {
+    pub format: TextFormat,
+    pub text: String,
+}
+
+impl From<Text> for Description {
+    fn from(text: Text) -> Self {
+        Self {
+            format: text.format,
+            text: text.content,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Image {
+    /// Base64 encoded image.
+    pub content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "button_type", rename_all = "snake_case")]
+pub enum Button {
+    /// Sends `value` back to the webhook when clicked.
+    Callback { text: String, value: String },
+    /// Opens a link when clicked.
+    Redirect {
+        text: String,
+        mobile_link: Link,
+        desktop_link: Link,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Link {
+    #[serde(rename = "type")]
+    pub link_type: String,
+    pub path: String,
+}
+
+impl Button {
+    pub fn callback(text: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::Callback {
+            text: text.into(),
+            value: value.into(),
+        }
+    }
+
+    /// A button opening `url` in the browser.
+    pub fn redirect(text: impl Into<String>, url: impl Into<String>) -> Self {
+        let url = url.into();
+        Self::Redirect {
+            text: text.into(),
+            mobile_link: Link {
+                link_type: "web".into(),
+                path: url.clone(),
+            },
+            desktop_link: Link {
+                link_type: "web".into(),
+                path: url,
+            },
+        }
+    }
+}
+
+impl InteractiveMessage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, text: impl Into<String>) -> Self {
+        self.elements.push(Element::Title {
+            title: Title { text: text.into() },
+        });
+        self
+    }
+
+    pub fn description(mut self, text: impl Into<Text>) -> Self {
+        self.elements.push(Element::Description {
+            description: text.into().into(),
+        });
+        self
+    }
+
+    /// Adds an image, `content` is the base64 encoded image.
+    pub fn image(mut self, content: impl Into<String>) -> Self {
+        self.elements.push(Element::Image {
+            image: Image {
+                content: content.into(),
+            },
+        });
+        self
+    }
+
+    pub fn button(mut self, button: Button) -> Self {
+        self.elements.push(Element::Button { button });
+        self
+    }
+
+    /// Adds buttons shown next to each other, at most [`MAX_BUTTONS_PER_GROUP`].
+    pub fn button_group(mut self, buttons: impl IntoIterator<Item = Button>) -> Self {
+        self.elements.push(Element::ButtonGroup {
+            button_group: buttons.into_iter().collect(),
+        });
+        self
+    }
+
+    pub fn elements(&self) -> &[Element] {
+        &self.elements
+    }
+
+    /// Checks the message against the limits of SeaTalk before it is sent.
+    pub(crate) fn validate(&self) -> Result<(), BodyError> {
+        if self.elements.is_empty() {
+            return Err(BodyError::InvalidInteractiveMessage(
+                "message has no elements",
+            ));
+        }
+        if self.elements.len() > MAX_ELEMENTS {
+            return Err(BodyError::InvalidInteractiveMessage(
+                "message has too many elements",
+            ));
+        }
+        for element in &self.elements {
+            if let Element::ButtonGroup { button_group } = element {
+                if button_group.is_empty() || button_group.len() > MAX_BUTTONS_PER_GROUP {
+                    return Err(BodyError::InvalidInteractiveMessage(
+                        "button group needs 1 to 3 buttons",
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn serializes_elements() {
+        let message = InteractiveMessage::new()
+            .title("Stickers")
+            .description(Text::plain("Pick a format"))
+            .button_group([
+                Button::callback("GIF", "convert:gif:set"),
+                Button::redirect("Open", "https://t.me/addstickers/set"),
+            ]);
+        assert_eq!(
+            serde_json::to_value(&message).unwrap(),
+            json!({"elements": [
+                {"element_type": "title", "title": {"text": "Stickers"}},
+                {"element_type": "description", "description": {"format": 2, "text": "Pick a format"}},
+                {"element_type": "button_group", "button_group": [
+                    {"button_type": "callback", "text": "GIF", "value": "convert:gif:set"},
+                    {
+                        "button_type": "redirect",
+                        "text": "Open",
+                        "mobile_link": {"type": "web", "path": "https://t.me/addstickers/set"},
+                        "desktop_link": {"type": "web", "path": "https://t.me/addstickers/set"},
+                    },
+                ]},
+            ]})
+        );
+        assert!(message.validate().is_ok());
+    }
+
+    #[test]
+    fn validates_limits() {
+        assert!(InteractiveMessage::new().validate().is_err());
+        let buttons = (0..4).map(|i| Button::callback(i.to_string(), i.to_string()));
+        assert!(InteractiveMessage::new()
+            .button_group(buttons)
+            .validate()
+            .is_err());
+    }
+}
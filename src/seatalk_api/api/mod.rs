@@ -1,10 +1,36 @@
 mod access_token;
 pub mod common;
+pub mod content;
+mod download_file;
 pub mod error;
+pub mod get_employee_code;
+pub mod get_employee_profile;
+pub mod get_group_info;
+pub mod get_group_members;
+pub mod get_joined_group_chats;
+pub mod get_message;
+pub mod get_thread;
+pub mod interactive;
+mod recall_message;
 mod send_group_message;
 mod send_subscriber_message;
+pub mod text;
+mod update_interactive_message;
 
 pub use self::access_token::GetAccessToken;
-pub use self::error::ApiError;
+pub use self::content::MessageContent;
+pub use self::download_file::DownloadFile;
+pub use self::error::{ApiError, SeatalkErrorCode};
+pub use self::get_employee_code::{EmployeeCode, EmployeeCodes, GetEmployeeCode};
+pub use self::get_employee_profile::{Employee, EmployeeProfiles, GetEmployeeProfile};
+pub use self::get_group_info::{GetGroupInfo, GroupInfo};
+pub use self::get_group_members::{GetGroupMembers, GroupMember, GroupMembers};
+pub use self::get_joined_group_chats::{GetJoinedGroupChats, JoinedGroupChats};
+pub use self::get_message::{ChatMessage, GetMessage};
+pub use self::get_thread::{GetThread, ThreadMessages};
+pub use self::interactive::{Button, InteractiveMessage};
+pub use self::recall_message::RecallMessage;
 pub use self::send_group_message::SendGroupMessage;
 pub use self::send_subscriber_message::SendSubscriberMessage;
+pub use self::text::{Markdown, Text, TextFormat};
+pub use self::update_interactive_message::UpdateInteractiveMessage;
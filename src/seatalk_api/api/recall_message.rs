@@ -0,0 +1,43 @@
+use std::borrow::Cow;
+
+use http::Method;
+use serde::Serialize;
+
+use crate::seatalk_api::endpoint::Endpoint;
+
+use super::error::BodyError;
+
+/// Recalls a message the bot sent, removing it from the chat.
+#[derive(Debug, Serialize)]
+pub struct RecallMessage {
+    message_id: String,
+}
+
+impl RecallMessage {
+    pub fn new(message_id: impl Into<String>) -> Self {
+        Self {
+            message_id: message_id.into(),
+        }
+    }
+}
+
+impl Endpoint for RecallMessage {
+    fn method(&self) -> http::Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> std::borrow::Cow<'static, str> {
+        Cow::from("messaging/v2/recall")
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        Ok(Some((
+            "application/json",
+            serde_json::to_string(self)?.into_bytes(),
+        )))
+    }
+
+    fn require_auth(&self) -> bool {
+        true
+    }
+}
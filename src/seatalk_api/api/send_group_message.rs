@@ -2,9 +2,14 @@ use std::borrow::Cow;
 
 use serde::Serialize;
 
-use crate::seatalk_api::endpoint::Endpoint;
+use crate::seatalk_api::{endpoint::Endpoint, rate_limit::RateLimitKey};
 
-use super::{common::MessageType, error::BodyError};
+use super::{
+    common::{validate_file, MessageType},
+    error::BodyError,
+    interactive::InteractiveMessage,
+    text::Text,
+};
 
 #[derive(Debug, Serialize)]
 pub struct SendGroupMessage {
@@ -17,7 +22,7 @@ pub struct SendGroupMessage {
 pub enum GroupMessage {
     #[serde(rename = "text")]
     Text {
-        text: TextGroupMessage,
+        text: Text,
         quoted_message_id: Option<String>,
         thread_id: Option<String>,
     },
@@ -27,20 +32,36 @@ pub enum GroupMessage {
         quoted_message_id: Option<String>,
         thread_id: Option<String>,
     },
+    #[serde(rename = "file")]
+    File {
+        file: FileGroupMessage,
+        quoted_message_id: Option<String>,
+        thread_id: Option<String>,
+    },
+    #[serde(rename = "interactive_message")]
+    InteractiveMessage {
+        interactive_message: InteractiveMessage,
+        quoted_message_id: Option<String>,
+        thread_id: Option<String>,
+    },
 }
 
 #[derive(Debug, Serialize)]
-pub struct TextGroupMessage {
-    format: u8,
+pub struct ImageGroupMessage {
     content: String,
 }
 
 #[derive(Debug, Serialize)]
-pub struct ImageGroupMessage {
+pub struct FileGroupMessage {
+    filename: String,
+    /// Base64 encoded file.
     content: String,
 }
 
 impl SendGroupMessage {
+    /// Creates a message of `message_type` with `text` as its content.
+    ///
+    /// File messages need a name, so they are created with [`Self::new_file_message`].
     pub fn new(
         group_id: impl Into<String>,
         thread_id: Option<String>,
@@ -50,7 +71,7 @@ impl SendGroupMessage {
     ) -> Self {
         match message_type {
             MessageType::Text => {
-                Self::new_text_message(group_id, thread_id, text, quoted_message_id)
+                Self::new_text_message(group_id, thread_id, text.into(), quoted_message_id)
             }
             MessageType::Image => {
                 Self::new_image_message(group_id, thread_id, text, quoted_message_id)
@@ -60,16 +81,13 @@ impl SendGroupMessage {
     pub fn new_text_message(
         group_id: impl Into<String>,
         thread_id: Option<String>,
-        text: impl Into<String>,
+        text: impl Into<Text>,
         quoted_message_id: Option<String>,
     ) -> Self {
         Self {
             group_id: group_id.into(),
             message: GroupMessage::Text {
-                text: TextGroupMessage {
-                    format: 1,
-                    content: text.into(),
-                },
+                text: text.into(),
                 quoted_message_id,
                 thread_id,
             },
@@ -92,6 +110,43 @@ impl SendGroupMessage {
             },
         }
     }
+
+    /// Creates a file message, `content` is the base64 encoded file.
+    pub fn new_file_message(
+        group_id: impl Into<String>,
+        thread_id: Option<String>,
+        filename: impl Into<String>,
+        content: impl Into<String>,
+        quoted_message_id: Option<String>,
+    ) -> Self {
+        Self {
+            group_id: group_id.into(),
+            message: GroupMessage::File {
+                file: FileGroupMessage {
+                    filename: filename.into(),
+                    content: content.into(),
+                },
+                quoted_message_id,
+                thread_id,
+            },
+        }
+    }
+
+    pub fn new_interactive_message(
+        group_id: impl Into<String>,
+        thread_id: Option<String>,
+        interactive_message: InteractiveMessage,
+        quoted_message_id: Option<String>,
+    ) -> Self {
+        Self {
+            group_id: group_id.into(),
+            message: GroupMessage::InteractiveMessage {
+                interactive_message,
+                quoted_message_id,
+                thread_id,
+            },
+        }
+    }
 }
 
 impl Endpoint for SendGroupMessage {
@@ -104,6 +159,14 @@ impl Endpoint for SendGroupMessage {
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        match &self.message {
+            GroupMessage::File { file, .. } => validate_file(&file.filename, &file.content)?,
+            GroupMessage::InteractiveMessage {
+                interactive_message,
+                ..
+            } => interactive_message.validate()?,
+            _ => {}
+        }
         Ok(Some((
             "application/json",
             serde_json::to_string(self)?.into_bytes(),
@@ -113,4 +176,33 @@ impl Endpoint for SendGroupMessage {
     fn require_auth(&self) -> bool {
         true
     }
+
+    fn rate_limit_key(&self) -> Option<RateLimitKey> {
+        Some(RateLimitKey(self.group_id.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::{engine::general_purpose, Engine};
+
+    use super::*;
+    use crate::seatalk_api::api::common::MAX_FILE_SIZE;
+
+    #[test]
+    fn validates_file_messages() {
+        let message = |filename, content| {
+            SendGroupMessage::new_file_message("group", None, filename, content, None)
+        };
+        assert!(message("pack.zip", "aGk=").body().is_ok());
+        assert!(matches!(
+            message("pack", "aGk=").body(),
+            Err(BodyError::InvalidFilename { .. })
+        ));
+        let content = general_purpose::STANDARD.encode(vec![0; MAX_FILE_SIZE + 1]);
+        assert!(matches!(
+            message("pack.zip", &content).body(),
+            Err(BodyError::FileTooLarge { .. })
+        ));
+    }
 }
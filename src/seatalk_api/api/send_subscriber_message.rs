@@ -3,9 +3,14 @@ use std::borrow::Cow;
 use http::Method;
 use serde::Serialize;
 
-use crate::seatalk_api::endpoint::Endpoint;
+use crate::seatalk_api::{endpoint::Endpoint, rate_limit::RateLimitKey};
 
-use super::{common::MessageType, error::BodyError};
+use super::{
+    common::{validate_file, MessageType},
+    error::BodyError,
+    interactive::InteractiveMessage,
+    text::Text,
+};
 
 #[derive(Debug, Serialize)]
 pub struct SendSubscriberMessage {
@@ -17,38 +22,40 @@ pub struct SendSubscriberMessage {
 #[serde(tag = "tag")]
 pub enum SubscriberMessage {
     #[serde(rename = "text")]
-    Text { text: TextSubscriberMessage },
+    Text { text: Text },
     #[serde(rename = "image")]
     Image { image: ImageSubscriberMessage },
+    #[serde(rename = "file")]
+    File { file: FileSubscriberMessage },
+    #[serde(rename = "interactive_message")]
+    InteractiveMessage {
+        interactive_message: InteractiveMessage,
+    },
 }
 
 #[derive(Debug, Serialize)]
-pub struct TextSubscriberMessage {
-    format: u8,
-    content: String,
+pub struct ImageSubscriberMessage {
+    pub content: String,
 }
 
 #[derive(Debug, Serialize)]
-pub struct ImageSubscriberMessage {
+pub struct FileSubscriberMessage {
+    pub filename: String,
+    /// Base64 encoded file.
     pub content: String,
 }
 
 impl SendSubscriberMessage {
+    /// Creates a message of `message_type` with `content`.
+    ///
+    /// File messages need a name, so they are created with [`Self::new_file_message`].
     pub fn new(
         employee_code: impl Into<String>,
         message_type: MessageType,
         content: impl Into<String>,
     ) -> Self {
         match message_type {
-            MessageType::Text => Self {
-                employee_code: employee_code.into(),
-                message: SubscriberMessage::Text {
-                    text: TextSubscriberMessage {
-                        format: 1,
-                        content: content.into(),
-                    },
-                },
-            },
+            MessageType::Text => Self::new_text_message(employee_code, content.into()),
             MessageType::Image => Self {
                 employee_code: employee_code.into(),
                 message: SubscriberMessage::Image {
@@ -59,6 +66,42 @@ impl SendSubscriberMessage {
             },
         }
     }
+
+    pub fn new_text_message(employee_code: impl Into<String>, text: impl Into<Text>) -> Self {
+        Self {
+            employee_code: employee_code.into(),
+            message: SubscriberMessage::Text { text: text.into() },
+        }
+    }
+
+    /// Creates a file message, `content` is the base64 encoded file.
+    pub fn new_file_message(
+        employee_code: impl Into<String>,
+        filename: impl Into<String>,
+        content: impl Into<String>,
+    ) -> Self {
+        Self {
+            employee_code: employee_code.into(),
+            message: SubscriberMessage::File {
+                file: FileSubscriberMessage {
+                    filename: filename.into(),
+                    content: content.into(),
+                },
+            },
+        }
+    }
+
+    pub fn new_interactive_message(
+        employee_code: impl Into<String>,
+        interactive_message: InteractiveMessage,
+    ) -> Self {
+        Self {
+            employee_code: employee_code.into(),
+            message: SubscriberMessage::InteractiveMessage {
+                interactive_message,
+            },
+        }
+    }
 }
 
 impl Endpoint for SendSubscriberMessage {
@@ -71,6 +114,13 @@ impl Endpoint for SendSubscriberMessage {
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        match &self.message {
+            SubscriberMessage::File { file } => validate_file(&file.filename, &file.content)?,
+            SubscriberMessage::InteractiveMessage {
+                interactive_message,
+            } => interactive_message.validate()?,
+            _ => {}
+        }
         Ok(Some((
             "application/json",
             serde_json::to_string(self)?.into_bytes(),
@@ -80,4 +130,32 @@ impl Endpoint for SendSubscriberMessage {
     fn require_auth(&self) -> bool {
         true
     }
+
+    fn rate_limit_key(&self) -> Option<RateLimitKey> {
+        Some(RateLimitKey(self.employee_code.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::{engine::general_purpose, Engine};
+
+    use super::*;
+    use crate::seatalk_api::api::common::MAX_FILE_SIZE;
+
+    #[test]
+    fn validates_file_messages() {
+        let message =
+            |filename, content| SendSubscriberMessage::new_file_message("e1", filename, content);
+        assert!(message("pack.zip", "aGk=").body().is_ok());
+        assert!(matches!(
+            message(".zip", "aGk=").body(),
+            Err(BodyError::InvalidFilename { .. })
+        ));
+        let content = general_purpose::STANDARD.encode(vec![0; MAX_FILE_SIZE + 1]);
+        assert!(matches!(
+            message("pack.zip", &content).body(),
+            Err(BodyError::FileTooLarge { .. })
+        ));
+    }
 }
@@ -0,0 +1,226 @@
+use serde::{Serialize, Serializer};
+
+/// How SeaTalk renders the content of a text message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextFormat {
+    /// Content is rendered as markdown.
+    #[default]
+    Markdown,
+    /// Content is shown as is.
+    Plain,
+}
+
+impl TextFormat {
+    pub fn code(&self) -> u8 {
+        match self {
+            Self::Markdown => 1,
+            Self::Plain => 2,
+        }
+    }
+}
+
+impl Serialize for TextFormat {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.code())
+    }
+}
+
+/// Content of a text message.
+///
+/// Strings convert to markdown text as they are, build markdown with untrusted input using
+/// [`Markdown`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Text {
+    pub format: TextFormat,
+    pub content: String,
+}
+
+impl Text {
+    /// Text shown as is.
+    pub fn plain(content: impl Into<String>) -> Self {
+        Self {
+            format: TextFormat::Plain,
+            content: content.into(),
+        }
+    }
+
+    /// Text that is already formatted as markdown.
+    pub fn markdown(content: impl Into<String>) -> Self {
+        Self {
+            format: TextFormat::Markdown,
+            content: content.into(),
+        }
+    }
+}
+
+impl From<String> for Text {
+    fn from(content: String) -> Self {
+        Self::markdown(content)
+    }
+}
+
+impl From<&str> for Text {
+    fn from(content: &str) -> Self {
+        Self::markdown(content)
+    }
+}
+
+impl From<Markdown> for Text {
+    fn from(markdown: Markdown) -> Self {
+        Self::markdown(markdown.content)
+    }
+}
+
+/// Builds markdown text, escaping everything that is not explicitly formatted.
+///
+/// ```
+/// # use seatalk_tgs::seatalk_api::api::text::Markdown;
+/// let text = Markdown::new().text("Found 2 stickers in ").bold("cute_cats");
+/// assert_eq!(text.as_str(), r"Found 2 stickers in **cute\_cats**");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Markdown {
+    content: String,
+}
+
+impl Markdown {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `text` with markdown escaped.
+    pub fn text(mut self, text: &str) -> Self {
+        let line_start = self.content.is_empty() || self.content.ends_with('\n');
+        self.content.push_str(&escape_from(text, line_start));
+        self
+    }
+
+    pub fn bold(mut self, text: &str) -> Self {
+        self.content.push_str("**");
+        self.content.push_str(&escape_from(text, false));
+        self.content.push_str("**");
+        self
+    }
+
+    pub fn italic(mut self, text: &str) -> Self {
+        self.content.push('_');
+        self.content.push_str(&escape_from(text, false));
+        self.content.push('_');
+        self
+    }
+
+    /// Appends `text` as inline code, backticks in `text` are replaced by quotes.
+    pub fn code(mut self, text: &str) -> Self {
+        self.content.push('`');
+        self.content.push_str(&text.replace('`', "'"));
+        self.content.push('`');
+        self
+    }
+
+    pub fn newline(mut self) -> Self {
+        self.content.push('\n');
+        self
+    }
+
+    /// Appends `markdown` without escaping it.
+    pub fn raw(mut self, markdown: &str) -> Self {
+        self.content.push_str(markdown);
+        self
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.content
+    }
+}
+
+/// Escapes the characters SeaTalk markdown interprets as formatting, `text` starting a line.
+///
+/// Emphasis, code and link characters are escaped anywhere, heading, quote, list and table
+/// markers only at the start of a line, the only place they format anything.
+pub fn escape(text: &str) -> String {
+    escape_from(text, true)
+}
+
+/// Escapes `text`, whose first line continues a line if `line_start` is not set.
+fn escape_from(text: &str, line_start: bool) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            escaped.push('\n');
+        }
+        let content = line.trim_start();
+        escaped.push_str(&line[..line.len() - content.len()]);
+        let is_line_start = line_start || i > 0;
+        let number = content.len()
+            - content
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        for (j, c) in content.char_indices() {
+            let is_marker = is_line_start
+                && ((j == 0 && matches!(c, '#' | '>' | '-' | '+' | '|'))
+                    || (j == number && number > 0 && matches!(c, '.' | ')')));
+            if is_marker || matches!(c, '\\' | '`' | '*' | '_' | '~' | '[' | ']') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn escapes_markdown() {
+        assert_eq!(escape("**a_b** [c](d)"), r"\*\*a\_b\*\* \[c\](d)");
+        assert_eq!(escape("plain text"), "plain text");
+        assert_eq!(
+            escape("# a\n  - b\n2. c\n> d"),
+            "\\# a\n  \\- b\n2\\. c\n\\> d"
+        );
+    }
+
+    #[test]
+    fn leaves_sentences_readable() {
+        let text = Text::from(
+            Markdown::new()
+                .text("Converted 3 stickers (.tgs) to .gif - ask again if any are missing!")
+                .newline()
+                .text("- Stickers: 1.5 MB + 2 files #1"),
+        );
+        assert_eq!(
+            text.content,
+            "Converted 3 stickers (.tgs) to .gif - ask again if any are missing!\n\
+             \\- Stickers: 1.5 MB + 2 files #1"
+        );
+    }
+
+    #[test]
+    fn builds_markdown() {
+        let text = Text::from(
+            Markdown::new()
+                .text("1. ")
+                .bold("*set*")
+                .newline()
+                .code("a`b"),
+        );
+        assert_eq!(text.content, "1\\. **\\*set\\***\n`a'b`");
+        assert_eq!(text.format, TextFormat::Markdown);
+    }
+
+    #[test]
+    fn serializes_format_code() {
+        assert_eq!(
+            serde_json::to_value(Text::plain("a")).unwrap(),
+            json!({"format": 2, "content": "a"})
+        );
+        assert_eq!(
+            serde_json::to_value(Text::markdown("a")).unwrap(),
+            json!({"format": 1, "content": "a"})
+        );
+    }
+}
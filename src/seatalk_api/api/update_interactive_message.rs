@@ -0,0 +1,58 @@
+use std::borrow::Cow;
+
+use http::Method;
+use serde::Serialize;
+
+use crate::seatalk_api::{endpoint::Endpoint, retry::RetryPolicy};
+
+use super::{error::BodyError, interactive::InteractiveMessage};
+
+/// Replaces the content of an interactive message the bot sent.
+#[derive(Debug, Serialize)]
+pub struct UpdateInteractiveMessage {
+    message_id: String,
+    message: UpdatedMessage,
+}
+
+#[derive(Debug, Serialize)]
+struct UpdatedMessage {
+    interactive_message: InteractiveMessage,
+}
+
+impl UpdateInteractiveMessage {
+    pub fn new(message_id: impl Into<String>, interactive_message: InteractiveMessage) -> Self {
+        Self {
+            message_id: message_id.into(),
+            message: UpdatedMessage {
+                interactive_message,
+            },
+        }
+    }
+}
+
+impl Endpoint for UpdateInteractiveMessage {
+    fn method(&self) -> http::Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> std::borrow::Cow<'static, str> {
+        Cow::from("messaging/v2/update")
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        self.message.interactive_message.validate()?;
+        Ok(Some((
+            "application/json",
+            serde_json::to_string(self)?.into_bytes(),
+        )))
+    }
+
+    fn require_auth(&self) -> bool {
+        true
+    }
+
+    // Setting the same content twice is harmless.
+    fn retry_policy(&self) -> Option<RetryPolicy> {
+        Some(RetryPolicy::default().with_retry_non_idempotent(true))
+    }
+}
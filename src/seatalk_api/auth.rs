@@ -3,7 +3,11 @@ use http::{HeaderMap, HeaderValue};
 use serde::Deserialize;
 use thiserror::Error;
 
-use super::{api, client::AsyncClient, query::AsyncQuery};
+use super::{
+    api::{self, SeatalkErrorCode},
+    client::{AsyncClient, Client},
+    query::{AsyncQuery, Query},
+};
 
 #[derive(Debug, Error)]
 pub enum AuthError {
@@ -14,11 +18,28 @@ pub enum AuthError {
     },
     #[error("invalid token")]
     InvalidToken,
+    #[error("invalid app id, check the configured seatalk app_id")]
+    InvalidAppId,
+    #[error("invalid app secret, check the configured seatalk app_secret")]
+    InvalidAppSecret,
+    #[error("app is disabled, enable it on the SeaTalk Open Platform")]
+    AppDisabled,
+}
+
+impl AuthError {
+    fn from_code(code: SeatalkErrorCode) -> Option<Self> {
+        match code {
+            SeatalkErrorCode::AppNotOnline => Some(Self::AppDisabled),
+            SeatalkErrorCode::InvalidAppSecret => Some(Self::InvalidAppSecret),
+            SeatalkErrorCode::InvalidAppId => Some(Self::InvalidAppId),
+            _ => None,
+        }
+    }
 }
 
 type AuthResult<T> = Result<T, AuthError>;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct AccessToken {
     pub app_access_token: String,
     #[serde(deserialize_with = "ts_seconds::deserialize")]
@@ -33,7 +54,7 @@ impl AccessToken {
     pub fn set_headers<'a>(
         &'a self,
         headers: &'a mut HeaderMap<HeaderValue>,
-    ) -> AuthResult<&mut HeaderMap<HeaderValue>> {
+    ) -> AuthResult<&'a mut HeaderMap<HeaderValue>> {
         let value = format!("Bearer {}", &self.app_access_token);
         let mut header_value = HeaderValue::from_str(&value)?;
         header_value.set_sensitive(true);
@@ -48,7 +69,33 @@ pub struct Auth {
     pub app_secret: String,
 }
 
+/// Reports credentials rejected by SeaTalk as [`api::ApiError::Auth`].
+fn credentials_error<E>(e: api::ApiError<E>) -> api::ApiError<E>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    match e.seatalk_code().and_then(AuthError::from_code) {
+        Some(auth_error) => auth_error.into(),
+        None => e,
+    }
+}
+
 impl Auth {
+    /// Fetches a new app access token.
+    ///
+    /// Credentials rejected by SeaTalk are reported as [`api::ApiError::Auth`].
+    pub fn get_access_token<C>(&self, api: &C) -> Result<AccessToken, api::ApiError<C::Error>>
+    where
+        C: Client,
+    {
+        api::GetAccessToken::new(self.app_id.to_owned(), self.app_secret.to_owned())
+            .query(api)
+            .map_err(credentials_error)
+    }
+
+    /// Fetches a new app access token.
+    ///
+    /// Credentials rejected by SeaTalk are reported as [`api::ApiError::Auth`].
     pub async fn get_access_token_async<C>(
         &self,
         api: &C,
@@ -56,17 +103,9 @@ impl Auth {
     where
         C: AsyncClient + Sync,
     {
-        let token: AccessToken =
-            api::GetAccessToken::new(self.app_id.to_owned(), self.app_secret.to_owned())
-                .query_async(api)
-                .await?;
-        Ok(token)
-    }
-    pub async fn check_auth_async<C>(&self, api: &C) -> Result<(), api::ApiError<C::Error>>
-    where
-        C: AsyncClient + Sync,
-    {
-        // TODO
-        unimplemented!()
+        api::GetAccessToken::new(self.app_id.to_owned(), self.app_secret.to_owned())
+            .query_async(api)
+            .await
+            .map_err(credentials_error)
     }
 }
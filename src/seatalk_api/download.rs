@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+
+use super::{
+    api::error::ApiError,
+    client::{AsyncClient, Client},
+    endpoint::Endpoint,
+    query::{self, AsyncQuery, Query},
+};
+
+/// Queries an endpoint answering with a file and returns the undecoded body.
+///
+/// JSON responses are checked like any other response, SeaTalk reports failures with them.
+pub struct Download<E> {
+    endpoint: E,
+}
+
+pub fn download<E>(endpoint: E) -> Download<E> {
+    Download { endpoint }
+}
+
+impl<E, C> Query<Bytes, C> for Download<E>
+where
+    E: Endpoint,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Bytes, ApiError<<C>::Error>> {
+        let rsp = query::send(&self.endpoint, client)?;
+        query::handle_file_response(&self.endpoint.endpoint(), rsp)
+    }
+}
+
+#[async_trait]
+impl<E, C> AsyncQuery<Bytes, C> for Download<E>
+where
+    E: Endpoint + Sync,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Bytes, ApiError<C::Error>> {
+        let rsp = query::send_async(&self.endpoint, client).await?;
+        query::handle_file_response(&self.endpoint.endpoint(), rsp)
+    }
+}
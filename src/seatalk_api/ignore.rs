@@ -1,3 +1,43 @@
+use async_trait::async_trait;
+use serde::de::IgnoredAny;
+
+use super::{
+    api::error::ApiError,
+    client::{AsyncClient, Client},
+    endpoint::Endpoint,
+    query::{self, AsyncQuery, Query},
+};
+
+/// Queries an endpoint and discards the response body.
 pub struct Ignore<E> {
-    endpoint
+    endpoint: E,
+}
+
+pub fn ignore<E>(endpoint: E) -> Ignore<E> {
+    Ignore { endpoint }
+}
+
+impl<E, C> Query<(), C> for Ignore<E>
+where
+    E: Endpoint,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<(), ApiError<<C>::Error>> {
+        let rsp = query::send(&self.endpoint, client)?;
+        query::handle_response::<IgnoredAny, _>(&self.endpoint.endpoint(), &rsp)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<E, C> AsyncQuery<(), C> for Ignore<E>
+where
+    E: Endpoint + Sync,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<(), ApiError<C::Error>> {
+        let rsp = query::send_async(&self.endpoint, client).await?;
+        query::handle_response::<IgnoredAny, _>(&self.endpoint.endpoint(), &rsp)?;
+        Ok(())
+    }
 }
@@ -1,9 +1,19 @@
 pub mod api;
 pub mod auth;
 pub mod client;
+pub mod download;
 pub mod endpoint;
+pub mod ignore;
+pub mod paged;
+pub mod params;
 pub mod query;
+pub mod rate_limit;
+pub mod raw;
+pub mod retry;
 pub mod seatalk;
 pub mod webhooks;
 
-pub use endpoint::ignore;
+pub use download::download;
+pub use ignore::ignore;
+pub use paged::paged;
+pub use raw::raw;
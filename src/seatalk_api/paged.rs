@@ -0,0 +1,121 @@
+use std::collections::VecDeque;
+
+use futures_util::{stream, Stream};
+use serde::de::DeserializeOwned;
+
+use super::{api::error::ApiError, client::AsyncClient, endpoint::Endpoint, query::AsyncQuery};
+
+/// One page of a list returned by a [`Pageable`] endpoint.
+pub trait Page: DeserializeOwned {
+    type Item;
+
+    /// Cursor of the next page, `None` on the last page.
+    fn next_cursor(&self) -> Option<&str>;
+
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// An endpoint listing items one page at a time, continuing after a cursor.
+pub trait Pageable: Endpoint {
+    type Page: Page;
+
+    /// Requests the page after the one that returned `cursor` as its next cursor.
+    fn set_cursor(&mut self, cursor: String);
+}
+
+/// Queries every page of an endpoint, following the cursor of each page.
+///
+/// Each page is a separate request, subject to the client's rate limits and retries.
+#[derive(Debug, Clone)]
+pub struct Paged<E> {
+    endpoint: E,
+    limit: Option<usize>,
+}
+
+pub fn paged<E>(endpoint: E) -> Paged<E> {
+    Paged {
+        endpoint,
+        limit: None,
+    }
+}
+
+type Item<E> = <<E as Pageable>::Page as Page>::Item;
+
+struct PagedState<'a, E: Pageable, C> {
+    endpoint: E,
+    client: &'a C,
+    items: VecDeque<Item<E>>,
+    remaining: Option<usize>,
+    /// Cursor the last page was requested with.
+    cursor: Option<String>,
+    last_page: bool,
+}
+
+impl<E> Paged<E>
+where
+    E: Pageable + Clone + Send + Sync,
+    E::Page: Send + 'static,
+    Item<E>: Send,
+{
+    /// Stops after `limit` items, without requesting further pages.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Items of every page, in order, until the last page or the limit is reached.
+    ///
+    /// The next page is only requested once the items of the previous one are consumed. The
+    /// stream ends after the first error, or when a page repeats the cursor it was requested with
+    /// or has a cursor but no items, rather than requesting the same page forever.
+    pub fn stream<'a, C>(
+        &self,
+        client: &'a C,
+    ) -> impl Stream<Item = Result<Item<E>, ApiError<C::Error>>> + Send + 'a
+    where
+        C: AsyncClient + Sync,
+        E: 'a,
+    {
+        let state = PagedState {
+            endpoint: self.endpoint.clone(),
+            client,
+            items: VecDeque::new(),
+            remaining: self.limit,
+            cursor: None,
+            last_page: false,
+        };
+        stream::try_unfold(state, |mut state| async move {
+            if state.remaining == Some(0) {
+                return Ok(None);
+            }
+            loop {
+                if let Some(item) = state.items.pop_front() {
+                    state.remaining = state.remaining.map(|remaining| remaining - 1);
+                    return Ok(Some((item, state)));
+                }
+                if state.last_page {
+                    return Ok(None);
+                }
+                let page: E::Page = state.endpoint.query_async(state.client).await?;
+                let cursor = page.next_cursor().map(str::to_owned);
+                let items = page.into_items();
+                match cursor {
+                    Some(cursor) if items.is_empty() || state.cursor.as_ref() == Some(&cursor) => {
+                        tracing::warn!(
+                            "Stopped paging {}, cursor {} does not advance",
+                            state.endpoint.endpoint(),
+                            cursor
+                        );
+                        state.last_page = true;
+                    }
+                    Some(cursor) => {
+                        state.endpoint.set_cursor(cursor.clone());
+                        state.cursor = Some(cursor);
+                    }
+                    None => state.last_page = true,
+                }
+                state.items.extend(items);
+            }
+        })
+    }
+}
@@ -0,0 +1,62 @@
+use std::borrow::Cow;
+
+use url::Url;
+
+/// Query parameters of a request.
+#[derive(Debug, Clone, Default)]
+pub struct QueryParams<'a> {
+    params: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> QueryParams<'a> {
+    pub fn push(&mut self, key: impl Into<Cow<'a, str>>, value: impl ToString) -> &mut Self {
+        self.params.push((key.into(), value.to_string().into()));
+        self
+    }
+
+    /// Pushes the parameter only if `value` is set.
+    pub fn push_opt(
+        &mut self,
+        key: impl Into<Cow<'a, str>>,
+        value: Option<impl ToString>,
+    ) -> &mut Self {
+        if let Some(value) = value {
+            self.push(key, value);
+        }
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    pub fn add_to_url(&self, url: &mut Url) {
+        if self.params.is_empty() {
+            return;
+        }
+        let mut pairs = url.query_pairs_mut();
+        for (key, value) in &self.params {
+            pairs.append_pair(key, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_params_to_url() {
+        let mut url = Url::parse("https://example.com/groups").unwrap();
+        let mut params = QueryParams::default();
+        params
+            .push("group_id", "a b")
+            .push_opt("cursor", None::<&str>)
+            .push("page_size", 50);
+        params.add_to_url(&mut url);
+        assert_eq!(
+            url.as_str(),
+            "https://example.com/groups?group_id=a+b&page_size=50"
+        );
+    }
+}
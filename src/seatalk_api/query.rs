@@ -1,10 +1,15 @@
-use http::Uri;
+use bytes::Bytes;
+use http::{header, Request, Response, Uri};
+use serde::de::{DeserializeOwned, IgnoredAny};
 use url::Url;
 
-use crate::seatalk_api::client::{AsyncClient, Client};
+use crate::seatalk_api::client::{AsyncClient, Client, RestClient};
 use async_trait::async_trait;
 
-use super::api::error::ApiError;
+use super::{api::error::ApiError, endpoint::Endpoint};
+
+/// Header SeaTalk identifies a request with, to quote when reporting an issue to SeaTalk.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
 
 pub fn url_to_http_uri(url: Url) -> Uri {
     url.as_str()
@@ -26,3 +31,135 @@ where
 {
     async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>>;
 }
+
+/// Builds the request for `endpoint`, including its retry policy, rate limit key and maximum
+/// response size.
+fn build_request<E, C>(
+    endpoint: &E,
+    client: &C,
+) -> Result<(http::request::Builder, Vec<u8>), ApiError<C::Error>>
+where
+    E: Endpoint + ?Sized,
+    C: RestClient,
+{
+    let mut url = client.rest_endpoint(&endpoint.endpoint())?;
+    endpoint.parameters().add_to_url(&mut url);
+    let mut req = Request::builder()
+        .method(endpoint.method())
+        .uri(url_to_http_uri(url));
+    if let Some(policy) = endpoint.retry_policy() {
+        req = req.extension(policy);
+    }
+    if let Some(key) = endpoint.rate_limit_key() {
+        req = req.extension(key);
+    }
+    if let Some(max) = endpoint.max_response_size() {
+        req = req.extension(max);
+    }
+
+    match endpoint.body()? {
+        Some((mime, data)) => Ok((req.header(header::CONTENT_TYPE, mime), data)),
+        None => Ok((req, Vec::new())),
+    }
+}
+
+/// Sends the request for `endpoint` without checking the response.
+pub(crate) fn send<E, C>(endpoint: &E, client: &C) -> Result<Response<Bytes>, ApiError<C::Error>>
+where
+    E: Endpoint + ?Sized,
+    C: Client,
+{
+    let (req, data) = build_request(endpoint, client)?;
+    if endpoint.require_auth() {
+        client.rest(req, data)
+    } else {
+        client.rest_no_auth(req, data)
+    }
+}
+
+/// Sends the request for `endpoint` without checking the response.
+pub(crate) async fn send_async<E, C>(
+    endpoint: &E,
+    client: &C,
+) -> Result<Response<Bytes>, ApiError<C::Error>>
+where
+    E: Endpoint + Sync + ?Sized,
+    C: AsyncClient + Sync,
+{
+    let (req, data) = build_request(endpoint, client)?;
+    if endpoint.require_auth() {
+        client.rest_async(req, data).await
+    } else {
+        client.rest_async_no_auth(req, data).await
+    }
+}
+
+/// The id SeaTalk assigned to the request `rsp` answers.
+pub(crate) fn request_id(rsp: &Response<Bytes>) -> Option<String> {
+    rsp.headers()
+        .get(REQUEST_ID_HEADER)?
+        .to_str()
+        .ok()
+        .map(Into::into)
+}
+
+/// Checks that SeaTalk handled the request to `endpoint` and deserializes the response body.
+///
+/// Every failure is logged and carries the SeaTalk request id of the response.
+pub(crate) fn handle_response<T, E>(endpoint: &str, rsp: &Response<Bytes>) -> Result<T, ApiError<E>>
+where
+    T: DeserializeOwned,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let request_id = request_id(rsp);
+    let result = check_response(rsp, request_id.clone()).and_then(|v| {
+        serde_json::from_value::<T>(v).map_err(|e| ApiError::data_type::<T>(e, request_id.clone()))
+    });
+    if let Err(e) = &result {
+        tracing::error!(
+            "SeaTalk request to {} failed (request id: {}): {}",
+            endpoint,
+            request_id.as_deref().unwrap_or("none"),
+            e
+        );
+    }
+    result
+}
+
+/// Returns the body of a successful response to `endpoint` that is not JSON, e.g. a downloaded
+/// file, and handles any other response like [`handle_response`].
+pub(crate) fn handle_file_response<E>(
+    endpoint: &str,
+    rsp: Response<Bytes>,
+) -> Result<Bytes, ApiError<E>>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let is_json = rsp
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"));
+    if rsp.status().is_success() && !is_json {
+        return Ok(rsp.into_body());
+    }
+    handle_response::<IgnoredAny, _>(endpoint, &rsp)?;
+    Ok(rsp.into_body())
+}
+
+fn check_response<E>(
+    rsp: &Response<Bytes>,
+    request_id: Option<String>,
+) -> Result<serde_json::Value, ApiError<E>>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let status = rsp.status();
+    let Ok(v) = serde_json::from_slice::<serde_json::Value>(rsp.body()) else {
+        return Err(ApiError::server_error(status, rsp.body(), request_id));
+    };
+    if !status.is_success() || v.get("code").is_none_or(|code| code != 0) {
+        return Err(ApiError::from_seatalk(v, request_id));
+    }
+    Ok(v)
+}
@@ -0,0 +1,357 @@
+use std::{
+    collections::HashMap,
+    num::NonZeroU32,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use governor::{
+    clock::{Clock, DefaultClock},
+    DefaultDirectRateLimiter, DefaultKeyedRateLimiter, Quota, RateLimiter,
+};
+
+/// Endpoint files are downloaded from, followed by the id of the file.
+const FILE_ENDPOINT: &str = "messaging/v2/file";
+
+/// Number of per target requests after which chats without recent requests are forgotten.
+const PRUNE_INTERVAL: u64 = 1024;
+
+/// Key of the chat a request is sent to, for endpoints that SeaTalk rate limits per chat.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RateLimitKey(pub String);
+
+/// Allows `requests` requests every `period`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub requests: u32,
+    pub period: Duration,
+}
+
+impl RateLimit {
+    pub fn new(requests: u32, period: Duration) -> Self {
+        Self { requests, period }
+    }
+
+    fn quota(&self) -> Quota {
+        let requests = NonZeroU32::new(self.requests).unwrap_or(NonZeroU32::MIN);
+        Quota::with_period(self.period / requests.get())
+            .unwrap_or_else(|| Quota::per_second(requests))
+            .allow_burst(requests)
+    }
+}
+
+/// Rate limits applied to SeaTalk requests.
+///
+/// Every endpoint gets its own bucket unless it is configured to share one, endpoints without a
+/// configured limit share the `default` bucket. File downloads are limited and measured as one
+/// `messaging/v2/file` endpoint. Endpoints with a per target limit are additionally limited for
+/// every chat they send to, see [`RateLimitKey`]. Only chats with recent requests are kept track
+/// of.
+#[derive(Debug, Clone)]
+pub struct RateLimits {
+    default: RateLimit,
+    /// Endpoints sharing a bucket, with its limit.
+    endpoints: Vec<(Vec<String>, RateLimit)>,
+    per_target: HashMap<String, RateLimit>,
+}
+
+impl Default for RateLimits {
+    fn default() -> Self {
+        Self::new(RateLimit::new(1, Duration::from_secs(2)))
+    }
+}
+
+impl RateLimits {
+    pub fn new(default: RateLimit) -> Self {
+        Self {
+            default,
+            endpoints: Vec::new(),
+            per_target: HashMap::new(),
+        }
+    }
+
+    /// Limit for all requests to `endpoint`, e.g. `messaging/v2/group_chat`.
+    pub fn endpoint(self, endpoint: impl Into<String>, limit: RateLimit) -> Self {
+        self.endpoints([endpoint], limit)
+    }
+
+    /// Limit for all requests to any of `endpoints` together, they share one bucket.
+    pub fn endpoints<I>(mut self, endpoints: I, limit: RateLimit) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.endpoints
+            .push((endpoints.into_iter().map(Into::into).collect(), limit));
+        self
+    }
+
+    /// Limit for requests to `endpoint` sent to the same chat.
+    pub fn per_target(mut self, endpoint: impl Into<String>, limit: RateLimit) -> Self {
+        self.per_target.insert(endpoint.into(), limit);
+        self
+    }
+}
+
+/// Time requests spent waiting for a rate limiter.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WaitStats {
+    /// Number of requests that passed the rate limiter.
+    pub requests: u64,
+    /// Number of requests that had to wait.
+    pub delayed: u64,
+    pub total_wait: Duration,
+    pub max_wait: Duration,
+}
+
+impl WaitStats {
+    fn record(&mut self, wait: Duration) {
+        self.requests += 1;
+        if !wait.is_zero() {
+            self.delayed += 1;
+        }
+        self.total_wait += wait;
+        self.max_wait = self.max_wait.max(wait);
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct RateLimiters {
+    default: DefaultDirectRateLimiter,
+    endpoints: HashMap<String, Arc<DefaultDirectRateLimiter>>,
+    per_target: HashMap<String, DefaultKeyedRateLimiter<String>>,
+    per_target_requests: AtomicU64,
+    metrics: Mutex<HashMap<String, WaitStats>>,
+}
+
+impl RateLimiters {
+    pub(crate) fn new(limits: &RateLimits) -> Self {
+        Self {
+            default: RateLimiter::direct(limits.default.quota()),
+            endpoints: limits
+                .endpoints
+                .iter()
+                .flat_map(|(endpoints, limit)| {
+                    let limiter = Arc::new(RateLimiter::direct(limit.quota()));
+                    endpoints
+                        .iter()
+                        .map(move |endpoint| (endpoint.clone(), limiter.clone()))
+                })
+                .collect(),
+            per_target: limits
+                .per_target
+                .iter()
+                .map(|(endpoint, limit)| (endpoint.clone(), RateLimiter::keyed(limit.quota())))
+                .collect(),
+            per_target_requests: AtomicU64::new(0),
+            metrics: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn endpoint_limiter(&self, endpoint: &str) -> &DefaultDirectRateLimiter {
+        self.endpoints
+            .get(endpoint)
+            .map(AsRef::as_ref)
+            .unwrap_or(&self.default)
+    }
+
+    /// Waits until a request to `path` for the chat `key` is allowed.
+    pub(crate) async fn until_ready(&self, path: &str, key: Option<&RateLimitKey>) {
+        let endpoint = endpoint_name(path);
+        let start = Instant::now();
+        self.endpoint_limiter(endpoint).until_ready().await;
+        if let (Some(limiter), Some(RateLimitKey(key))) = (self.per_target.get(endpoint), key) {
+            limiter.until_key_ready(key).await;
+            self.count_per_target_request();
+        }
+        self.record(endpoint, start);
+    }
+
+    /// Blocks the current thread until a request to `path` for the chat `key` is allowed.
+    pub(crate) fn until_ready_blocking(&self, path: &str, key: Option<&RateLimitKey>) {
+        let endpoint = endpoint_name(path);
+        let start = Instant::now();
+        let clock = DefaultClock::default();
+        let limiter = self.endpoint_limiter(endpoint);
+        while let Err(not_until) = limiter.check() {
+            std::thread::sleep(not_until.wait_time_from(clock.now()));
+        }
+        if let (Some(limiter), Some(RateLimitKey(key))) = (self.per_target.get(endpoint), key) {
+            while let Err(not_until) = limiter.check_key(key) {
+                std::thread::sleep(not_until.wait_time_from(clock.now()));
+            }
+            self.count_per_target_request();
+        }
+        self.record(endpoint, start);
+    }
+
+    /// Prunes the per target limiters every [`PRUNE_INTERVAL`] requests, so their number of
+    /// chats doesn't grow with every chat ever sent to.
+    fn count_per_target_request(&self) {
+        if self.per_target_requests.fetch_add(1, Ordering::Relaxed) % PRUNE_INTERVAL
+            == PRUNE_INTERVAL - 1
+        {
+            self.prune();
+        }
+    }
+
+    /// Forgets the chats whose limit has fully replenished.
+    fn prune(&self) {
+        for limiter in self.per_target.values() {
+            limiter.retain_recent();
+            limiter.shrink_to_fit();
+        }
+    }
+
+    fn record(&self, endpoint: &str, start: Instant) {
+        // Waits below a millisecond are bookkeeping overhead, not rate limiting.
+        let wait = Some(start.elapsed())
+            .filter(|wait| *wait >= Duration::from_millis(1))
+            .unwrap_or_default();
+        if !wait.is_zero() {
+            tracing::debug!("Rate limited request to {} for {:?}", endpoint, wait);
+        }
+        self.metrics
+            .lock()
+            .unwrap()
+            .entry(endpoint.to_owned())
+            .or_default()
+            .record(wait);
+    }
+
+    /// Wait time statistics per endpoint.
+    pub(crate) fn metrics(&self) -> HashMap<String, WaitStats> {
+        self.metrics.lock().unwrap().clone()
+    }
+}
+
+/// Endpoint requests to `path` are limited and measured as.
+fn endpoint_name(path: &str) -> &str {
+    let endpoint = path.trim_start_matches('/');
+    match endpoint.strip_prefix(FILE_ENDPOINT) {
+        Some(id) if id.starts_with('/') => FILE_ENDPOINT,
+        _ => endpoint,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn limits_per_target() {
+        let limits = RateLimits::new(RateLimit::new(100, Duration::from_secs(1))).per_target(
+            "messaging/v2/group_chat",
+            RateLimit::new(1, Duration::from_millis(200)),
+        );
+        let limiters = RateLimiters::new(&limits);
+        let a = RateLimitKey("a".into());
+        let b = RateLimitKey("b".into());
+
+        limiters
+            .until_ready("/messaging/v2/group_chat", Some(&a))
+            .await;
+        limiters
+            .until_ready("/messaging/v2/group_chat", Some(&b))
+            .await;
+        let metrics = limiters.metrics();
+        assert_eq!(metrics["messaging/v2/group_chat"].delayed, 0);
+
+        limiters
+            .until_ready("/messaging/v2/group_chat", Some(&a))
+            .await;
+        let stats = &limiters.metrics()["messaging/v2/group_chat"];
+        assert_eq!(stats.requests, 3);
+        assert_eq!(stats.delayed, 1);
+        assert!(stats.max_wait >= Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn forgets_idle_targets() {
+        let limits = RateLimits::new(RateLimit::new(100, Duration::from_secs(1))).per_target(
+            "messaging/v2/group_chat",
+            RateLimit::new(1, Duration::from_millis(20)),
+        );
+        let limiters = RateLimiters::new(&limits);
+        for key in ["a", "b"] {
+            limiters
+                .until_ready("/messaging/v2/group_chat", Some(&RateLimitKey(key.into())))
+                .await;
+        }
+        let limiter = &limiters.per_target["messaging/v2/group_chat"];
+        assert_eq!(limiter.len(), 2);
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        limiters.prune();
+        assert_eq!(limiter.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn shares_buckets_between_endpoints() {
+        let limits = RateLimits::new(RateLimit::new(100, Duration::from_secs(1))).endpoints(
+            [
+                "contacts/v2/profile",
+                "contacts/v2/get_employee_code_with_seatalk_id",
+            ],
+            RateLimit::new(1, Duration::from_millis(200)),
+        );
+        let limiters = RateLimiters::new(&limits);
+
+        limiters.until_ready("/contacts/v2/profile", None).await;
+        limiters
+            .until_ready("/contacts/v2/get_employee_code_with_seatalk_id", None)
+            .await;
+        let metrics = limiters.metrics();
+        assert_eq!(metrics["contacts/v2/profile"].delayed, 0);
+        assert_eq!(
+            metrics["contacts/v2/get_employee_code_with_seatalk_id"].delayed,
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn endpoints_have_separate_buckets() {
+        let limits = RateLimits::new(RateLimit::new(1, Duration::from_secs(60))).endpoint(
+            "auth/app_access_token",
+            RateLimit::new(1, Duration::from_secs(60)),
+        );
+        let limiters = RateLimiters::new(&limits);
+
+        limiters.until_ready("/auth/app_access_token", None).await;
+        limiters.until_ready("/messaging/v2/group_chat", None).await;
+        let metrics = limiters.metrics();
+        assert_eq!(metrics["auth/app_access_token"].delayed, 0);
+        assert_eq!(metrics["messaging/v2/group_chat"].delayed, 0);
+    }
+
+    #[tokio::test]
+    async fn downloads_share_one_endpoint() {
+        let limits = RateLimits::new(RateLimit::new(100, Duration::from_secs(1))).endpoint(
+            "messaging/v2/file",
+            RateLimit::new(1, Duration::from_millis(200)),
+        );
+        let limiters = RateLimiters::new(&limits);
+
+        limiters.until_ready("/messaging/v2/file/a", None).await;
+        limiters.until_ready("/messaging/v2/file/b", None).await;
+        let metrics = limiters.metrics();
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(metrics["messaging/v2/file"].requests, 2);
+        assert_eq!(metrics["messaging/v2/file"].delayed, 1);
+    }
+
+    #[test]
+    fn blocking_waits_for_quota() {
+        let limits = RateLimits::new(RateLimit::new(1, Duration::from_millis(100)));
+        let limiters = RateLimiters::new(&limits);
+
+        limiters.until_ready_blocking("/messaging/v2/single_chat", None);
+        limiters.until_ready_blocking("/messaging/v2/single_chat", None);
+        let stats = &limiters.metrics()["messaging/v2/single_chat"];
+        assert_eq!(stats.delayed, 1);
+        assert!(stats.max_wait >= Duration::from_millis(50));
+    }
+}
@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::Response;
+use serde::de::IgnoredAny;
+
+use super::{
+    api::error::ApiError,
+    client::{AsyncClient, Client},
+    endpoint::Endpoint,
+    query::{self, AsyncQuery, Query},
+};
+
+/// Queries an endpoint and returns the whole response, with its status, headers and undecoded
+/// body.
+///
+/// Responses that SeaTalk answered with a non-zero `code` are still returned as errors.
+pub struct Raw<E> {
+    endpoint: E,
+}
+
+pub fn raw<E>(endpoint: E) -> Raw<E> {
+    Raw { endpoint }
+}
+
+impl<E, C> Query<Response<Bytes>, C> for Raw<E>
+where
+    E: Endpoint,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Response<Bytes>, ApiError<<C>::Error>> {
+        let rsp = query::send(&self.endpoint, client)?;
+        query::handle_response::<IgnoredAny, _>(&self.endpoint.endpoint(), &rsp)?;
+        Ok(rsp)
+    }
+}
+
+#[async_trait]
+impl<E, C> AsyncQuery<Response<Bytes>, C> for Raw<E>
+where
+    E: Endpoint + Sync,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Response<Bytes>, ApiError<C::Error>> {
+        let rsp = query::send_async(&self.endpoint, client).await?;
+        query::handle_response::<IgnoredAny, _>(&self.endpoint.endpoint(), &rsp)?;
+        Ok(rsp)
+    }
+}
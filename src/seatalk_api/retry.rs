@@ -0,0 +1,235 @@
+use std::time::Duration;
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use http::{header, Method, Response, StatusCode};
+use rand::Rng;
+
+use super::api::SeatalkErrorCode;
+
+/// How failed SeaTalk requests are retried.
+///
+/// Requests are retried with exponential backoff between `min_delay` and `max_delay`. A
+/// `Retry-After` header sent by SeaTalk takes precedence over the computed delay, requests asked
+/// to wait longer than `max_delay` are not retried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub min_delay: Duration,
+    pub max_delay: Duration,
+    pub factor: u32,
+    pub jitter: bool,
+    /// Retry requests that may have reached SeaTalk even if the method is not idempotent.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            min_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            factor: 2,
+            jitter: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn with_delay(mut self, min_delay: Duration, max_delay: Duration) -> Self {
+        self.min_delay = min_delay;
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn with_retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    /// Whether a request that failed in transit may be sent again.
+    ///
+    /// Requests that never reached SeaTalk are always safe to resend.
+    pub(crate) fn may_resend(&self, method: &Method, reached_server: bool) -> bool {
+        !reached_server || self.retry_non_idempotent || is_idempotent(method)
+    }
+
+    /// Delay before retry number `attempt + 1`, or `None` if the request should not be retried.
+    ///
+    /// A `retry_after` above `max_delay` gives up rather than waiting less than SeaTalk asked.
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if attempt >= self.max_retries {
+            return None;
+        }
+        if let Some(retry_after) = retry_after {
+            return (retry_after <= self.max_delay).then_some(retry_after);
+        }
+        let backoff = self
+            .min_delay
+            .saturating_mul(self.factor.saturating_pow(attempt))
+            .min(self.max_delay);
+        if !self.jitter || backoff.is_zero() {
+            return Some(backoff);
+        }
+        let half = backoff / 2;
+        Some(half + rand::thread_rng().gen_range(Duration::ZERO..=half))
+    }
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+    )
+}
+
+/// Whether SeaTalk answered with a failure that may succeed if the request is sent again.
+pub(crate) fn is_retryable_response(rsp: &Response<Bytes>) -> bool {
+    let status = rsp.status();
+    if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+        return true;
+    }
+    serde_json::from_slice::<serde_json::Value>(rsp.body())
+        .ok()
+        .and_then(|v| v.get("code")?.as_i64())
+        .is_some_and(|code| SeatalkErrorCode::from(code).is_retryable())
+}
+
+/// Whether SeaTalk refused the request without handling it, so it is safe to send again.
+pub(crate) fn is_unprocessed_response(rsp: &Response<Bytes>) -> bool {
+    if rsp.status() == StatusCode::TOO_MANY_REQUESTS {
+        return true;
+    }
+    serde_json::from_slice::<serde_json::Value>(rsp.body())
+        .ok()
+        .and_then(|v| v.get("code")?.as_i64())
+        .is_some_and(|code| SeatalkErrorCode::from(code) == SeatalkErrorCode::RateLimited)
+}
+
+/// Parses the `Retry-After` header, given either in seconds or as an HTTP date.
+pub(crate) fn retry_after(rsp: &Response<Bytes>) -> Option<Duration> {
+    let value = rsp.headers().get(header::RETRY_AFTER)?.to_str().ok()?;
+    if let Ok(secs) = value.trim().parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status: StatusCode, body: &str) -> Response<Bytes> {
+        Response::builder()
+            .status(status)
+            .body(Bytes::from(body.to_owned()))
+            .unwrap()
+    }
+
+    #[test]
+    fn backoff_grows_until_max_delay() {
+        let policy = RetryPolicy::default()
+            .with_max_retries(5)
+            .with_delay(Duration::from_secs(1), Duration::from_secs(5))
+            .with_jitter(false);
+        let delays: Vec<_> = (0..6).map(|attempt| policy.delay(attempt, None)).collect();
+        assert_eq!(
+            delays,
+            [1, 2, 4, 5, 5]
+                .map(|secs| Some(Duration::from_secs(secs)))
+                .into_iter()
+                .chain([None])
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn jitter_stays_within_backoff() {
+        let policy =
+            RetryPolicy::default().with_delay(Duration::from_secs(2), Duration::from_secs(2));
+        for _ in 0..100 {
+            let delay = policy.delay(0, None).unwrap();
+            assert!(delay >= Duration::from_secs(1) && delay <= Duration::from_secs(2));
+        }
+    }
+
+    #[test]
+    fn retry_after_overrides_backoff() {
+        let policy = RetryPolicy::default();
+        assert_eq!(
+            policy.delay(0, Some(Duration::from_secs(3))),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(policy.delay(0, Some(Duration::from_secs(60))), None);
+
+        let rsp = Response::builder()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .header(header::RETRY_AFTER, "3")
+            .body(Bytes::new())
+            .unwrap();
+        assert_eq!(retry_after(&rsp), Some(Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn classifies_responses() {
+        assert!(is_retryable_response(&response(
+            StatusCode::BAD_GATEWAY,
+            ""
+        )));
+        assert!(is_retryable_response(&response(
+            StatusCode::OK,
+            r#"{"code":101}"#
+        )));
+        assert!(!is_retryable_response(&response(
+            StatusCode::OK,
+            r#"{"code":0}"#
+        )));
+        assert!(!is_retryable_response(&response(
+            StatusCode::OK,
+            r#"{"code":7001}"#
+        )));
+        assert!(is_unprocessed_response(&response(
+            StatusCode::OK,
+            r#"{"code":101}"#
+        )));
+        assert!(!is_unprocessed_response(&response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            ""
+        )));
+    }
+
+    #[test]
+    fn only_resends_safe_requests() {
+        let policy = RetryPolicy::default();
+        assert!(policy.may_resend(&Method::POST, false));
+        assert!(!policy.may_resend(&Method::POST, true));
+        assert!(policy.may_resend(&Method::GET, true));
+        assert!(policy
+            .with_retry_non_idempotent(true)
+            .may_resend(&Method::POST, true));
+    }
+}
@@ -1,5 +1,4 @@
-use governor::{DefaultDirectRateLimiter, Jitter, Quota, RateLimiter};
-use std::{num::NonZeroU32, sync::Arc};
+use std::{collections::HashMap, future::Future, io::Read, sync::Arc, time::Duration};
 use tokio::sync::Mutex;
 
 use async_trait::async_trait;
@@ -9,11 +8,14 @@ use thiserror::Error;
 use url::Url;
 
 use super::{
-    api,
+    api::{self, SeatalkErrorCode},
     auth::{AccessToken, Auth, AuthError},
     client::{self, RestClient},
+    endpoint::MaxResponseSize,
+    rate_limit::{RateLimitKey, RateLimiters, RateLimits, WaitStats},
+    retry::{self, RetryPolicy},
 };
-use reqwest::Client as AsyncClient;
+use reqwest::{blocking::Client, Client as AsyncClient};
 
 #[derive(Debug, Error)]
 pub enum SeatalkError {
@@ -31,36 +33,145 @@ pub enum SeatalkError {
 
 pub type SeatalkResult<T> = Result<T, SeatalkError>;
 
+/// Blocking SeaTalk client.
+///
+/// Must not be used from within an async runtime, use [`AsyncSeatalk`] there instead.
+#[derive(Debug)]
+pub struct Seatalk {
+    client: Client,
+    lim: RateLimiters,
+    rest_url: Url,
+    auth: Auth,
+    access_token: std::sync::Mutex<Option<AccessToken>>,
+    retry_policy: RetryPolicy,
+}
+
 #[derive(Debug)]
 pub struct AsyncSeatalk {
     client: reqwest::Client,
-    lim: DefaultDirectRateLimiter,
+    lim: RateLimiters,
     rest_url: Url,
     auth: Auth,
     access_token: Arc<Mutex<Option<AccessToken>>>,
+    retry_policy: RetryPolicy,
 }
 
-impl AsyncSeatalk {
-    pub async fn new(protocol: &str, host: &str, auth: Auth) -> SeatalkResult<Self> {
-        let rest_url = Url::parse(&format!("{}://{}/", protocol, host))?;
+#[derive(Debug)]
+pub struct SeatalkBuilder {
+    protocol: String,
+    host: String,
+    auth: Auth,
+    retry_policy: RetryPolicy,
+    rate_limits: RateLimits,
+}
+
+impl SeatalkBuilder {
+    pub fn new(host: impl Into<String>, auth: Auth) -> Self {
+        Self {
+            protocol: "https".into(),
+            host: host.into(),
+            auth,
+            retry_policy: RetryPolicy::default(),
+            rate_limits: RateLimits::default(),
+        }
+    }
+
+    /// Talk to SeaTalk over plain http.
+    pub fn insecure(mut self) -> Self {
+        self.protocol = "http".into();
+        self
+    }
+
+    /// Retry policy for endpoints that do not set their own.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn rate_limits(mut self, rate_limits: RateLimits) -> Self {
+        self.rate_limits = rate_limits;
+        self
+    }
+
+    /// Builds the client, fetching the access token of the first request.
+    ///
+    /// Fails with [`api::ApiError::Auth`] if SeaTalk rejects the credentials.
+    pub fn build(self) -> SeatalkResult<Seatalk> {
+        let api = Seatalk {
+            client: Client::new(),
+            lim: RateLimiters::new(&self.rate_limits),
+            rest_url: self.rest_url()?,
+            auth: self.auth,
+            access_token: std::sync::Mutex::new(None),
+            retry_policy: self.retry_policy,
+        };
+
+        api.access_token(None)?;
+
+        Ok(api)
+    }
+
+    /// Builds the client, fetching the access token of the first request.
+    ///
+    /// Fails with [`api::ApiError::Auth`] if SeaTalk rejects the credentials.
+    pub async fn build_async(self) -> SeatalkResult<AsyncSeatalk> {
+        let rest_url = self.rest_url()?;
         let access_token = Arc::new(Mutex::new(None));
         let client = AsyncClient::new();
 
-        let lim =
-            RateLimiter::direct(Quota::with_period(std::time::Duration::from_secs(2)).unwrap());
+        let lim = RateLimiters::new(&self.rate_limits);
 
-        let api = Self {
+        let api = AsyncSeatalk {
             client,
             lim,
             rest_url,
-            auth,
+            auth: self.auth,
             access_token,
+            retry_policy: self.retry_policy,
         };
 
-        *api.access_token.lock().await = Some(api.auth.get_access_token_async(&api).await?);
+        api.access_token(None).await?;
 
         Ok(api)
     }
+
+    fn rest_url(&self) -> Result<Url, url::ParseError> {
+        Url::parse(&format!("{}://{}/", self.protocol, self.host))
+    }
+}
+
+impl Seatalk {
+    pub fn new(protocol: &str, host: &str, auth: Auth) -> SeatalkResult<Self> {
+        let mut builder = Self::builder(host, auth);
+        builder.protocol = protocol.into();
+        builder.build()
+    }
+
+    pub fn builder(host: impl Into<String>, auth: Auth) -> SeatalkBuilder {
+        SeatalkBuilder::new(host, auth)
+    }
+
+    /// Time requests spent waiting for the rate limiters, per endpoint.
+    pub fn rate_limit_metrics(&self) -> HashMap<String, WaitStats> {
+        self.lim.metrics()
+    }
+}
+
+impl AsyncSeatalk {
+    pub async fn new(protocol: &str, host: &str, auth: Auth) -> SeatalkResult<Self> {
+        let mut builder = Self::builder(host, auth);
+        builder.protocol = protocol.into();
+        builder.build_async().await
+    }
+
+    pub fn builder(host: impl Into<String>, auth: Auth) -> SeatalkBuilder {
+        SeatalkBuilder::new(host, auth)
+    }
+
+    /// Time requests spent waiting for the rate limiters, per endpoint.
+    pub fn rate_limit_metrics(&self) -> HashMap<String, WaitStats> {
+        self.lim.metrics()
+    }
 }
 
 #[derive(Debug, Error)]
@@ -80,6 +191,38 @@ pub enum RestError {
         #[from]
         source: http::Error,
     },
+    #[error("reading the response: {}", source)]
+    Read {
+        #[from]
+        source: std::io::Error,
+    },
+    /// The response is larger than the request's [`MaxResponseSize`].
+    #[error("response larger than {max} bytes")]
+    ResponseTooLarge { max: usize },
+}
+
+impl RestError {
+    /// Whether the request failed in transit and may succeed if sent again.
+    fn is_transient(&self) -> bool {
+        match self {
+            Self::Communication { source } => {
+                source.is_connect() || source.is_timeout() || source.is_request()
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the request may have been received by SeaTalk before failing.
+    fn reached_server(&self) -> bool {
+        !matches!(self, Self::Communication { source } if source.is_connect())
+    }
+}
+
+impl RestClient for Seatalk {
+    type Error = RestError;
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, api::ApiError<Self::Error>> {
+        Ok(self.rest_url.join(endpoint)?)
+    }
 }
 
 impl RestClient for AsyncSeatalk {
@@ -89,46 +232,319 @@ impl RestClient for AsyncSeatalk {
     }
 }
 
+impl Seatalk {
+    /// Returns a usable access token, fetching a new one if the cached token is expired or is
+    /// `rejected`.
+    fn access_token(
+        &self,
+        rejected: Option<&AccessToken>,
+    ) -> Result<AccessToken, api::ApiError<RestError>> {
+        let mut access_token = self.access_token.lock().unwrap();
+        let usable = access_token.as_ref().filter(|t| {
+            !t.is_expired() && rejected.is_none_or(|r| r.app_access_token != t.app_access_token)
+        });
+        if let Some(token) = usable {
+            return Ok(token.clone());
+        }
+        let token = self.auth.get_access_token(self)?;
+        *access_token = Some(token.clone());
+        Ok(token)
+    }
+
+    fn with_retry<F>(
+        &self,
+        request: &http::Request<Vec<u8>>,
+        send: F,
+    ) -> Result<Response<Bytes>, api::ApiError<RestError>>
+    where
+        F: Fn() -> Result<Response<Bytes>, api::ApiError<RestError>>,
+    {
+        let policy = request
+            .extensions()
+            .get::<RetryPolicy>()
+            .unwrap_or(&self.retry_policy);
+        let mut attempt = 0;
+        loop {
+            let result = send();
+            let Some(delay) = retry_delay(policy, request, &result, attempt) else {
+                return result;
+            };
+            attempt += 1;
+            std::thread::sleep(delay);
+        }
+    }
+
+    fn send_authenticated(
+        &self,
+        request: &http::Request<Vec<u8>>,
+    ) -> Result<Response<Bytes>, api::ApiError<RestError>> {
+        let token = self.access_token(None)?;
+        let rsp = self.send_with_token(request, &token)?;
+        if !is_token_rejected(&rsp) {
+            return Ok(rsp);
+        }
+
+        tracing::warn!("SeaTalk rejected the access token, refreshing and retrying");
+        let token = self.access_token(Some(&token))?;
+        self.send_with_token(request, &token)
+    }
+
+    fn send_with_token(
+        &self,
+        request: &http::Request<Vec<u8>>,
+        token: &AccessToken,
+    ) -> Result<Response<Bytes>, api::ApiError<RestError>> {
+        let mut request = clone_request(request);
+        token
+            .set_headers(request.headers_mut())
+            .map_err(|e| api::ApiError::client(e.into()))?;
+        self.send(request).map_err(api::ApiError::client)
+    }
+
+    fn send(&self, request: http::Request<Vec<u8>>) -> Result<Response<Bytes>, RestError> {
+        self.lim.until_ready_blocking(
+            request.uri().path(),
+            request.extensions().get::<RateLimitKey>(),
+        );
+        let max_size = request.extensions().get::<MaxResponseSize>().copied();
+        let request = request.try_into()?;
+        let rsp = self.client.execute(request)?;
+
+        let mut http_rsp = Response::builder()
+            .status(rsp.status())
+            .version(rsp.version());
+        let headers = http_rsp.headers_mut().unwrap();
+        for (key, value) in rsp.headers() {
+            headers.insert(key, value.clone());
+        }
+        let body = match max_size {
+            Some(MaxResponseSize(max)) => {
+                check_content_length(rsp.content_length(), max)?;
+                let mut body = Vec::new();
+                rsp.take(max as u64 + 1).read_to_end(&mut body)?;
+                if body.len() > max {
+                    return Err(RestError::ResponseTooLarge { max });
+                }
+                body.into()
+            }
+            None => rsp.bytes()?,
+        };
+        Ok(http_rsp.body(body)?)
+    }
+}
+
+impl AsyncSeatalk {
+    /// Returns a usable access token, fetching a new one if the cached token is expired or is
+    /// `rejected`.
+    ///
+    /// The token lock is held while fetching so concurrent requests share a single refresh.
+    async fn access_token(
+        &self,
+        rejected: Option<&AccessToken>,
+    ) -> Result<AccessToken, api::ApiError<RestError>> {
+        let mut access_token = self.access_token.lock().await;
+        let usable = access_token.as_ref().filter(|t| {
+            !t.is_expired() && rejected.is_none_or(|r| r.app_access_token != t.app_access_token)
+        });
+        if let Some(token) = usable {
+            return Ok(token.clone());
+        }
+        let token = self.auth.get_access_token_async(self).await?;
+        *access_token = Some(token.clone());
+        Ok(token)
+    }
+
+    /// Sends the request with `send`, retrying transient failures according to the request's
+    /// [`RetryPolicy`].
+    async fn with_retry<F, Fut>(
+        &self,
+        request: &http::Request<Vec<u8>>,
+        send: F,
+    ) -> Result<Response<Bytes>, api::ApiError<RestError>>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<Response<Bytes>, api::ApiError<RestError>>>,
+    {
+        let policy = request
+            .extensions()
+            .get::<RetryPolicy>()
+            .unwrap_or(&self.retry_policy);
+        let mut attempt = 0;
+        loop {
+            let result = send().await;
+            let Some(delay) = retry_delay(policy, request, &result, attempt) else {
+                return result;
+            };
+            attempt += 1;
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    async fn send_authenticated(
+        &self,
+        request: &http::Request<Vec<u8>>,
+    ) -> Result<Response<Bytes>, api::ApiError<RestError>> {
+        let token = self.access_token(None).await?;
+        let rsp = self.send_with_token(request, &token).await?;
+        if !is_token_rejected(&rsp) {
+            return Ok(rsp);
+        }
+
+        tracing::warn!("SeaTalk rejected the access token, refreshing and retrying");
+        let token = self.access_token(Some(&token)).await?;
+        self.send_with_token(request, &token).await
+    }
+
+    async fn send_with_token(
+        &self,
+        request: &http::Request<Vec<u8>>,
+        token: &AccessToken,
+    ) -> Result<Response<Bytes>, api::ApiError<RestError>> {
+        let mut request = clone_request(request);
+        token
+            .set_headers(request.headers_mut())
+            .map_err(|e| api::ApiError::client(e.into()))?;
+        self.send(request).await.map_err(api::ApiError::client)
+    }
+
+    async fn send(&self, request: http::Request<Vec<u8>>) -> Result<Response<Bytes>, RestError> {
+        self.lim
+            .until_ready(
+                request.uri().path(),
+                request.extensions().get::<RateLimitKey>(),
+            )
+            .await;
+        let max_size = request.extensions().get::<MaxResponseSize>().copied();
+        let request = request.try_into()?;
+        let mut rsp = self.client.execute(request).await?;
+
+        let mut http_rsp = Response::builder()
+            .status(rsp.status())
+            .version(rsp.version());
+        let headers = http_rsp.headers_mut().unwrap();
+        for (key, value) in rsp.headers() {
+            headers.insert(key, value.clone());
+        }
+        let body = match max_size {
+            Some(MaxResponseSize(max)) => {
+                check_content_length(rsp.content_length(), max)?;
+                let mut body = Vec::new();
+                while let Some(chunk) = rsp.chunk().await? {
+                    if body.len() + chunk.len() > max {
+                        return Err(RestError::ResponseTooLarge { max });
+                    }
+                    body.extend_from_slice(&chunk);
+                }
+                body.into()
+            }
+            None => rsp.bytes().await?,
+        };
+        Ok(http_rsp.body(body)?)
+    }
+}
+
+/// Fails responses announcing a body larger than `max` bytes, before their body is read.
+fn check_content_length(content_length: Option<u64>, max: usize) -> Result<(), RestError> {
+    match content_length {
+        Some(length) if length > max as u64 => Err(RestError::ResponseTooLarge { max }),
+        _ => Ok(()),
+    }
+}
+
+/// Delay before retrying `request` after it completed with `result`, or `None` if it should not
+/// be retried.
+fn retry_delay(
+    policy: &RetryPolicy,
+    request: &http::Request<Vec<u8>>,
+    result: &Result<Response<Bytes>, api::ApiError<RestError>>,
+    attempt: u32,
+) -> Option<Duration> {
+    let delay = match result {
+        Ok(rsp)
+            if retry::is_retryable_response(rsp)
+                && (retry::is_unprocessed_response(rsp)
+                    || policy.may_resend(request.method(), true)) =>
+        {
+            policy.delay(attempt, retry::retry_after(rsp))
+        }
+        Err(api::ApiError::Client { source })
+            if source.is_transient()
+                && policy.may_resend(request.method(), source.reached_server()) =>
+        {
+            policy.delay(attempt, None)
+        }
+        _ => None,
+    }?;
+    tracing::warn!(
+        "Retrying request to {} in {:?} (retry {} of {})",
+        request.uri().path(),
+        delay,
+        attempt + 1,
+        policy.max_retries
+    );
+    Some(delay)
+}
+
+fn clone_request(request: &http::Request<Vec<u8>>) -> http::Request<Vec<u8>> {
+    let mut clone = http::Request::new(request.body().clone());
+    *clone.method_mut() = request.method().clone();
+    *clone.uri_mut() = request.uri().clone();
+    *clone.version_mut() = request.version();
+    *clone.headers_mut() = request.headers().clone();
+    *clone.extensions_mut() = request.extensions().clone();
+    clone
+}
+
+/// Whether SeaTalk refused the access token used for the request.
+fn is_token_rejected(rsp: &Response<Bytes>) -> bool {
+    if rsp.status() == http::StatusCode::UNAUTHORIZED {
+        return true;
+    }
+    serde_json::from_slice::<serde_json::Value>(rsp.body())
+        .ok()
+        .and_then(|v| v.get("code")?.as_i64())
+        .is_some_and(|code| SeatalkErrorCode::from(code) == SeatalkErrorCode::AccessTokenInvalid)
+}
+
+impl client::Client for Seatalk {
+    fn rest(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, api::ApiError<<Self as RestClient>::Error>> {
+        let request = request
+            .body(body)
+            .map_err(|e| api::ApiError::client(e.into()))?;
+        self.with_retry(&request, || self.send_authenticated(&request))
+    }
+
+    fn rest_no_auth(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, api::ApiError<<Self as RestClient>::Error>> {
+        let request = request
+            .body(body)
+            .map_err(|e| api::ApiError::client(e.into()))?;
+        self.with_retry(&request, || {
+            self.send(clone_request(&request))
+                .map_err(api::ApiError::client)
+        })
+    }
+}
+
 #[async_trait]
 impl client::AsyncClient for AsyncSeatalk {
     async fn rest_async(
         &self,
-        mut request: http::request::Builder,
+        request: http::request::Builder,
         body: Vec<u8>,
     ) -> Result<Response<Bytes>, api::ApiError<<Self as RestClient>::Error>> {
-        use futures_util::TryFutureExt;
-        let should_reauth = self
-            .access_token
-            .lock()
+        let request = request
+            .body(body)
+            .map_err(|e| api::ApiError::client(e.into()))?;
+        self.with_retry(&request, || self.send_authenticated(&request))
             .await
-            .as_ref()
-            .map_or(true, |t| t.is_expired());
-
-        if should_reauth {
-            *self.access_token.lock().await = Some(self.auth.get_access_token_async(self).await?);
-        }
-        let call = || async {
-            self.access_token
-                .lock()
-                .await
-                .as_ref()
-                .ok_or(AuthError::InvalidToken)?
-                .set_headers(request.headers_mut().unwrap())?;
-            let http_request = request.body(body)?;
-            let request = http_request.try_into()?;
-            self.lim.until_ready().await;
-            let rsp = self.client.execute(request).await?;
-
-            let mut http_rsp = Response::builder()
-                .status(rsp.status())
-                .version(rsp.version());
-            let headers = http_rsp.headers_mut().unwrap();
-            for (key, value) in rsp.headers() {
-                headers.insert(key, value.clone());
-            }
-            Ok(http_rsp.body(rsp.bytes().await?)?)
-        };
-        call().map_err(api::ApiError::client).await
     }
 
     async fn rest_async_no_auth(
@@ -136,22 +552,14 @@ impl client::AsyncClient for AsyncSeatalk {
         request: http::request::Builder,
         body: Vec<u8>,
     ) -> Result<Response<Bytes>, api::ApiError<<Self as RestClient>::Error>> {
-        use futures_util::TryFutureExt;
-        let call = || async {
-            let http_request = request.body(body)?;
-            let request = http_request.try_into()?;
-            self.lim.until_ready().await;
-            let rsp = self.client.execute(request).await?;
-
-            let mut http_rsp = Response::builder()
-                .status(rsp.status())
-                .version(rsp.version());
-            let headers = http_rsp.headers_mut().unwrap();
-            for (key, value) in rsp.headers() {
-                headers.insert(key, value.clone());
-            }
-            Ok(http_rsp.body(rsp.bytes().await?)?)
-        };
-        call().map_err(api::ApiError::client).await
+        let request = request
+            .body(body)
+            .map_err(|e| api::ApiError::client(e.into()))?;
+        self.with_retry(&request, || async {
+            self.send(clone_request(&request))
+                .await
+                .map_err(api::ApiError::client)
+        })
+        .await
     }
 }
@@ -1,7 +1,203 @@
-use serde::Deserialize;
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
+use axum::{
+    async_trait,
+    body::Bytes,
+    extract::{rejection::BytesRejection, FromRef, FromRequest, Request},
+    response::{IntoResponse, Response},
+};
+use http::StatusCode;
+use serde::{de::DeserializeOwned, Deserialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+pub use super::api::content::{Mention, MessageContent};
+
+/// Header SeaTalk uses to send the signature of a webhook request.
+pub const SIGNATURE_HEADER: &str = "signature";
+
+/// Verifies that webhook requests were sent by SeaTalk.
+///
+/// SeaTalk signs every event callback with `sha256(body + signing_secret)`, hex encoded, and
+/// sends it in the `Signature` header. With a maximum clock skew set, events whose `timestamp`
+/// is further from now are rejected, so captured requests can't be replayed later.
+#[derive(Debug, Clone)]
+pub struct WebhookVerifier {
+    signing_secret: Arc<str>,
+    max_skew: Option<Duration>,
+    skew: Arc<Mutex<SkewStats>>,
+}
+
+/// Difference between the `timestamp` of the events and the time they were received.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SkewStats {
+    /// Number of events within the allowed skew.
+    pub accepted: u64,
+    /// Number of events rejected for their timestamp.
+    pub rejected: u64,
+    /// Sum of the skew of the accepted events.
+    pub total_skew: Duration,
+    /// Largest skew of an accepted event.
+    pub max_skew: Duration,
+}
+
+/// The only field of an event checked before it is deserialized.
+#[derive(Deserialize)]
+struct EventTimestamp {
+    timestamp: Option<u64>,
+}
+
+impl WebhookVerifier {
+    pub fn new(signing_secret: impl Into<String>) -> Self {
+        Self {
+            signing_secret: signing_secret.into().into(),
+            max_skew: None,
+            skew: Default::default(),
+        }
+    }
+
+    /// Rejects events whose `timestamp` is more than `max_skew` from now.
+    pub fn with_max_skew(mut self, max_skew: Duration) -> Self {
+        self.max_skew = Some(max_skew);
+        self
+    }
+
+    /// Skew of the events checked so far, only recorded with a maximum skew set.
+    pub fn skew_metrics(&self) -> SkewStats {
+        self.skew.lock().unwrap().clone()
+    }
+
+    /// Checks the `timestamp` of the event in `body` against the maximum skew.
+    pub fn check_timestamp(&self, body: &[u8]) -> Result<(), SignatureRejection> {
+        let Some(max) = self.max_skew else {
+            return Ok(());
+        };
+        let Some(timestamp) = serde_json::from_slice::<EventTimestamp>(body)?.timestamp else {
+            self.skew.lock().unwrap().rejected += 1;
+            return Err(SignatureRejection::MissingTimestamp);
+        };
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let skew = Duration::from_secs(now.abs_diff(timestamp));
+        let mut stats = self.skew.lock().unwrap();
+        if skew > max {
+            stats.rejected += 1;
+            return Err(SignatureRejection::Stale { skew, max });
+        }
+        stats.accepted += 1;
+        stats.total_skew += skew;
+        stats.max_skew = stats.max_skew.max(skew);
+        Ok(())
+    }
+
+    /// Computes the signature SeaTalk would send for `body`.
+    pub fn sign(&self, body: &[u8]) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(body);
+        hasher.update(self.signing_secret.as_bytes());
+        hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    pub fn verify(&self, body: &[u8], signature: &str) -> Result<(), SignatureRejection> {
+        let expected = self.sign(body);
+        let signature = signature.trim().to_ascii_lowercase();
+        // Compare without short-circuiting so the signature can't be guessed byte by byte.
+        let matches = expected.len() == signature.len()
+            && expected
+                .bytes()
+                .zip(signature.bytes())
+                .fold(0, |acc, (a, b)| acc | (a ^ b))
+                == 0;
+        if matches {
+            Ok(())
+        } else {
+            Err(SignatureRejection::InvalidSignature)
+        }
+    }
+}
+
+/// Extractor that checks the SeaTalk signature of the request before deserializing the JSON
+/// body into `T`.
+///
+/// Requires a [`WebhookVerifier`] to be available from the router state.
+#[derive(Debug)]
+pub struct Signed<T>(pub T);
+
+#[derive(Debug, Error)]
+pub enum SignatureRejection {
+    #[error("missing signature header")]
+    MissingSignature,
+    #[error("invalid signature")]
+    InvalidSignature,
+    #[error("missing event timestamp")]
+    MissingTimestamp,
+    #[error("event timestamp is {:?} from now, at most {:?} is allowed", skew, max)]
+    Stale { skew: Duration, max: Duration },
+    #[error("failed to read body: {}", source)]
+    Body {
+        #[from]
+        source: BytesRejection,
+    },
+    #[error("failed to parse body: {}", source)]
+    Json {
+        #[from]
+        source: serde_json::Error,
+    },
+}
+
+impl IntoResponse for SignatureRejection {
+    fn into_response(self) -> Response {
+        tracing::warn!("Rejected webhook request: {}", self);
+        match self {
+            Self::MissingSignature
+            | Self::InvalidSignature
+            | Self::MissingTimestamp
+            | Self::Stale { .. } => StatusCode::FORBIDDEN.into_response(),
+            Self::Body { source } => source.into_response(),
+            Self::Json { .. } => StatusCode::BAD_REQUEST.into_response(),
+        }
+    }
+}
+
+#[async_trait]
+impl<T, S> FromRequest<S> for Signed<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+    WebhookVerifier: FromRef<S>,
+{
+    type Rejection = SignatureRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let verifier = WebhookVerifier::from_ref(state);
+        let signature = req
+            .headers()
+            .get(SIGNATURE_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_owned)
+            .ok_or(SignatureRejection::MissingSignature)?;
+        let body = Bytes::from_request(req, state).await?;
+        verifier.verify(&body, &signature)?;
+        verifier.check_timestamp(&body)?;
+        Ok(Self(serde_json::from_slice(&body)?))
+    }
+}
+
+/// Event callback sent by SeaTalk.
+///
+/// Events this crate doesn't know yet are kept in [`ReceivedMessage::Unknown`] so they can be
+/// acknowledged instead of rejected.
 #[derive(Debug, Deserialize)]
-#[serde(tag = "event_type")]
+#[serde(remote = "Self", tag = "event_type")]
 pub enum ReceivedMessage {
     #[serde(alias = "event_verification")]
     EventVerification {
@@ -24,28 +220,112 @@ pub enum ReceivedMessage {
         app_id: String,
         event: MentionedFromGroupChatEvent,
     },
+    /// A message without a mention of the bot, sent in a thread the bot is part of.
+    #[serde(alias = "new_message_received_from_thread")]
+    NewMessageFromThread {
+        event_id: String,
+        timestamp: u64,
+        app_id: String,
+        event: MentionedFromGroupChatEvent,
+    },
+    #[serde(alias = "interactive_message_click")]
+    InteractiveMessageClick {
+        event_id: String,
+        timestamp: u64,
+        app_id: String,
+        event: InteractiveMessageClickEvent,
+    },
+    #[serde(alias = "bot_added_to_group_chat")]
+    BotAddedToGroupChat {
+        event_id: String,
+        timestamp: u64,
+        app_id: String,
+        event: BotAddedToGroupChatEvent,
+    },
+    #[serde(alias = "bot_removed_from_group_chat")]
+    BotRemovedFromGroupChat {
+        event_id: String,
+        timestamp: u64,
+        app_id: String,
+        event: BotRemovedFromGroupChatEvent,
+    },
+    /// A user opened the chat with the bot.
+    #[serde(alias = "user_enter_chatroom_with_bot")]
+    UserEnterChatroom {
+        event_id: String,
+        timestamp: u64,
+        app_id: String,
+        event: UserEnterChatroomEvent,
+    },
+    /// An event of a type not listed in [`EVENT_TYPES`], with its whole body.
+    #[serde(skip_deserializing)]
+    Unknown(serde_json::Value),
 }
 
-#[derive(Debug, Deserialize)]
-pub struct SeatalkChallengeEvent {
-    pub seatalk_challenge: String,
+/// `event_type`s with a variant in [`ReceivedMessage`].
+pub const EVENT_TYPES: &[&str] = &[
+    "event_verification",
+    "message_from_bot_subscriber",
+    "new_mentioned_message_received_from_group_chat",
+    "new_message_received_from_thread",
+    "interactive_message_click",
+    "bot_added_to_group_chat",
+    "bot_removed_from_group_chat",
+    "user_enter_chatroom_with_bot",
+];
+
+impl ReceivedMessage {
+    /// Id SeaTalk keeps when it delivers the event again.
+    pub fn event_id(&self) -> Option<&str> {
+        match self {
+            Self::EventVerification { event_id, .. }
+            | Self::MessageFromBotSubscriber { event_id, .. }
+            | Self::NewMentionedMessageFromGroupChat { event_id, .. }
+            | Self::NewMessageFromThread { event_id, .. }
+            | Self::InteractiveMessageClick { event_id, .. }
+            | Self::BotAddedToGroupChat { event_id, .. }
+            | Self::BotRemovedFromGroupChat { event_id, .. }
+            | Self::UserEnterChatroom { event_id, .. } => Some(event_id),
+            Self::Unknown(value) => value.get("event_id")?.as_str(),
+        }
+    }
+
+    /// `event_type` of an unknown event.
+    pub fn unknown_event_type(&self) -> Option<&str> {
+        match self {
+            Self::Unknown(value) => value.get("event_type")?.as_str(),
+            _ => None,
+        }
+    }
 }
 
-#[derive(Debug, Deserialize)]
-pub struct SubscriberMessageEvent {
-    pub employee_code: String,
-    pub message: SubscriberMessage,
+impl<'de> Deserialize<'de> for ReceivedMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let known = value
+            .get("event_type")
+            .and_then(serde_json::Value::as_str)
+            .is_some_and(|event_type| EVENT_TYPES.contains(&event_type));
+        if !known {
+            return Ok(Self::Unknown(value));
+        }
+        // Known events with a malformed body are still rejected.
+        ReceivedMessage::deserialize(value).map_err(serde::de::Error::custom)
+    }
 }
 
 #[derive(Debug, Deserialize)]
-pub struct SubscriberMessage {
-    pub tag: String,
-    pub text: SubscriberMessageContent,
+pub struct SeatalkChallengeEvent {
+    pub seatalk_challenge: String,
 }
 
 #[derive(Debug, Deserialize)]
-pub struct SubscriberMessageContent {
-    pub content: String,
+pub struct SubscriberMessageEvent {
+    pub employee_code: String,
+    pub message: MessageContent,
 }
 
 #[derive(Debug, Deserialize)]
@@ -57,12 +337,27 @@ pub struct MentionedFromGroupChatEvent {
 #[derive(Debug, Deserialize)]
 pub struct MentionedMessage {
     pub message_id: String,
+    #[serde(default)]
     pub quoted_message_id: String,
     pub thread_id: Option<String>,
     pub sender: Sender,
     pub message_sent_time: u64,
-    pub tag: String,
-    pub text: MentionedMessageContent,
+    #[serde(flatten)]
+    pub content: MessageContent,
+}
+
+/// A click on a callback button of an interactive message the bot sent.
+#[derive(Debug, Deserialize)]
+pub struct InteractiveMessageClickEvent {
+    /// Id of the interactive message that was clicked.
+    pub message_id: String,
+    pub employee_code: String,
+    pub seatalk_id: String,
+    /// Value of the clicked button.
+    pub value: String,
+    /// Group the message was sent to, `None` for messages sent to a subscriber.
+    pub group_id: Option<String>,
+    pub thread_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -73,12 +368,175 @@ pub struct Sender {
 }
 
 #[derive(Debug, Deserialize)]
-pub struct MentionedMessageContent {
-    pub plain_text: String,
-    pub mentioned_list: Vec<Mention>,
+pub struct BotAddedToGroupChatEvent {
+    pub group: EventGroup,
+    /// Who added the bot.
+    pub inviter: EventUser,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EventGroup {
+    pub group_id: String,
+    pub group_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BotRemovedFromGroupChatEvent {
+    pub group_id: String,
+    /// Who removed the bot.
+    pub remover: EventUser,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserEnterChatroomEvent {
+    pub employee_code: String,
+    pub seatalk_id: String,
+    pub email: Option<String>,
 }
+
+/// A user who caused an event.
 #[derive(Debug, Deserialize)]
-pub struct Mention {
-    pub username: String,
+pub struct EventUser {
     pub seatalk_id: String,
+    pub employee_code: String,
+    pub email: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, routing::post, Router};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const SECRET: &str = "secret";
+    const BODY: &str = r#"{"event_id":"1234","event_type":"event_verification","timestamp":1727676000,"app_id":"app","event":{"seatalk_challenge":"challenge"}}"#;
+    const SIGNATURE: &str = "260a959ee78529e2a3008a6b5b1eee3f4e95096da85630c85c07d72adc82a0c8";
+
+    fn router() -> Router {
+        async fn handler(Signed(payload): Signed<ReceivedMessage>) -> StatusCode {
+            match payload {
+                ReceivedMessage::EventVerification { .. } => StatusCode::OK,
+                _ => StatusCode::NOT_FOUND,
+            }
+        }
+        Router::new()
+            .route("/", post(handler))
+            .with_state(WebhookVerifier::new(SECRET))
+    }
+
+    fn request(body: &str, signature: Option<&str>) -> Request {
+        let mut req = http::Request::builder().method("POST").uri("/");
+        if let Some(signature) = signature {
+            req = req.header("Signature", signature);
+        }
+        req.body(Body::from(body.to_owned())).unwrap()
+    }
+
+    #[test]
+    fn sign_matches_fixture() {
+        assert_eq!(
+            WebhookVerifier::new(SECRET).sign(BODY.as_bytes()),
+            SIGNATURE
+        );
+    }
+
+    #[test]
+    fn verify_rejects_wrong_secret() {
+        let verifier = WebhookVerifier::new("other");
+        assert!(matches!(
+            verifier.verify(BODY.as_bytes(), SIGNATURE),
+            Err(SignatureRejection::InvalidSignature)
+        ));
+    }
+
+    #[tokio::test]
+    async fn accepts_signed_request() {
+        let rsp = router()
+            .oneshot(request(BODY, Some(SIGNATURE)))
+            .await
+            .unwrap();
+        assert_eq!(rsp.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn accepts_uppercase_signature() {
+        let rsp = router()
+            .oneshot(request(BODY, Some(&SIGNATURE.to_uppercase())))
+            .await
+            .unwrap();
+        assert_eq!(rsp.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn keeps_unknown_events() {
+        let body = r#"{"event_id":"1","event_type":"bot_renamed","timestamp":1,"app_id":"app","event":{}}"#;
+        let message: ReceivedMessage = serde_json::from_str(body).unwrap();
+        assert_eq!(message.unknown_event_type(), Some("bot_renamed"));
+
+        let body = r#"{"event_id":"1","event_type":"bot_removed_from_group_chat","timestamp":1,"app_id":"app","event":{"group_id":"g","remover":{"seatalk_id":"1","employee_code":"e1"}}}"#;
+        let message: ReceivedMessage = serde_json::from_str(body).unwrap();
+        assert!(matches!(
+            message,
+            ReceivedMessage::BotRemovedFromGroupChat { event, .. } if event.group_id == "g"
+        ));
+
+        let malformed = r#"{"event_id":"1","event_type":"bot_removed_from_group_chat","event":{}}"#;
+        assert!(serde_json::from_str::<ReceivedMessage>(malformed).is_err());
+    }
+
+    #[test]
+    fn rejects_stale_events() {
+        let verifier = WebhookVerifier::new(SECRET).with_max_skew(Duration::from_secs(300));
+        assert!(matches!(
+            verifier.check_timestamp(BODY.as_bytes()),
+            Err(SignatureRejection::Stale { .. })
+        ));
+        assert!(matches!(
+            verifier.check_timestamp(br#"{"event_type":"event_verification"}"#),
+            Err(SignatureRejection::MissingTimestamp)
+        ));
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let fresh = BODY.replace("1727676000", &(now - 10).to_string());
+        verifier.check_timestamp(fresh.as_bytes()).unwrap();
+
+        let stats = verifier.skew_metrics();
+        assert_eq!((stats.accepted, stats.rejected), (1, 2));
+        assert!(stats.max_skew >= Duration::from_secs(10));
+        assert!(WebhookVerifier::new(SECRET)
+            .check_timestamp(BODY.as_bytes())
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn rejects_replayed_request() {
+        let router = Router::new()
+            .route("/", post(|Signed(_): Signed<ReceivedMessage>| async {}))
+            .with_state(WebhookVerifier::new(SECRET).with_max_skew(Duration::from_secs(300)));
+        let rsp = router
+            .oneshot(request(BODY, Some(SIGNATURE)))
+            .await
+            .unwrap();
+        assert_eq!(rsp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn rejects_unsigned_request() {
+        let rsp = router().oneshot(request(BODY, None)).await.unwrap();
+        assert_eq!(rsp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn rejects_tampered_request() {
+        let tampered = BODY.replace("challenge", "tampered");
+        let rsp = router()
+            .oneshot(request(&tampered, Some(SIGNATURE)))
+            .await
+            .unwrap();
+        assert_eq!(rsp.status(), StatusCode::FORBIDDEN);
+    }
 }
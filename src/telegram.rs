@@ -7,7 +7,8 @@ use teloxide::{
     types::{Sticker, StickerSet},
     Bot,
 };
-use tokio::fs;
+use tokio::{fs, io::AsyncWriteExt};
+use url::Url;
 
 #[derive(Debug)]
 pub struct TelegramStickerDownloader {
@@ -15,12 +16,25 @@ pub struct TelegramStickerDownloader {
 }
 
 impl TelegramStickerDownloader {
-    pub async fn new(api_token: &str) -> Result<Self, teloxide::RequestError> {
+    /// Connects to the Bot API at `api_url`, or at api.telegram.org if `None`.
+    pub async fn new(
+        api_token: &str,
+        api_url: Option<Url>,
+    ) -> Result<Self, teloxide::RequestError> {
         let bot = Bot::new(api_token);
+        let bot = match api_url {
+            Some(api_url) => bot.set_api_url(api_url),
+            None => bot,
+        };
         let _ = bot.get_me().await?;
         Ok(Self { bot })
     }
 
+    /// Wraps `bot` without checking that its token is valid.
+    pub fn from_bot(bot: Bot) -> Self {
+        Self { bot }
+    }
+
     pub async fn get_sticker_set(&self, name: &str) -> Result<StickerSet, teloxide::RequestError> {
         self.bot.get_sticker_set(name).await
     }
@@ -33,6 +47,7 @@ impl TelegramStickerDownloader {
         let file = self.bot.get_file(&sticker.file.id).await?;
         let mut dest = fs::File::create(path).await?;
         self.bot.download_file(&file.path, &mut dest).await?;
+        dest.flush().await?;
         Ok(())
     }
 
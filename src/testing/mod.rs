@@ -0,0 +1,8 @@
+//! In-process fakes of the services the bot talks to, for tests that should not touch the
+//! network.
+
+mod seatalk;
+mod telegram;
+
+pub use self::seatalk::{FakeError, FakeSeatalk, SentMessage};
+pub use self::telegram::FakeTelegram;
@@ -0,0 +1,838 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use axum::{
+    extract::{Path, Query, State},
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::Utc;
+use http::{header, HeaderMap, StatusCode};
+use serde_json::{json, Value};
+use tokio::{net::TcpListener, task::JoinHandle};
+
+use crate::seatalk_api::{
+    auth::Auth,
+    query::REQUEST_ID_HEADER,
+    rate_limit::{RateLimit, RateLimits},
+    retry::RetryPolicy,
+    seatalk::SeatalkBuilder,
+};
+
+/// A message received by [`FakeSeatalk`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentMessage {
+    /// Endpoint the message was sent to, e.g. `messaging/v2/group_chat`.
+    pub endpoint: String,
+    /// `group_id` or `employee_code` the message was sent to.
+    pub target: String,
+    /// Message id the fake returned for the message.
+    pub message_id: String,
+    /// The `message` object of the request, replaced when the message is updated.
+    pub message: Value,
+    /// Number of times the message was updated.
+    pub updates: usize,
+    pub recalled: bool,
+}
+
+impl SentMessage {
+    pub fn tag(&self) -> Option<&str> {
+        self.message.get("tag")?.as_str()
+    }
+
+    /// Content of a text message.
+    pub fn text(&self) -> Option<&str> {
+        self.message.pointer("/text/content")?.as_str()
+    }
+
+    /// Text of the first description of an interactive message.
+    pub fn description(&self) -> Option<&str> {
+        self.message
+            .pointer("/interactive_message/elements")?
+            .as_array()?
+            .iter()
+            .find_map(|element| element.pointer("/description/text")?.as_str())
+    }
+
+    /// Name of the file sent in a file message.
+    pub fn filename(&self) -> Option<&str> {
+        self.message.pointer("/file/filename")?.as_str()
+    }
+}
+
+/// Failure returned by [`FakeSeatalk`] instead of handling a request.
+#[derive(Debug, Clone)]
+pub struct FakeError {
+    pub status: StatusCode,
+    pub code: i64,
+    pub retry_after: Option<u64>,
+    /// Handle the request before failing, like a SeaTalk failure after the message was sent.
+    pub processed: bool,
+}
+
+impl FakeError {
+    /// Responds with `200 OK` and the SeaTalk error `code`.
+    pub fn code(code: i64) -> Self {
+        Self {
+            status: StatusCode::OK,
+            code,
+            retry_after: None,
+            processed: false,
+        }
+    }
+
+    /// Responds with `status` and SeaTalk error code 2.
+    pub fn status(status: StatusCode) -> Self {
+        Self {
+            status,
+            code: 2,
+            retry_after: None,
+            processed: false,
+        }
+    }
+
+    pub fn with_retry_after(mut self, secs: u64) -> Self {
+        self.retry_after = Some(secs);
+        self
+    }
+
+    /// Fails after handling the request, only supported when sending messages.
+    pub fn after_processing(mut self) -> Self {
+        self.processed = true;
+        self
+    }
+}
+
+impl IntoResponse for FakeError {
+    fn into_response(self) -> Response {
+        let mut headers = HeaderMap::new();
+        if let Some(retry_after) = self.retry_after {
+            headers.insert(header::RETRY_AFTER, retry_after.into());
+        }
+        (
+            self.status,
+            headers,
+            Json(json!({"code": self.code, "message": "injected error"})),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FakeGroup {
+    group_id: String,
+    name: String,
+    /// Employee codes of the members.
+    members: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct FakeEmployee {
+    employee_code: String,
+    name: String,
+}
+
+impl FakeEmployee {
+    fn seatalk_id(&self) -> String {
+        seatalk_id(&self.employee_code)
+    }
+}
+
+/// SeaTalk id the fake gives the employee `employee_code`.
+fn seatalk_id(employee_code: &str) -> String {
+    format!("seatalk-{}", employee_code)
+}
+
+/// A message posted by an employee, see [`FakeSeatalk::post_message`].
+#[derive(Debug, Clone)]
+struct FakeChatMessage {
+    message_id: String,
+    group_id: String,
+    thread_id: Option<String>,
+    employee_code: String,
+    text: String,
+}
+
+impl FakeChatMessage {
+    fn to_json(&self) -> Value {
+        json!({
+            "message_id": self.message_id,
+            "quoted_message_id": "",
+            "thread_id": self.thread_id.clone().unwrap_or_default(),
+            "sender": {
+                "seatalk_id": seatalk_id(&self.employee_code),
+                "employee_code": self.employee_code,
+                "sender_type": 1,
+            },
+            "message_sent_time": 1727676000,
+            "tag": "text",
+            "text": {"plain_text": self.text},
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct FakeState {
+    history: Vec<FakeChatMessage>,
+    /// Files served by `messaging/v2/file/<index>`.
+    files: Vec<Vec<u8>>,
+    groups: Vec<FakeGroup>,
+    employees: Vec<FakeEmployee>,
+    lookups: HashMap<String, usize>,
+    tokens: HashSet<String>,
+    tokens_issued: usize,
+    next_message_id: usize,
+    next_request_id: usize,
+    messages: Vec<SentMessage>,
+    errors: HashMap<String, VecDeque<FakeError>>,
+    /// Bodies answered to lookups instead of the fake's data.
+    responses: HashMap<String, VecDeque<Value>>,
+    /// How long sent group messages wait before they are answered.
+    group_message_latency: Duration,
+}
+
+impl FakeState {
+    fn take_error(&mut self, endpoint: &str) -> Option<FakeError> {
+        self.errors.get_mut(endpoint)?.pop_front()
+    }
+
+    fn is_authorized(&self, headers: &HeaderMap) -> bool {
+        headers
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .is_some_and(|token| self.tokens.contains(token))
+    }
+}
+
+type SharedState = Arc<Mutex<FakeState>>;
+
+/// In-process stand-in for the SeaTalk OpenAPI.
+///
+/// Serves `auth/app_access_token`, `messaging/v2/group_chat`, `messaging/v2/single_chat`,
+/// `messaging/v2/update`, `messaging/v2/recall`, the group chat, message history and employee
+/// lookups and file downloads over plain http on a random local port, and records every message
+/// it receives. Only messages added with [`FakeSeatalk::post_message`] can be read back. Every
+/// response carries a request id `fake-request-N`. The server stops when the fake is dropped.
+#[derive(Debug)]
+pub struct FakeSeatalk {
+    addr: SocketAddr,
+    state: SharedState,
+    server: JoinHandle<()>,
+}
+
+impl FakeSeatalk {
+    pub const APP_ID: &'static str = "fake-app-id";
+    pub const APP_SECRET: &'static str = "fake-app-secret";
+
+    pub async fn start() -> std::io::Result<Self> {
+        let state = SharedState::default();
+        let router = Router::new()
+            .route("/auth/app_access_token", post(app_access_token))
+            .route("/messaging/v2/group_chat", post(group_chat))
+            .route("/messaging/v2/single_chat", post(single_chat))
+            .route("/messaging/v2/update", post(update))
+            .route("/messaging/v2/recall", post(recall))
+            .route("/messaging/v2/group_chat/info", get(group_info))
+            .route("/messaging/v2/group_chat/joined", get(joined_groups))
+            .route("/messaging/v2/group_chat/members", get(group_members))
+            .route(
+                "/messaging/v2/get_message_by_message_id",
+                get(message_by_id),
+            )
+            .route(
+                "/messaging/v2/group_chat/get_thread_by_thread_id",
+                get(thread_by_id),
+            )
+            .route("/messaging/v2/file/:file_id", get(file))
+            .route("/contacts/v2/profile", get(employee_profile))
+            .route(
+                "/contacts/v2/get_employee_code_with_seatalk_id",
+                post(employee_code),
+            )
+            .layer(middleware::map_response_with_state(
+                state.clone(),
+                add_request_id,
+            ))
+            .with_state(state.clone());
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let server = tokio::spawn(async move {
+            let _ = axum::serve(listener, router).await;
+        });
+        Ok(Self {
+            addr,
+            state,
+            server,
+        })
+    }
+
+    /// Host to pass to [`SeatalkBuilder::new`].
+    pub fn host(&self) -> String {
+        self.addr.to_string()
+    }
+
+    /// Credentials the fake accepts.
+    pub fn auth(&self) -> Auth {
+        Auth {
+            app_id: Self::APP_ID.into(),
+            app_secret: Self::APP_SECRET.into(),
+        }
+    }
+
+    /// A client builder pointed at the fake, without rate limits and with short retry delays.
+    pub fn builder(&self) -> SeatalkBuilder {
+        SeatalkBuilder::new(self.host(), self.auth())
+            .insecure()
+            .rate_limits(RateLimits::new(RateLimit::new(
+                1000,
+                Duration::from_secs(1),
+            )))
+            .retry_policy(
+                RetryPolicy::default().with_delay(Duration::from_millis(1), Duration::from_secs(2)),
+            )
+    }
+
+    /// Fails the next request to `endpoint`, e.g. `messaging/v2/group_chat`, with `error`.
+    ///
+    /// Errors injected for the same endpoint are returned in order, one per request.
+    pub fn inject_error(&self, endpoint: &str, error: FakeError) {
+        self.state
+            .lock()
+            .unwrap()
+            .errors
+            .entry(endpoint.trim_start_matches('/').to_owned())
+            .or_default()
+            .push_back(error);
+    }
+
+    /// Answers the next lookup of `endpoint` with `body` and code 0, instead of the fake's data.
+    ///
+    /// Responses injected for the same endpoint are returned in order, one per request.
+    pub fn inject_response(&self, endpoint: &str, body: Value) {
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .entry(endpoint.trim_start_matches('/').to_owned())
+            .or_default()
+            .push_back(body);
+    }
+
+    /// Answers group messages `latency` after recording them, like a slow SeaTalk.
+    pub fn set_group_message_latency(&self, latency: Duration) {
+        self.state.lock().unwrap().group_message_latency = latency;
+    }
+
+    /// Adds the bot to the group `group_id` named `name` with the employees `members`.
+    pub fn add_group(&self, group_id: &str, name: &str, members: &[&str]) {
+        self.state.lock().unwrap().groups.push(FakeGroup {
+            group_id: group_id.into(),
+            name: name.into(),
+            members: members.iter().map(|m| m.to_string()).collect(),
+        });
+    }
+
+    /// Adds the employee `employee_code` named `name`, its SeaTalk id is `seatalk-<employee_code>`.
+    pub fn add_employee(&self, employee_code: &str, name: &str) {
+        self.state.lock().unwrap().employees.push(FakeEmployee {
+            employee_code: employee_code.into(),
+            name: name.into(),
+        });
+    }
+
+    /// Posts `text` as `employee_code` in the group `group_id`, returns the message id.
+    ///
+    /// The message starts a thread unless `thread_id` is set.
+    pub fn post_message(
+        &self,
+        group_id: &str,
+        thread_id: Option<&str>,
+        employee_code: &str,
+        text: &str,
+    ) -> String {
+        let mut state = self.state.lock().unwrap();
+        state.next_message_id += 1;
+        let message_id = format!("fake-message-{}", state.next_message_id);
+        state.history.push(FakeChatMessage {
+            message_id: message_id.clone(),
+            group_id: group_id.into(),
+            thread_id: thread_id.map(Into::into),
+            employee_code: employee_code.into(),
+            text: text.into(),
+        });
+        message_id
+    }
+
+    /// Serves `content` as a file received in a message, returns the URL to download it from.
+    pub fn add_file(&self, content: &[u8]) -> String {
+        let mut state = self.state.lock().unwrap();
+        state.files.push(content.to_vec());
+        format!(
+            "http://{}/messaging/v2/file/{}",
+            self.addr,
+            state.files.len() - 1
+        )
+    }
+
+    /// Number of authorized requests to the lookup `endpoint`, e.g. `contacts/v2/profile`.
+    pub fn lookups(&self, endpoint: &str) -> usize {
+        let state = self.state.lock().unwrap();
+        state.lookups.get(endpoint).copied().unwrap_or(0)
+    }
+
+    /// Revokes every access token issued so far.
+    pub fn revoke_tokens(&self) {
+        self.state.lock().unwrap().tokens.clear();
+    }
+
+    pub fn tokens_issued(&self) -> usize {
+        self.state.lock().unwrap().tokens_issued
+    }
+
+    /// Messages received so far, in order.
+    pub fn messages(&self) -> Vec<SentMessage> {
+        self.state.lock().unwrap().messages.clone()
+    }
+
+    /// Waits until at least `count` messages were received and returns them.
+    ///
+    /// Panics if they don't arrive within `timeout`.
+    pub async fn wait_for_messages(&self, count: usize, timeout: Duration) -> Vec<SentMessage> {
+        let wait = async {
+            loop {
+                let messages = self.messages();
+                if messages.len() >= count {
+                    return messages;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        };
+        tokio::time::timeout(timeout, wait)
+            .await
+            .unwrap_or_else(|_| panic!("expected {} messages, got {:?}", count, self.messages()))
+    }
+}
+
+impl Drop for FakeSeatalk {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+async fn add_request_id(State(state): State<SharedState>, mut rsp: Response) -> Response {
+    let mut state = state.lock().unwrap();
+    state.next_request_id += 1;
+    let request_id = format!("fake-request-{}", state.next_request_id);
+    rsp.headers_mut()
+        .insert(REQUEST_ID_HEADER, request_id.parse().unwrap());
+    rsp
+}
+
+async fn app_access_token(State(state): State<SharedState>, Json(body): Json<Value>) -> Response {
+    let mut state = state.lock().unwrap();
+    if let Some(error) = state.take_error("auth/app_access_token") {
+        return error.into_response();
+    }
+    if body.get("app_id").and_then(Value::as_str) != Some(FakeSeatalk::APP_ID) {
+        return FakeError::code(1001).into_response();
+    }
+    if body.get("app_secret").and_then(Value::as_str) != Some(FakeSeatalk::APP_SECRET) {
+        return FakeError::code(1000).into_response();
+    }
+    state.tokens_issued += 1;
+    let token = format!("fake-token-{}", state.tokens_issued);
+    state.tokens.insert(token.clone());
+    Json(json!({
+        "code": 0,
+        "app_access_token": token,
+        "expire": Utc::now().timestamp() + 7200,
+    }))
+    .into_response()
+}
+
+async fn group_chat(
+    State(state): State<SharedState>,
+    headers: HeaderMap,
+    Json(body): Json<Value>,
+) -> Response {
+    let rsp = record_message(
+        &state,
+        "messaging/v2/group_chat",
+        "group_id",
+        &headers,
+        body,
+    );
+    let latency = state.lock().unwrap().group_message_latency;
+    tokio::time::sleep(latency).await;
+    rsp
+}
+
+async fn single_chat(
+    State(state): State<SharedState>,
+    headers: HeaderMap,
+    Json(body): Json<Value>,
+) -> Response {
+    record_message(
+        &state,
+        "messaging/v2/single_chat",
+        "employee_code",
+        &headers,
+        body,
+    )
+}
+
+fn record_message(
+    state: &SharedState,
+    endpoint: &str,
+    target_field: &str,
+    headers: &HeaderMap,
+    body: Value,
+) -> Response {
+    let mut state = state.lock().unwrap();
+    if !state.is_authorized(headers) {
+        return FakeError::code(100).into_response();
+    }
+    let error = state.take_error(endpoint);
+    if let Some(error) = error.clone().filter(|error| !error.processed) {
+        return error.into_response();
+    }
+    let (Some(target), Some(message)) = (
+        body.get(target_field).and_then(Value::as_str),
+        body.get("message"),
+    ) else {
+        return FakeError::code(102).into_response();
+    };
+    state.next_message_id += 1;
+    let message_id = format!("fake-message-{}", state.next_message_id);
+    state.messages.push(SentMessage {
+        endpoint: endpoint.into(),
+        target: target.into(),
+        message_id: message_id.clone(),
+        message: message.clone(),
+        updates: 0,
+        recalled: false,
+    });
+    if let Some(error) = error {
+        return error.into_response();
+    }
+    Json(json!({"code": 0, "message_id": message_id})).into_response()
+}
+
+/// Runs `f` on the message the request body refers to by `message_id`.
+fn modify_message(
+    state: &SharedState,
+    endpoint: &str,
+    headers: &HeaderMap,
+    body: &Value,
+    f: impl FnOnce(&mut SentMessage) -> Result<(), FakeError>,
+) -> Response {
+    let mut state = state.lock().unwrap();
+    if !state.is_authorized(headers) {
+        return FakeError::code(100).into_response();
+    }
+    if let Some(error) = state.take_error(endpoint) {
+        return error.into_response();
+    }
+    let Some(message_id) = body.get("message_id").and_then(Value::as_str) else {
+        return FakeError::code(102).into_response();
+    };
+    let Some(message) = state
+        .messages
+        .iter_mut()
+        .find(|m| m.message_id == message_id && !m.recalled)
+    else {
+        return FakeError::code(5).into_response();
+    };
+    match f(message) {
+        Ok(()) => Json(json!({"code": 0})).into_response(),
+        Err(error) => error.into_response(),
+    }
+}
+
+async fn update(
+    State(state): State<SharedState>,
+    headers: HeaderMap,
+    Json(body): Json<Value>,
+) -> Response {
+    let Some(interactive_message) = body.pointer("/message/interactive_message").cloned() else {
+        return FakeError::code(102).into_response();
+    };
+    modify_message(&state, "messaging/v2/update", &headers, &body, |message| {
+        if message.tag() != Some("interactive_message") {
+            return Err(FakeError::code(102));
+        }
+        message.message["interactive_message"] = interactive_message;
+        message.updates += 1;
+        Ok(())
+    })
+}
+
+async fn recall(
+    State(state): State<SharedState>,
+    headers: HeaderMap,
+    Json(body): Json<Value>,
+) -> Response {
+    modify_message(&state, "messaging/v2/recall", &headers, &body, |message| {
+        message.recalled = true;
+        Ok(())
+    })
+}
+
+/// Checks the token and injected errors of a lookup, then answers with `f`.
+fn lookup(
+    state: &SharedState,
+    endpoint: &str,
+    headers: &HeaderMap,
+    f: impl FnOnce(&FakeState) -> Result<Value, FakeError>,
+) -> Response {
+    let mut state = state.lock().unwrap();
+    if !state.is_authorized(headers) {
+        return FakeError::code(100).into_response();
+    }
+    *state.lookups.entry(endpoint.to_owned()).or_default() += 1;
+    if let Some(error) = state.take_error(endpoint) {
+        return error.into_response();
+    }
+    let injected = state
+        .responses
+        .get_mut(endpoint)
+        .and_then(VecDeque::pop_front);
+    match injected.map(Ok).unwrap_or_else(|| f(&state)) {
+        Ok(mut body) => {
+            body["code"] = 0.into();
+            Json(body).into_response()
+        }
+        Err(error) => error.into_response(),
+    }
+}
+
+/// Returns the page of `items` selected by the `cursor` and `page_size` parameters.
+fn page<T: Clone>(items: &[T], params: &HashMap<String, String>) -> (Vec<T>, String) {
+    let start: usize = params
+        .get("cursor")
+        .and_then(|cursor| cursor.parse().ok())
+        .unwrap_or(0);
+    let size: usize = params
+        .get("page_size")
+        .and_then(|size| size.parse().ok())
+        .unwrap_or(50);
+    let end = (start + size).min(items.len());
+    let next_cursor = if end < items.len() {
+        end.to_string()
+    } else {
+        String::new()
+    };
+    (items[start.min(end)..end].to_vec(), next_cursor)
+}
+
+fn find_group<'a>(
+    state: &'a FakeState,
+    params: &HashMap<String, String>,
+) -> Result<&'a FakeGroup, FakeError> {
+    let group_id = params.get("group_id").ok_or(FakeError::code(102))?;
+    state
+        .groups
+        .iter()
+        .find(|group| &group.group_id == group_id)
+        .ok_or(FakeError::code(7000))
+}
+
+async fn group_info(
+    State(state): State<SharedState>,
+    headers: HeaderMap,
+    Query(params): Query<HashMap<String, String>>,
+) -> Response {
+    lookup(&state, "messaging/v2/group_chat/info", &headers, |state| {
+        let group = find_group(state, &params)?;
+        Ok(json!({
+            "group": {
+                "group_name": group.name,
+                "group_user_total": group.members.len(),
+                "group_bot_total": 1,
+            }
+        }))
+    })
+}
+
+async fn joined_groups(
+    State(state): State<SharedState>,
+    headers: HeaderMap,
+    Query(params): Query<HashMap<String, String>>,
+) -> Response {
+    lookup(
+        &state,
+        "messaging/v2/group_chat/joined",
+        &headers,
+        |state| {
+            let ids: Vec<_> = state.groups.iter().map(|g| g.group_id.clone()).collect();
+            let (ids, next_cursor) = page(&ids, &params);
+            Ok(json!({
+                "joined_group_chats": {"group_id": ids},
+                "next_cursor": next_cursor,
+            }))
+        },
+    )
+}
+
+async fn group_members(
+    State(state): State<SharedState>,
+    headers: HeaderMap,
+    Query(params): Query<HashMap<String, String>>,
+) -> Response {
+    lookup(
+        &state,
+        "messaging/v2/group_chat/members",
+        &headers,
+        |state| {
+            let group = find_group(state, &params)?;
+            let (members, next_cursor) = page(&group.members, &params);
+            let members: Vec<_> = members
+                .iter()
+                .map(|code| {
+                    json!({
+                        "seatalk_id": seatalk_id(code),
+                        "employee_code": code,
+                        "email": format!("{}@example.com", code),
+                    })
+                })
+                .collect();
+            Ok(json!({"group_user_list": members, "next_cursor": next_cursor}))
+        },
+    )
+}
+
+async fn file(
+    State(state): State<SharedState>,
+    headers: HeaderMap,
+    Path(file_id): Path<usize>,
+) -> Response {
+    let state = state.lock().unwrap();
+    if !state.is_authorized(&headers) {
+        return FakeError::code(100).into_response();
+    }
+    match state.files.get(file_id) {
+        Some(content) => (
+            [(header::CONTENT_TYPE, "application/octet-stream")],
+            content.clone(),
+        )
+            .into_response(),
+        None => FakeError::code(5).into_response(),
+    }
+}
+
+async fn employee_profile(
+    State(state): State<SharedState>,
+    headers: HeaderMap,
+    Query(params): Query<Vec<(String, String)>>,
+) -> Response {
+    lookup(&state, "contacts/v2/profile", &headers, |state| {
+        let employees: Vec<_> = params
+            .iter()
+            .filter(|(key, _)| key == "employee_code")
+            .filter_map(|(_, code)| state.employees.iter().find(|e| &e.employee_code == code))
+            .map(|employee| {
+                json!({
+                    "employee_code": employee.employee_code,
+                    "seatalk_id": employee.seatalk_id(),
+                    "name": employee.name,
+                    "email": format!("{}@example.com", employee.employee_code),
+                })
+            })
+            .collect();
+        if employees.is_empty() {
+            return Err(FakeError::code(3000));
+        }
+        Ok(json!({"employees": employees}))
+    })
+}
+
+async fn employee_code(
+    State(state): State<SharedState>,
+    headers: HeaderMap,
+    Json(body): Json<Value>,
+) -> Response {
+    lookup(
+        &state,
+        "contacts/v2/get_employee_code_with_seatalk_id",
+        &headers,
+        |state| {
+            let seatalk_ids = body
+                .get("seatalk_ids")
+                .and_then(Value::as_array)
+                .ok_or(FakeError::code(102))?;
+            let employees: Vec<_> = seatalk_ids
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|id| {
+                    let employee = state.employees.iter().find(|e| e.seatalk_id() == id);
+                    json!({
+                        "seatalk_id": id,
+                        "employee_code": employee.map(|e| e.employee_code.clone()),
+                    })
+                })
+                .collect();
+            Ok(json!({"employees": employees}))
+        },
+    )
+}
+
+async fn message_by_id(
+    State(state): State<SharedState>,
+    headers: HeaderMap,
+    Query(params): Query<HashMap<String, String>>,
+) -> Response {
+    lookup(
+        &state,
+        "messaging/v2/get_message_by_message_id",
+        &headers,
+        |state| {
+            let message_id = params.get("message_id").ok_or(FakeError::code(102))?;
+            let message = state
+                .history
+                .iter()
+                .find(|m| &m.message_id == message_id)
+                .ok_or(FakeError::code(5))?;
+            let mut body = message.to_json();
+            body.as_object_mut().unwrap().remove("message_id");
+            Ok(body)
+        },
+    )
+}
+
+async fn thread_by_id(
+    State(state): State<SharedState>,
+    headers: HeaderMap,
+    Query(params): Query<HashMap<String, String>>,
+) -> Response {
+    lookup(
+        &state,
+        "messaging/v2/group_chat/get_thread_by_thread_id",
+        &headers,
+        |state| {
+            let (Some(group_id), Some(thread_id)) =
+                (params.get("group_id"), params.get("thread_id"))
+            else {
+                return Err(FakeError::code(102));
+            };
+            let messages: Vec<_> = state
+                .history
+                .iter()
+                .filter(|m| &m.group_id == group_id)
+                .filter(|m| &m.message_id == thread_id || m.thread_id.as_ref() == Some(thread_id))
+                .map(FakeChatMessage::to_json)
+                .collect();
+            if messages.is_empty() {
+                return Err(FakeError::code(5));
+            }
+            let (messages, next_cursor) = page(&messages, &params);
+            Ok(json!({"thread_messages": messages, "next_cursor": next_cursor}))
+        },
+    )
+}
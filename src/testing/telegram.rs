@@ -0,0 +1,263 @@
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use axum::{
+    extract::{Path as UrlPath, State},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use http::StatusCode;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use teloxide::Bot;
+use tokio::{net::TcpListener, task::JoinHandle};
+use url::Url;
+
+/// Optional `set.json` in a fixture directory.
+#[derive(Debug, Default, Deserialize)]
+struct SetMetadata {
+    title: Option<String>,
+    sticker_type: Option<String>,
+}
+
+#[derive(Debug)]
+struct FixtureSticker {
+    file_id: String,
+    /// Path the file is downloaded from.
+    file_path: String,
+    path: PathBuf,
+    sticker: Value,
+}
+
+#[derive(Debug)]
+struct FixtureSet {
+    set: Value,
+}
+
+#[derive(Debug, Default)]
+struct Fixtures {
+    sets: HashMap<String, FixtureSet>,
+    stickers: HashMap<String, FixtureSticker>,
+}
+
+impl Fixtures {
+    /// Loads every directory in `root` as a sticker set named after the directory.
+    ///
+    /// Files ending in `.webp`, `.tgs` and `.webm` become static, animated and video stickers.
+    fn load(root: &Path) -> std::io::Result<Self> {
+        let mut fixtures = Self::default();
+        for entry in std::fs::read_dir(root)? {
+            let dir = entry?.path();
+            if dir.is_dir() {
+                fixtures.load_set(&dir)?;
+            }
+        }
+        Ok(fixtures)
+    }
+
+    fn load_set(&mut self, dir: &Path) -> std::io::Result<()> {
+        let name = dir.file_name().unwrap().to_string_lossy().to_string();
+        let metadata: SetMetadata = match std::fs::read(dir.join("set.json")) {
+            Ok(data) => serde_json::from_slice(&data)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => SetMetadata::default(),
+            Err(e) => return Err(e),
+        };
+        let sticker_type = metadata.sticker_type.unwrap_or("regular".into());
+
+        let mut paths: Vec<_> = std::fs::read_dir(dir)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<Result<_, _>>()?;
+        paths.sort();
+
+        let mut stickers = Vec::new();
+        for path in paths {
+            let (extension, is_animated, is_video) = match path.extension().and_then(|e| e.to_str())
+            {
+                Some(extension @ "webp") => (extension, false, false),
+                Some(extension @ "tgs") => (extension, true, false),
+                Some(extension @ "webm") => (extension, false, true),
+                _ => continue,
+            };
+            let stem = path.file_stem().unwrap().to_string_lossy();
+            let file_id = format!("{}-{}", name, stem);
+            let mut sticker = json!({
+                "file_id": file_id,
+                "file_unique_id": file_id,
+                "file_size": std::fs::metadata(&path)?.len(),
+                "width": 512,
+                "height": 512,
+                "type": sticker_type,
+                "is_animated": is_animated,
+                "is_video": is_video,
+                "emoji": "🙂",
+                "set_name": name,
+            });
+            if sticker_type == "custom_emoji" {
+                sticker["custom_emoji_id"] = file_id.clone().into();
+            }
+            stickers.push(sticker.clone());
+            self.stickers.insert(
+                file_id.clone(),
+                FixtureSticker {
+                    file_path: format!("stickers/{}.{}", file_id, extension),
+                    file_id,
+                    path,
+                    sticker,
+                },
+            );
+        }
+
+        let set = json!({
+            "name": name,
+            "title": metadata.title.unwrap_or(name.clone()),
+            "sticker_type": sticker_type,
+            "stickers": stickers,
+        });
+        self.sets.insert(name, FixtureSet { set });
+        Ok(())
+    }
+}
+
+/// In-process stand-in for the Telegram Bot API.
+///
+/// Serves `getMe`, `getStickerSet`, `getFile`, `getCustomEmojiStickers` and file downloads for
+/// the sticker sets in a fixture directory, see [`FakeTelegram::start`]. The server stops when
+/// the fake is dropped.
+#[derive(Debug)]
+pub struct FakeTelegram {
+    addr: SocketAddr,
+    server: JoinHandle<()>,
+}
+
+impl FakeTelegram {
+    pub const TOKEN: &'static str = "123456:fake-token";
+
+    /// Starts the fake with the sticker sets in `fixtures`.
+    ///
+    /// Every directory in `fixtures` is a sticker set named after the directory, with an
+    /// optional `set.json` setting its `title` and `sticker_type`.
+    pub async fn start(fixtures: impl AsRef<Path>) -> std::io::Result<Self> {
+        let fixtures = Arc::new(Fixtures::load(fixtures.as_ref())?);
+        let router = Router::new()
+            .route("/:token/:method", post(method))
+            .route("/file/:token/*path", get(download))
+            .with_state(fixtures);
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let server = tokio::spawn(async move {
+            let _ = axum::serve(listener, router).await;
+        });
+        Ok(Self { addr, server })
+    }
+
+    /// Base URL to pass to [`Bot::set_api_url`].
+    pub fn api_url(&self) -> Url {
+        Url::parse(&format!("http://{}/", self.addr)).unwrap()
+    }
+
+    /// A bot pointed at the fake.
+    pub fn bot(&self) -> Bot {
+        Bot::new(Self::TOKEN).set_api_url(self.api_url())
+    }
+}
+
+impl Drop for FakeTelegram {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+fn ok(result: Value) -> Response {
+    Json(json!({"ok": true, "result": result})).into_response()
+}
+
+fn error(status: StatusCode, description: &str) -> Response {
+    (
+        status,
+        Json(json!({
+            "ok": false,
+            "error_code": status.as_u16(),
+            "description": description,
+        })),
+    )
+        .into_response()
+}
+
+async fn method(
+    State(fixtures): State<Arc<Fixtures>>,
+    UrlPath((token, method)): UrlPath<(String, String)>,
+    body: Option<Json<Value>>,
+) -> Response {
+    if token.strip_prefix("bot") != Some(FakeTelegram::TOKEN) {
+        return error(StatusCode::UNAUTHORIZED, "Unauthorized");
+    }
+    let Json(body) = body.unwrap_or(Json(Value::Null));
+    // Bot API method names are case-insensitive.
+    match method.to_ascii_lowercase().as_str() {
+        "getme" => ok(json!({
+            "id": 123456,
+            "is_bot": true,
+            "first_name": "Fake",
+            "username": "fake_bot",
+            "can_join_groups": true,
+            "can_read_all_group_messages": false,
+            "supports_inline_queries": false,
+        })),
+        "getstickerset" => {
+            let name = body.get("name").and_then(Value::as_str).unwrap_or_default();
+            match fixtures.sets.get(name) {
+                Some(set) => ok(set.set.clone()),
+                None => error(StatusCode::BAD_REQUEST, "Bad Request: STICKERSET_INVALID"),
+            }
+        }
+        "getcustomemojistickers" => {
+            let stickers: Vec<_> = body
+                .get("custom_emoji_ids")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(|id| fixtures.stickers.get(id.as_str()?))
+                .filter(|s| s.sticker.get("custom_emoji_id").is_some())
+                .map(|s| s.sticker.clone())
+                .collect();
+            ok(stickers.into())
+        }
+        "getfile" => {
+            let file_id = body
+                .get("file_id")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            match fixtures.stickers.get(file_id) {
+                Some(sticker) => ok(json!({
+                    "file_id": sticker.file_id,
+                    "file_unique_id": sticker.file_id,
+                    "file_size": sticker.sticker["file_size"],
+                    "file_path": sticker.file_path,
+                })),
+                None => error(StatusCode::BAD_REQUEST, "Bad Request: invalid file_id"),
+            }
+        }
+        _ => error(StatusCode::NOT_FOUND, "Not Found: method not found"),
+    }
+}
+
+async fn download(
+    State(fixtures): State<Arc<Fixtures>>,
+    UrlPath((token, path)): UrlPath<(String, String)>,
+) -> Response {
+    if token.strip_prefix("bot") != Some(FakeTelegram::TOKEN) {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+    let Some(sticker) = fixtures.stickers.values().find(|s| s.file_path == path) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    match tokio::fs::read(&sticker.path).await {
+        Ok(data) => data.into_response(),
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
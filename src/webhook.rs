@@ -1,4 +1,13 @@
-use std::sync::Arc;
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
 
 use axum::{extract::State, response::IntoResponse, Json};
 use base64::{engine::general_purpose, Engine};
@@ -6,30 +15,110 @@ use http::StatusCode;
 use regex::Regex;
 use serde::Deserialize;
 use serde_json::json;
+use teloxide::types::{Sticker, StickerSet};
 use temp_dir::TempDir;
 use thiserror::Error;
 
 use crate::{
-    consts::{GROUP_INV, WHITELIST_GROUP_IDS},
-    convert::{convert_tgs, convert_webm, convert_webp},
+    admin::{list_groups, set_whitelisted},
+    commands::{bot_commands, sticker_set_name, tokenize, BotCommand, Chat, RouteError},
+    consts::GROUP_INV,
+    convert::{convert_tgs, convert_webm, convert_webp, zip_dir, ConvertError},
+    dedupe::{Dedupe, Delivery, HandlingEvent},
+    employees::EmployeeDirectory,
     seatalk_api::{
-        api::{common::MessageType, ApiError, SendGroupMessage, SendSubscriberMessage},
-        ignore,
+        api::{
+            common::{MessageType, MAX_FILE_SIZE},
+            content::FileContent,
+            ApiError, Button, ChatMessage, DownloadFile, GetMessage, InteractiveMessage, Markdown,
+            RecallMessage, SeatalkErrorCode, SendGroupMessage, SendSubscriberMessage, Text,
+            UpdateInteractiveMessage,
+        },
+        download, ignore,
         query::AsyncQuery,
         seatalk::{AsyncSeatalk, RestError, SeatalkError},
         webhooks::{
-            MentionedFromGroupChatEvent, MentionedMessage, MentionedMessageContent,
-            ReceivedMessage, SeatalkChallengeEvent, SubscriberMessageEvent,
+            BotAddedToGroupChatEvent, BotRemovedFromGroupChatEvent, InteractiveMessageClickEvent,
+            MentionedFromGroupChatEvent, MentionedMessage, MessageContent, ReceivedMessage,
+            SeatalkChallengeEvent, Signed, SubscriberMessageEvent, UserEnterChatroomEvent,
         },
     },
     telegram::TelegramStickerDownloader,
+    whitelist::Whitelist,
 };
 
+/// Least time between two updates of a progress card.
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(2);
+
 pub async fn message_received(
     State(seatalk): State<Arc<AsyncSeatalk>>,
+    State(conversions): State<Conversions>,
     State(telegram): State<Arc<TelegramStickerDownloader>>,
-    Json(payload): Json<ReceivedMessage>,
+    State(whitelist): State<Whitelist>,
+    State(employees): State<EmployeeDirectory>,
+    State(dedupe): State<Dedupe>,
+    Signed(payload): Signed<ReceivedMessage>,
 ) -> Result<impl IntoResponse, WebhookError> {
+    // Verification challenges are answered every time they are sent.
+    let event_id = match &payload {
+        ReceivedMessage::EventVerification { .. } => None,
+        payload => payload.event_id().map(str::to_owned),
+    };
+    let mut handling = None;
+    if let Some(event_id) = &event_id {
+        match dedupe.start(event_id).await {
+            Delivery::New(event) => handling = Some(event),
+            Delivery::InProgress => {
+                // SeaTalk delivers the event again later, by then it is handled or failed.
+                tracing::info!("Event {} is still being handled", event_id);
+                return Ok(StatusCode::SERVICE_UNAVAILABLE.into_response());
+            }
+            Delivery::Handled => {
+                tracing::info!("Ignoring duplicate event {}", event_id);
+                return Ok(StatusCode::OK.into_response());
+            }
+        }
+    }
+    let result = handle_event(
+        seatalk,
+        conversions,
+        telegram,
+        whitelist,
+        employees,
+        payload,
+        &mut handling,
+    )
+    .await;
+    // Unless a spawned task took over handling the event, it is done.
+    match handling {
+        Some(handling) if result.is_ok() => handling.finish().await,
+        _ => {}
+    }
+    result
+}
+
+/// Runs `task` in the background, the event is recorded as handled once it succeeds.
+fn spawn_handling(
+    handling: Option<HandlingEvent>,
+    task: impl Future<Output = Result<(), WebhookError>> + Send + 'static,
+) {
+    tokio::spawn(async move {
+        if let (Ok(()), Some(handling)) = (task.await, handling) {
+            handling.finish().await;
+        }
+    });
+}
+
+/// Handles `payload`, tasks spawned to finish handling it take `handling`.
+async fn handle_event(
+    seatalk: Arc<AsyncSeatalk>,
+    conversions: Conversions,
+    telegram: Arc<TelegramStickerDownloader>,
+    whitelist: Whitelist,
+    employees: EmployeeDirectory,
+    payload: ReceivedMessage,
+    handling: &mut Option<HandlingEvent>,
+) -> Result<axum::response::Response, WebhookError> {
     match payload {
         ReceivedMessage::EventVerification {
             event: SeatalkChallengeEvent { seatalk_challenge },
@@ -42,24 +131,69 @@ pub async fn message_received(
                 .into_response())
         }
         ReceivedMessage::MessageFromBotSubscriber {
-            event: SubscriberMessageEvent { employee_code, .. },
+            event:
+                SubscriberMessageEvent {
+                    employee_code,
+                    message,
+                },
+            ..
+        } => match message {
+            MessageContent::Image { image: file } | MessageContent::File { file } => {
+                spawn_handling(
+                    handling.take(),
+                    convert_received_file(seatalk, employee_code, file),
+                );
+            }
+            message => {
+                let words = match &message {
+                    MessageContent::Text { text } => tokenize(text),
+                    _ => Vec::new(),
+                };
+                handle_direct_message(
+                    seatalk.as_ref(),
+                    &whitelist,
+                    &employees,
+                    &employee_code,
+                    &words,
+                )
+                .await?;
+            }
+        },
+        ReceivedMessage::UserEnterChatroom {
+            event: UserEnterChatroomEvent { employee_code, .. },
+            ..
+        } => invite_subscriber(seatalk.as_ref(), &employees, &employee_code).await?,
+        ReceivedMessage::BotAddedToGroupChat {
+            event: BotAddedToGroupChatEvent { group, inviter },
             ..
         } => {
-            let seatalk = seatalk.as_ref();
-            ignore(SendSubscriberMessage::new(
-                &employee_code,
-                MessageType::Text,
-                "Join my group to convert Telegram stickers!",
-            ))
-            .query_async(seatalk)
-            .await?;
-            ignore(SendSubscriberMessage::new(
-                &employee_code,
-                MessageType::Image,
-                GROUP_INV,
-            ))
-            .query_async(seatalk)
-            .await?;
+            // Looking up the inviter would delay acknowledging the event.
+            let whitelisted = whitelist.contains(&group.group_id);
+            tokio::spawn(async move {
+                tracing::info!(
+                    "{} added me to group {} ({}), whitelisted: {}",
+                    employees.describe(&seatalk, &inviter.employee_code).await,
+                    group.group_name,
+                    group.group_id,
+                    whitelisted
+                );
+            });
+        }
+        ReceivedMessage::BotRemovedFromGroupChat {
+            event: BotRemovedFromGroupChatEvent { group_id, remover },
+            ..
+        } => {
+            tokio::spawn(async move {
+                tracing::info!(
+                    "{} removed me from group {}",
+                    employees.describe(&seatalk, &remover.employee_code).await,
+                    group_id
+                );
+            });
+        }
+        ReceivedMessage::NewMessageFromThread { .. } => {}
+        ReceivedMessage::Unknown(event) => {
+            tracing::warn!("Ignoring unknown event: {}", event);
         }
         ReceivedMessage::NewMentionedMessageFromGroupChat {
             event:
@@ -68,35 +202,40 @@ pub async fn message_received(
                     message:
                         MentionedMessage {
                             message_id,
+                            quoted_message_id,
                             thread_id,
-                            text: MentionedMessageContent { plain_text, .. },
+                            sender,
+                            content,
                             ..
                         },
                 },
             ..
         } => {
-            if WHITELIST_GROUP_IDS.contains(&group_id.as_str()) {
+            if whitelist.contains(&group_id) {
                 let thread_id = thread_id.unwrap_or("".into());
                 if thread_id.is_empty() {
-                    let thread_id = Some(message_id);
-                    tokio::spawn(async move {
-                        let _ = download_and_send_stickers_group(
-                            telegram,
-                            seatalk.clone(),
+                    spawn_handling(handling.take(), async move {
+                        let mention = Mention {
+                            requester: employees.describe_cached(&sender.employee_code),
+                            employee_code: sender.employee_code,
                             group_id,
-                            thread_id,
-                            plain_text,
-                        )
-                        .await;
+                            message_id,
+                            quoted_message_id: Some(quoted_message_id)
+                                .filter(|message_id| !message_id.is_empty()),
+                            words: match &content {
+                                MessageContent::Text { text } => tokenize(text),
+                                _ => Vec::new(),
+                            },
+                        };
+                        handle_mention(telegram, seatalk.clone(), conversions, mention).await
                     });
                 }
             } else {
                 let seatalk = seatalk.as_ref();
-                ignore(SendGroupMessage::new(
+                ignore(SendGroupMessage::new_text_message(
                     &group_id,
                     None,
-                    "Join my group to convert Telegram stickers!",
-                    MessageType::Text,
+                    Text::plain("Join my group to convert Telegram stickers!"),
                     None,
                 ))
                 .query_async(seatalk)
@@ -114,10 +253,250 @@ pub async fn message_received(
                 .map_err(WebhookError::Rest)?;
             }
         }
+        ReceivedMessage::InteractiveMessageClick {
+            event:
+                InteractiveMessageClickEvent {
+                    message_id,
+                    employee_code,
+                    value,
+                    group_id,
+                    ..
+                },
+            ..
+        } => {
+            let Some(group_id) = group_id.filter(|group_id| whitelist.contains(group_id)) else {
+                tracing::warn!(
+                    "Ignoring click on {} outside whitelisted groups",
+                    message_id
+                );
+                return Ok(StatusCode::OK.into_response());
+            };
+            match CardAction::parse(&value) {
+                Some(CardAction::Convert { .. }) if !conversions.claim_offer(&message_id) => {
+                    ignore(SendGroupMessage::new_text_message(
+                        &group_id,
+                        None,
+                        Text::plain("Already converting this sticker set"),
+                        Some(message_id),
+                    ))
+                    .query_async(seatalk.as_ref())
+                    .await
+                    .map_err(WebhookError::Rest)?;
+                }
+                Some(CardAction::Convert { set_name, zip }) => {
+                    spawn_handling(handling.take(), async move {
+                        // Conversions don't wait for SeaTalk's rate limited contacts API.
+                        let requester = employees.describe_cached(&employee_code);
+                        let request = ConversionRequest {
+                            requester,
+                            employee_code,
+                            group_id,
+                            quoted_message_id: Some(message_id.clone()),
+                            sticker_set_name: set_name,
+                            zip,
+                        };
+                        let result = download_and_send_stickers_group(
+                            telegram,
+                            seatalk.clone(),
+                            conversions.clone(),
+                            request,
+                        )
+                        .await;
+                        conversions.release_offer(&message_id);
+                        result
+                    });
+                }
+                Some(CardAction::Cancel) => {
+                    let is_admin = whitelist.is_admin(&employee_code);
+                    let reply = match conversions.cancel(&message_id, &employee_code, is_admin) {
+                        // A running conversion updates its own progress card.
+                        Cancellation::Cancelled => return Ok(StatusCode::OK.into_response()),
+                        Cancellation::NotAllowed => {
+                            "Only the member who started the conversion or an admin can cancel it"
+                        }
+                        Cancellation::NotRunning => "Cancelled",
+                    };
+                    ignore(SendGroupMessage::new_text_message(
+                        &group_id,
+                        None,
+                        Text::plain(reply),
+                        Some(message_id),
+                    ))
+                    .query_async(seatalk.as_ref())
+                    .await
+                    .map_err(WebhookError::Rest)?;
+                }
+                None => tracing::warn!("Unknown button value: {}", value),
+            }
+        }
     };
     Ok(StatusCode::OK.into_response())
 }
 
+/// Greets a subscriber and invites them to the group stickers are converted in.
+async fn invite_subscriber(
+    seatalk: &AsyncSeatalk,
+    employees: &EmployeeDirectory,
+    employee_code: &str,
+) -> Result<(), WebhookError> {
+    let greeting = match employees.employee(seatalk, employee_code).await {
+        Ok(Some(employee)) => format!(
+            "Hi {}! Join my group to convert Telegram stickers!",
+            employee.display_name()
+        ),
+        Ok(None) => "Join my group to convert Telegram stickers!".to_owned(),
+        Err(e) => {
+            tracing::warn!("Failed to look up employee {}: {}", employee_code, e);
+            "Join my group to convert Telegram stickers!".to_owned()
+        }
+    };
+    ignore(SendSubscriberMessage::new_text_message(
+        employee_code,
+        Text::plain(greeting),
+    ))
+    .query_async(seatalk)
+    .await?;
+    ignore(SendSubscriberMessage::new(
+        employee_code,
+        MessageType::Image,
+        GROUP_INV,
+    ))
+    .query_async(seatalk)
+    .await?;
+    Ok(())
+}
+
+/// Kind of sticker file, decides how it is converted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum StickerFormat {
+    /// WebP or another still image, converted to PNG.
+    Image,
+    /// Lottie animation (`.tgs`), converted to GIF.
+    Animated,
+    /// WebM video, converted to GIF.
+    Video,
+}
+
+impl StickerFormat {
+    const ALL: [Self; 3] = [Self::Image, Self::Animated, Self::Video];
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::Image => "Still stickers and images",
+            Self::Animated => "Animated stickers",
+            Self::Video => "Video stickers",
+        }
+    }
+
+    /// Extensions of the files received in messages that are converted as this format.
+    fn extensions(self) -> &'static [&'static str] {
+        match self {
+            Self::Image => &["webp", "png", "jpg", "jpeg"],
+            Self::Animated => &["tgs"],
+            Self::Video => &["webm"],
+        }
+    }
+
+    fn of_sticker(sticker: &Sticker) -> Self {
+        if sticker.flags.is_video {
+            Self::Video
+        } else if sticker.flags.is_animated {
+            Self::Animated
+        } else {
+            Self::Image
+        }
+    }
+
+    /// Format of a file received in a message, images have no file name.
+    fn of_file(filename: Option<&str>) -> Option<Self> {
+        let Some(filename) = filename else {
+            return Some(Self::Image);
+        };
+        let extension = Path::new(filename)
+            .extension()?
+            .to_string_lossy()
+            .to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|format| format.extensions().contains(&extension.as_str()))
+    }
+
+    fn converted_extension(self) -> &'static str {
+        match self {
+            Self::Image => "png",
+            Self::Animated | Self::Video => "gif",
+        }
+    }
+
+    fn convert(self, file_path: &Path, out_path: &Path) -> Result<(), ConvertError> {
+        match self {
+            Self::Image => convert_webp(file_path, out_path),
+            Self::Animated => convert_tgs(file_path, out_path),
+            Self::Video => convert_webm(file_path, out_path),
+        }
+    }
+}
+
+/// Converts a sticker file or image sent to the bot directly and sends it back.
+async fn convert_received_file(
+    seatalk: Arc<AsyncSeatalk>,
+    employee_code: String,
+    file: FileContent,
+) -> Result<(), WebhookError> {
+    let seatalk = seatalk.as_ref();
+    let reply = |text: &'static str| {
+        ignore(SendSubscriberMessage::new_text_message(
+            &employee_code,
+            Text::plain(text),
+        ))
+    };
+    let Some(format) = StickerFormat::of_file(file.filename.as_deref()) else {
+        reply("I can only convert images and .webp, .tgs or .webm sticker files")
+            .query_async(seatalk)
+            .await?;
+        return Ok(());
+    };
+    let content = match download(DownloadFile::new(&file.content).max_size(MAX_FILE_SIZE))
+        .query_async(seatalk)
+        .await
+    {
+        Ok(content) => content,
+        Err(ApiError::Client {
+            source: RestError::ResponseTooLarge { .. },
+        }) => {
+            reply("Sorry, that file is too large, send files of up to 5 MB")
+                .query_async(seatalk)
+                .await?;
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let temp_dir = TempDir::new()?;
+    let file_path = temp_dir.path().join("sticker");
+    let converted_file_path = temp_dir
+        .path()
+        .join(format!("converted.{}", format.converted_extension()));
+    tokio::fs::write(&file_path, &content).await?;
+    if let Err(e) = format.convert(&file_path, &converted_file_path) {
+        tracing::error!("Failed to convert file from {}: {}", employee_code, e);
+        reply("Sorry, I couldn't convert that file")
+            .query_async(seatalk)
+            .await?;
+        return Ok(());
+    }
+    let converted = tokio::fs::read(&converted_file_path).await?;
+    ignore(SendSubscriberMessage::new(
+        &employee_code,
+        MessageType::Image,
+        general_purpose::STANDARD.encode(converted),
+    ))
+    .query_async(seatalk)
+    .await?;
+    let _ = temp_dir.cleanup();
+    Ok(())
+}
+
 #[derive(Debug, Error)]
 pub enum WebhookError {
     #[error(transparent)]
@@ -129,6 +508,9 @@ pub enum WebhookError {
     #[error(transparent)]
     Seatalk(#[from] SeatalkError),
 
+    #[error(transparent)]
+    Convert(#[from] ConvertError),
+
     #[error(transparent)]
     Rest(#[from] ApiError<RestError>),
 
@@ -143,36 +525,313 @@ impl IntoResponse for WebhookError {
     }
 }
 
-fn parse_sticker_set_name(message: &str) -> Result<String, WebhookError> {
-    let re = Regex::new(r"(?:@.* *)(?:/convert *)https://t\.me/(?:addstickers|addemoji)/(.*)/?$")
-        .unwrap();
-    let caps = re
-        .captures(message)
-        .ok_or(WebhookError::BadRequest("Cannot capture URL".into()))?;
-    let set_name = caps
-        .get(1)
-        .ok_or(WebhookError::BadRequest("Cannot capture URL".into()))?
-        .as_str();
-    tracing::info!("Parsed sticker pack: {}", set_name);
-    Ok(set_name.into())
+/// Name of the first sticker set linked anywhere in `text`.
+fn find_sticker_set_name(text: &str) -> Option<String> {
+    let re = Regex::new(r"https://t\.me/(?:addstickers|addemoji)/([^/\s]+)").unwrap();
+    Some(re.captures(text)?.get(1)?.as_str().to_owned())
 }
 
-async fn download_and_send_stickers_group(
+/// Action behind a button of the card offering how to send a sticker set.
+#[derive(Debug, PartialEq)]
+enum CardAction {
+    Convert { set_name: String, zip: bool },
+    Cancel,
+}
+
+impl CardAction {
+    fn parse(value: &str) -> Option<Self> {
+        if value == "cancel" {
+            return Some(Self::Cancel);
+        }
+        let (format, set_name) = value.strip_prefix("convert:")?.split_once(':')?;
+        let zip = match format {
+            "images" => false,
+            "zip" => true,
+            _ => return None,
+        };
+        Some(Self::Convert {
+            set_name: set_name.into(),
+            zip,
+        })
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::Convert {
+                set_name,
+                zip: false,
+            } => format!("convert:images:{}", set_name),
+            Self::Convert {
+                set_name,
+                zip: true,
+            } => format!("convert:zip:{}", set_name),
+            Self::Cancel => "cancel".into(),
+        }
+    }
+}
+
+/// A mention of the bot outside a thread of a whitelisted group.
+struct Mention {
+    /// Who mentioned the bot, for audit logs.
+    requester: String,
+    employee_code: String,
+    group_id: String,
+    /// Id of the mention, replies quote it.
+    message_id: String,
+    /// Message the mention quotes, if any.
+    quoted_message_id: Option<String>,
+    /// Words of the mention, without the users it mentions.
+    words: Vec<String>,
+}
+
+async fn handle_mention(
+    telegram: impl AsRef<TelegramStickerDownloader>,
+    seatalk: Arc<AsyncSeatalk>,
+    conversions: Conversions,
+    mention: Mention,
+) -> Result<(), WebhookError> {
+    let Mention {
+        requester,
+        employee_code,
+        group_id,
+        message_id,
+        quoted_message_id,
+        words,
+    } = mention;
+    let (set_name, zip) = match bot_commands().route(Chat::Group, false, &words) {
+        Ok(BotCommand::Convert {
+            set_name: Some(set_name),
+            zip,
+        }) => (set_name, zip),
+        Ok(BotCommand::Convert {
+            set_name: None,
+            zip,
+        }) => {
+            let set_name = match &quoted_message_id {
+                Some(quoted_message_id) => {
+                    quoted_sticker_set(seatalk.as_ref(), quoted_message_id).await
+                }
+                None => None,
+            };
+            let Some(set_name) = set_name else {
+                return reply_to_mention(
+                    seatalk.as_ref(),
+                    &group_id,
+                    &message_id,
+                    route_error_reply(&invalid_sticker_set_url()),
+                )
+                .await;
+            };
+            (set_name, zip)
+        }
+        Ok(BotCommand::Help) => {
+            let reply = help_reply(false);
+            return reply_to_mention(seatalk.as_ref(), &group_id, &message_id, reply).await;
+        }
+        Ok(BotCommand::Formats) => {
+            let reply = formats_reply();
+            return reply_to_mention(seatalk.as_ref(), &group_id, &message_id, reply).await;
+        }
+        Ok(command) => {
+            tracing::warn!("Ignoring {:?} in group {}", command, group_id);
+            return Ok(());
+        }
+        Err(RouteError::NotACommand) => {
+            match words.iter().find_map(|word| sticker_set_name(word)) {
+                Some(set_name) => {
+                    return offer_sticker_set(
+                        telegram,
+                        seatalk,
+                        group_id,
+                        Some(message_id),
+                        set_name,
+                    )
+                    .await
+                }
+                None => {
+                    return reply_to_mention(
+                        seatalk.as_ref(),
+                        &group_id,
+                        &message_id,
+                        route_error_reply(&invalid_sticker_set_url()),
+                    )
+                    .await
+                }
+            }
+        }
+        Err(error) => {
+            return reply_to_mention(
+                seatalk.as_ref(),
+                &group_id,
+                &message_id,
+                route_error_reply(&error),
+            )
+            .await
+        }
+    };
+    let request = ConversionRequest {
+        requester,
+        employee_code,
+        group_id,
+        quoted_message_id: Some(message_id),
+        sticker_set_name: set_name,
+        zip,
+    };
+    download_and_send_stickers_group(telegram, seatalk, conversions, request).await
+}
+
+/// Error replied to mentions without a sticker set to convert.
+fn invalid_sticker_set_url() -> RouteError {
+    let commands = bot_commands();
+    let convert = commands.get("/convert").expect("/convert is registered");
+    RouteError::InvalidArguments {
+        usage: convert.usage(),
+        help: convert.help,
+        reason: "Invalid Telegram sticker set URL".into(),
+    }
+}
+
+/// Replies to the mention `message_id`.
+async fn reply_to_mention(
+    seatalk: &AsyncSeatalk,
+    group_id: &str,
+    message_id: &str,
+    reply: Markdown,
+) -> Result<(), WebhookError> {
+    ignore(SendGroupMessage::new_text_message(
+        group_id,
+        None,
+        reply,
+        Some(message_id.to_owned()),
+    ))
+    .query_async(seatalk)
+    .await
+    .map_err(WebhookError::Rest)
+}
+
+fn route_error_reply(error: &RouteError) -> Markdown {
+    match error {
+        RouteError::InvalidArguments {
+            usage,
+            help,
+            reason,
+        } => Markdown::new()
+            .text(reason)
+            .newline()
+            .text("Usage: ")
+            .code(usage)
+            .newline()
+            .text(help),
+        RouteError::UnknownCommand(name) => Markdown::new()
+            .text("Unknown command ")
+            .code(name)
+            .text(", send ")
+            .code("/help")
+            .text(" to see every command"),
+        RouteError::NotACommand => Markdown::new().text(&error.to_string()),
+    }
+}
+
+/// Lists the commands the sender can use, with their arguments.
+fn help_reply(is_admin: bool) -> Markdown {
+    bot_commands()
+        .all(is_admin)
+        .fold(Markdown::new().text("Commands:"), |reply, command| {
+            let reply = reply.newline().text("- ").code(&command.usage());
+            let reply = match command.chats {
+                [Chat::Group] => reply.text(" (mention me in a group)"),
+                [Chat::Direct] => reply.text(" (message me directly)"),
+                _ => reply,
+            };
+            reply.text(": ").text(command.help)
+        })
+}
+
+/// Lists the sticker formats and what they are converted to.
+fn formats_reply() -> Markdown {
+    StickerFormat::ALL.into_iter().fold(
+        Markdown::new().text("Formats I convert:"),
+        |reply, format| {
+            let extensions: Vec<_> = format
+                .extensions()
+                .iter()
+                .map(|extension| format!(".{}", extension))
+                .collect();
+            reply
+                .newline()
+                .text("- ")
+                .bold(format.description())
+                .text(&format!(
+                    " ({}) to .{}",
+                    extensions.join(", "),
+                    format.converted_extension()
+                ))
+        },
+    )
+}
+
+/// Runs the command sent by a subscriber, or invites them to the bot's group if the message is
+/// not a command.
+async fn handle_direct_message(
+    seatalk: &AsyncSeatalk,
+    whitelist: &Whitelist,
+    employees: &EmployeeDirectory,
+    employee_code: &str,
+    words: &[String],
+) -> Result<(), WebhookError> {
+    let is_admin = whitelist.is_admin(employee_code);
+    let reply = match bot_commands().route(Chat::Direct, is_admin, words) {
+        Ok(BotCommand::Groups) => list_groups(seatalk, whitelist).await?,
+        Ok(BotCommand::Whitelist { group }) => {
+            set_whitelisted(seatalk, whitelist, employees, employee_code, &group, true).await?
+        }
+        Ok(BotCommand::Unwhitelist { group }) => {
+            set_whitelisted(seatalk, whitelist, employees, employee_code, &group, false).await?
+        }
+        Ok(BotCommand::Help) => help_reply(is_admin),
+        Ok(BotCommand::Formats) => formats_reply(),
+        Err(error @ (RouteError::InvalidArguments { .. } | RouteError::UnknownCommand(_))) => {
+            route_error_reply(&error)
+        }
+        Ok(BotCommand::Convert { .. }) | Err(RouteError::NotACommand) => {
+            return invite_subscriber(seatalk, employees, employee_code).await;
+        }
+    };
+    ignore(SendSubscriberMessage::new_text_message(
+        employee_code,
+        reply,
+    ))
+    .query_async(seatalk)
+    .await?;
+    Ok(())
+}
+
+/// Name of the sticker set linked in the message `message_id`, if any.
+async fn quoted_sticker_set(seatalk: &AsyncSeatalk, message_id: &str) -> Option<String> {
+    let message: ChatMessage = match GetMessage::new(message_id).query_async(seatalk).await {
+        Ok(message) => message,
+        Err(e) => {
+            tracing::warn!("Failed to fetch quoted message {}: {}", message_id, e);
+            return None;
+        }
+    };
+    find_sticker_set_name(message.text()?)
+}
+
+/// Sends a card asking whether `set_name` should be sent as images or as a zip file.
+async fn offer_sticker_set(
     telegram: impl AsRef<TelegramStickerDownloader>,
     seatalk: Arc<AsyncSeatalk>,
     group_id: String,
     quoted_message_id: Option<String>,
-    message: String,
+    set_name: String,
 ) -> Result<(), WebhookError> {
-    let telegram = telegram.as_ref();
     let seatalk = seatalk.as_ref();
-
-    let Ok(sticker_set_name) = parse_sticker_set_name(&message) else {
-        ignore(SendGroupMessage::new(
+    let Ok(sticker_set) = telegram.as_ref().get_sticker_set(&set_name).await else {
+        ignore(SendGroupMessage::new_text_message(
             &group_id,
             None,
-            "Invalid Telegram sticker set URL\nExample usage: `@StickersBot /convert  https://t.me/addstickers/Trashhagain`",
-            MessageType::Text,
+            Text::plain("Invalid Telegram sticker set URL"),
             quoted_message_id,
         ))
         .query_async(seatalk)
@@ -181,12 +840,175 @@ async fn download_and_send_stickers_group(
         return Ok(());
     };
 
+    let action = |zip| CardAction::Convert {
+        set_name: sticker_set.name.clone(),
+        zip,
+    };
+    let card = InteractiveMessage::new()
+        .title(&sticker_set.title)
+        .description(
+            Markdown::new()
+                .text(&format!(
+                    "Found {} stickers in sticker set: ",
+                    sticker_set.stickers.len()
+                ))
+                .bold(&sticker_set.name)
+                .newline()
+                .text("How should I send them?"),
+        )
+        .button_group([
+            Button::callback("Send as images", action(false).value()),
+            Button::callback("Send as zip", action(true).value()),
+            Button::callback("Cancel", CardAction::Cancel.value()),
+        ]);
+    ignore(SendGroupMessage::new_interactive_message(
+        &group_id,
+        None,
+        card,
+        quoted_message_id,
+    ))
+    .query_async(seatalk)
+    .await
+    .map_err(WebhookError::Rest)?;
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+struct SentGroupMessage {
+    message_id: String,
+}
+
+/// The running conversions.
+#[derive(Debug, Clone, Default)]
+pub struct Conversions(Arc<Mutex<ConversionState>>);
+
+#[derive(Debug, Default)]
+struct ConversionState {
+    /// Cancellation flags and who asked for the conversion, keyed by the id of the progress
+    /// card.
+    cancelled: HashMap<String, (Arc<AtomicBool>, String)>,
+    /// Ids of the cards offering a sticker set whose conversion was clicked.
+    offers: HashSet<String>,
+}
+
+impl Conversions {
+    /// Registers the conversion with the progress card `card_id` asked for by `employee_code`.
+    fn start(&self, card_id: &str, employee_code: &str) -> Arc<AtomicBool> {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        self.0.lock().unwrap().cancelled.insert(
+            card_id.to_owned(),
+            (cancelled.clone(), employee_code.to_owned()),
+        );
+        cancelled
+    }
+
+    fn finish(&self, card_id: &str) {
+        self.0.lock().unwrap().cancelled.remove(card_id);
+    }
+
+    /// Marks the sticker set offered by the card `card_id` as being converted, returns `false`
+    /// if it already is.
+    fn claim_offer(&self, card_id: &str) -> bool {
+        self.0.lock().unwrap().offers.insert(card_id.to_owned())
+    }
+
+    fn release_offer(&self, card_id: &str) {
+        self.0.lock().unwrap().offers.remove(card_id);
+    }
+
+    /// Asks the conversion with the progress card `card_id` to stop, on behalf of
+    /// `employee_code`.
+    ///
+    /// Only who asked for the conversion and admins can cancel it.
+    fn cancel(&self, card_id: &str, employee_code: &str, is_admin: bool) -> Cancellation {
+        match self.0.lock().unwrap().cancelled.get(card_id) {
+            Some((cancelled, requester)) if is_admin || requester == employee_code => {
+                cancelled.store(true, Ordering::Relaxed);
+                Cancellation::Cancelled
+            }
+            Some(_) => Cancellation::NotAllowed,
+            None => Cancellation::NotRunning,
+        }
+    }
+}
+
+/// Outcome of a click on a cancel button.
+#[derive(Debug, PartialEq)]
+enum Cancellation {
+    Cancelled,
+    /// The conversion was asked for by someone else.
+    NotAllowed,
+    /// The card has no running conversion.
+    NotRunning,
+}
+
+/// Card showing the progress of a conversion, with a cancel button while it is `running`.
+///
+/// `status` is written by the bot and added to the card as markdown.
+fn progress_card(sticker_set: &StickerSet, status: &str, running: bool) -> InteractiveMessage {
+    let card = InteractiveMessage::new()
+        .title(&sticker_set.title)
+        .description(
+            Markdown::new()
+                .text(&format!(
+                    "Found {} stickers in sticker set: ",
+                    sticker_set.stickers.len()
+                ))
+                .bold(&sticker_set.name)
+                .newline()
+                .raw(status),
+        );
+    if running {
+        card.button(Button::callback("Cancel", CardAction::Cancel.value()))
+    } else {
+        card
+    }
+}
+
+/// Shows `card` in place of the progress card `card_id`, failures only hide progress.
+async fn update_card(seatalk: &AsyncSeatalk, card_id: &str, card: InteractiveMessage) {
+    if let Err(e) = ignore(UpdateInteractiveMessage::new(card_id, card))
+        .query_async(seatalk)
+        .await
+    {
+        tracing::warn!("Failed to update progress card {}: {}", card_id, e);
+    }
+}
+
+/// A sticker set someone asked to convert in a group.
+struct ConversionRequest {
+    /// Who asked for the conversion, for audit logs.
+    requester: String,
+    /// Employee code of the requester, who can cancel the conversion.
+    employee_code: String,
+    group_id: String,
+    quoted_message_id: Option<String>,
+    sticker_set_name: String,
+    zip: bool,
+}
+
+async fn download_and_send_stickers_group(
+    telegram: impl AsRef<TelegramStickerDownloader>,
+    seatalk: Arc<AsyncSeatalk>,
+    conversions: Conversions,
+    request: ConversionRequest,
+) -> Result<(), WebhookError> {
+    let ConversionRequest {
+        requester,
+        employee_code,
+        group_id,
+        quoted_message_id,
+        sticker_set_name,
+        zip,
+    } = request;
+    let telegram = telegram.as_ref();
+    let seatalk = seatalk.as_ref();
+
     let Ok(sticker_set) = telegram.get_sticker_set(&sticker_set_name).await else {
-        ignore(SendGroupMessage::new(
+        ignore(SendGroupMessage::new_text_message(
             &group_id,
             None,
-            "Invalid Telegram sticker set URL",
-            MessageType::Text,
+            Text::plain("Invalid Telegram sticker set URL"),
             quoted_message_id,
         ))
         .query_async(seatalk)
@@ -195,40 +1017,104 @@ async fn download_and_send_stickers_group(
         return Ok(());
     };
 
-    #[derive(Debug, Deserialize)]
-    struct SentGroupMessage {
-        message_id: String,
-    }
-
-    let code: SentGroupMessage = SendGroupMessage::new(
+    let card: SentGroupMessage = SendGroupMessage::new_interactive_message(
         &group_id,
         None,
-        format!(
-            "Found {} stickers in sticker set: **{}**",
-            sticker_set.stickers.len(),
-            sticker_set.name,
-        ),
-        MessageType::Text,
+        progress_card(&sticker_set, "Starting...", true),
         quoted_message_id,
     )
     .query_async(seatalk)
     .await
     .map_err(WebhookError::Rest)?;
 
-    let thread_id = Some(code.message_id);
+    tracing::info!(
+        "{} converts sticker set {} in group {} (zip: {})",
+        requester,
+        sticker_set.name,
+        group_id,
+        zip
+    );
+    let cancelled = conversions.start(&card.message_id, &employee_code);
+    let result = send_stickers(
+        telegram,
+        seatalk,
+        &group_id,
+        &card.message_id,
+        &sticker_set,
+        zip,
+        &cancelled,
+    )
+    .await;
+    conversions.finish(&card.message_id);
+    if let Err(e) = &result {
+        tracing::error!("Failed to convert {}: {:?}", sticker_set.name, e);
+        update_card(
+            seatalk,
+            &card.message_id,
+            progress_card(&sticker_set, "Stopped, something went wrong", false),
+        )
+        .await;
+    }
+    result
+}
+
+/// Converts and sends the stickers of `sticker_set` in the thread of the progress card `card_id`.
+///
+/// Stickers already sent are recalled if the conversion is cancelled.
+async fn send_stickers(
+    telegram: &TelegramStickerDownloader,
+    seatalk: &AsyncSeatalk,
+    group_id: &str,
+    card_id: &str,
+    sticker_set: &StickerSet,
+    zip: bool,
+    cancelled: &AtomicBool,
+) -> Result<(), WebhookError> {
+    let thread_id = Some(card_id.to_owned());
+    let total = sticker_set.stickers.len();
 
     let temp_dir = TempDir::new().map_err(WebhookError::FS)?;
     let converted_dir = temp_dir.path().join("converted");
     tokio::fs::create_dir_all(&converted_dir).await?;
     let mut failed = 0;
+    let mut posted = Vec::new();
+    let mut last_update = Instant::now();
 
     for (i, sticker) in sticker_set.stickers.iter().enumerate() {
-        tracing::info!(
-            "Processing {}: {}/{}",
-            sticker_set.name,
-            i + 1,
-            sticker_set.stickers.len()
-        );
+        if cancelled.load(Ordering::Relaxed) {
+            tracing::info!("Cancelled converting {}", sticker_set.name);
+            for message_id in &posted {
+                if let Err(e) = ignore(RecallMessage::new(message_id))
+                    .query_async(seatalk)
+                    .await
+                {
+                    tracing::warn!("Failed to recall sticker {}: {}", message_id, e);
+                }
+            }
+            update_card(
+                seatalk,
+                card_id,
+                progress_card(sticker_set, "Cancelled", false),
+            )
+            .await;
+            let _ = temp_dir.cleanup();
+            return Ok(());
+        }
+        if last_update.elapsed() >= PROGRESS_INTERVAL {
+            update_card(
+                seatalk,
+                card_id,
+                progress_card(
+                    sticker_set,
+                    &format!("Converting {} of {}...", i + 1, total),
+                    true,
+                ),
+            )
+            .await;
+            last_update = Instant::now();
+        }
+
+        tracing::info!("Processing {}: {}/{}", sticker_set.name, i + 1, total);
         let file_name = sticker.file.id.to_owned();
         let file_path = temp_dir.path().join(&file_name);
 
@@ -242,69 +1128,212 @@ async fn download_and_send_stickers_group(
             continue;
         }
 
+        let format = StickerFormat::of_sticker(sticker);
         let mut converted_file_path = converted_dir.join(&file_name);
-        let cnv = if sticker.flags.is_video {
-            converted_file_path.set_extension("gif");
-            convert_webm(&file_path, &converted_file_path)
-        } else if sticker.flags.is_animated {
-            converted_file_path.set_extension("gif");
-            convert_tgs(&file_path, &converted_file_path)
-        } else {
-            converted_file_path.set_extension("png");
-            convert_webp(&file_path, &converted_file_path)
-        };
+        converted_file_path.set_extension(format.converted_extension());
 
-        if let Err(e) = cnv {
+        if let Err(e) = format.convert(&file_path, &converted_file_path) {
             tracing::error!("Failed to convert: {}", e);
             failed += 1;
             continue;
         };
 
+        if zip {
+            continue;
+        }
+
         let f = tokio::fs::read(&converted_file_path).await.unwrap();
         let f_b64 = general_purpose::STANDARD.encode(f);
-        if ignore(SendGroupMessage::new(
-            &group_id,
-            thread_id.clone(),
-            f_b64,
-            MessageType::Image,
-            None,
-        ))
-        .query_async(seatalk)
-        .await
-        .is_err()
-        {
-            tracing::error!("Failed to send converted");
-            failed += 1;
+        let sent: Result<SentGroupMessage, _> =
+            SendGroupMessage::new(group_id, thread_id.clone(), f_b64, MessageType::Image, None)
+                .query_async(seatalk)
+                .await;
+        match sent {
+            Ok(sent) => posted.push(sent.message_id),
+            Err(e) => {
+                match e.seatalk_code() {
+                    Some(SeatalkErrorCode::BotNotInGroup | SeatalkErrorCode::GroupNotFound) => {
+                        tracing::error!("Cannot post to group {} anymore: {}", group_id, e);
+                        return Err(WebhookError::Rest(e));
+                    }
+                    _ if e.is_auth_error() => {
+                        tracing::error!("Stopped sending stickers: {}", e);
+                        return Err(WebhookError::Rest(e));
+                    }
+                    Some(SeatalkErrorCode::ImageTooLarge) => {
+                        tracing::warn!("Converted sticker {} is too large to send", file_name);
+                    }
+                    Some(SeatalkErrorCode::RateLimited) => {
+                        tracing::warn!("Rate limited while sending sticker {}", file_name);
+                    }
+                    _ => tracing::error!("Failed to send converted: {}", e),
+                }
+                failed += 1;
+            }
         }
     }
-    if failed == 0 {
-        ignore(SendGroupMessage::new(
-            &group_id,
-            thread_id.clone(),
-            "Done",
-            MessageType::Text,
-            None,
-        ))
-        .query_async(seatalk)
-        .await
-        .map_err(WebhookError::Rest)?;
+
+    let mut status = if failed == 0 {
+        "Done".to_owned()
     } else {
-        ignore(SendGroupMessage::new(
-            &group_id,
+        format!(
+            "Converted {} of {} stickers.\nSome sticker types are not supported yet ):",
+            total - failed,
+            total
+        )
+    };
+    if zip && failed < total {
+        let archive_path = temp_dir.path().join(format!("{}.zip", sticker_set.name));
+        if let Err(e) = send_zip(
+            seatalk,
+            group_id,
             thread_id.clone(),
-            format!(
-                "Converted {} of {} stickers.\nSome sticker types are not supported yet ):",
-                sticker_set.stickers.len() - failed,
-                sticker_set.stickers.len()
-            ),
-            MessageType::Text,
-            None,
-        ))
-        .query_async(seatalk)
+            &converted_dir,
+            &archive_path,
+        )
         .await
-        .map_err(WebhookError::Rest)?;
+        {
+            tracing::error!("Failed to send {} as zip: {}", sticker_set.name, e);
+            status.push_str("\nFailed to send the sticker pack as a zip file, it may be too large");
+        }
     }
+    update_card(seatalk, card_id, progress_card(sticker_set, &status, false)).await;
 
     let _ = temp_dir.cleanup();
     Ok(())
 }
+
+async fn send_zip(
+    seatalk: &AsyncSeatalk,
+    group_id: &str,
+    thread_id: Option<String>,
+    converted_dir: &Path,
+    archive_path: &Path,
+) -> Result<(), WebhookError> {
+    zip_dir(converted_dir, archive_path)?;
+    let f = tokio::fs::read(archive_path).await?;
+    let filename = archive_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or("stickers.zip".into());
+    ignore(SendGroupMessage::new_file_message(
+        group_id,
+        thread_id,
+        filename,
+        general_purpose::STANDARD.encode(f),
+        None,
+    ))
+    .query_async(seatalk)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_sticker_set_names() {
+        assert_eq!(
+            find_sticker_set_name("look https://t.me/addstickers/Cats/ so cute").as_deref(),
+            Some("Cats")
+        );
+        assert_eq!(find_sticker_set_name("https://t.me/addstickers/"), None);
+    }
+
+    #[test]
+    fn detects_received_file_formats() {
+        assert_eq!(StickerFormat::of_file(None), Some(StickerFormat::Image));
+        assert_eq!(
+            StickerFormat::of_file(Some("cat.WEBP")),
+            Some(StickerFormat::Image)
+        );
+        assert_eq!(
+            StickerFormat::of_file(Some("cat.tgs")),
+            Some(StickerFormat::Animated)
+        );
+        assert_eq!(
+            StickerFormat::of_file(Some("cat.webm")),
+            Some(StickerFormat::Video)
+        );
+        assert_eq!(StickerFormat::of_file(Some("notes.pdf")), None);
+        assert_eq!(StickerFormat::of_file(Some("README")), None);
+    }
+
+    #[test]
+    fn shows_progress_unescaped() {
+        let sticker_set: StickerSet = serde_json::from_value(json!({
+            "name": "cute_cats",
+            "title": "Cute cats",
+            "sticker_type": "regular",
+            "stickers": []
+        }))
+        .unwrap();
+        let card = serde_json::to_value(progress_card(&sticker_set, "Converting 1 of 2...", true))
+            .unwrap();
+        assert_eq!(
+            card.pointer("/elements/1/description/text"),
+            Some(&json!(
+                "Found 0 stickers in sticker set: **cute\\_cats**\nConverting 1 of 2..."
+            ))
+        );
+        assert_eq!(
+            card.pointer("/elements/2/element_type"),
+            Some(&json!("button"))
+        );
+    }
+
+    #[test]
+    fn cancels_running_conversions() {
+        let conversions = Conversions::default();
+        assert_eq!(
+            conversions.cancel("card", "e1", false),
+            Cancellation::NotRunning
+        );
+        let cancelled = conversions.start("card", "e1");
+        assert_eq!(
+            conversions.cancel("card", "e1", false),
+            Cancellation::Cancelled
+        );
+        assert!(cancelled.load(Ordering::Relaxed));
+        conversions.finish("card");
+        assert_eq!(
+            conversions.cancel("card", "e1", false),
+            Cancellation::NotRunning
+        );
+    }
+
+    #[test]
+    fn only_requesters_and_admins_cancel() {
+        let conversions = Conversions::default();
+        let cancelled = conversions.start("card", "e1");
+        assert_eq!(
+            conversions.cancel("card", "e2", false),
+            Cancellation::NotAllowed
+        );
+        assert!(!cancelled.load(Ordering::Relaxed));
+        assert_eq!(
+            conversions.cancel("card", "admin", true),
+            Cancellation::Cancelled
+        );
+        assert!(cancelled.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn card_actions_round_trip() {
+        for action in [
+            CardAction::Convert {
+                set_name: "Cats".into(),
+                zip: false,
+            },
+            CardAction::Convert {
+                set_name: "Cats".into(),
+                zip: true,
+            },
+            CardAction::Cancel,
+        ] {
+            assert_eq!(CardAction::parse(&action.value()), Some(action));
+        }
+        assert_eq!(CardAction::parse("convert:png:Cats"), None);
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    collections::HashSet,
+    path::PathBuf,
+    sync::{Arc, Mutex, RwLock},
+};
+
+/// Groups the bot converts stickers in, and the admins allowed to change them.
+///
+/// Changes are written to the optional whitelist file, so groups whitelisted or removed at
+/// runtime survive a restart. Once the file exists it replaces the configured groups.
+#[derive(Debug, Clone)]
+pub struct Whitelist {
+    admins: Arc<HashSet<String>>,
+    groups: Arc<RwLock<HashSet<String>>>,
+    file: Option<Arc<PathBuf>>,
+    /// Held while a change is saved, so changes are written in order.
+    saving: Arc<Mutex<()>>,
+}
+
+impl Whitelist {
+    pub fn new(
+        groups: impl IntoIterator<Item = impl Into<String>>,
+        admins: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            admins: Arc::new(admins.into_iter().map(Into::into).collect()),
+            groups: Arc::new(RwLock::new(groups.into_iter().map(Into::into).collect())),
+            file: None,
+            saving: Arc::default(),
+        }
+    }
+
+    /// Replaces the groups with the ones stored in `file` and stores later changes there.
+    ///
+    /// A missing file is created from the current groups on the first change.
+    pub fn with_file(mut self, file: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let file = file.into();
+        match std::fs::read(&file) {
+            Ok(data) => {
+                let groups: Vec<String> = serde_json::from_slice(&data)?;
+                *self.groups.write().unwrap() = groups.into_iter().collect();
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+        self.file = Some(Arc::new(file));
+        Ok(self)
+    }
+
+    pub fn contains(&self, group_id: &str) -> bool {
+        self.groups.read().unwrap().contains(group_id)
+    }
+
+    pub fn is_admin(&self, employee_code: &str) -> bool {
+        self.admins.contains(employee_code)
+    }
+
+    /// Whitelisted group ids, sorted.
+    pub fn groups(&self) -> Vec<String> {
+        let mut groups: Vec<_> = self.groups.read().unwrap().iter().cloned().collect();
+        groups.sort();
+        groups
+    }
+
+    /// Whitelists `group_id`, returns whether it was not whitelisted before.
+    pub async fn add(&self, group_id: &str) -> std::io::Result<bool> {
+        let group_id = group_id.to_owned();
+        self.update(move |groups| groups.insert(group_id)).await
+    }
+
+    /// Removes `group_id` from the whitelist, returns whether it was whitelisted.
+    pub async fn remove(&self, group_id: &str) -> std::io::Result<bool> {
+        let group_id = group_id.to_owned();
+        self.update(move |groups| groups.remove(&group_id)).await
+    }
+
+    /// Changes the groups with `f` and saves them if `f` returns true, off the async runtime.
+    async fn update(
+        &self,
+        f: impl FnOnce(&mut HashSet<String>) -> bool + Send + 'static,
+    ) -> std::io::Result<bool> {
+        let whitelist = self.clone();
+        tokio::task::spawn_blocking(move || {
+            let _saving = whitelist.saving.lock().unwrap();
+            let groups = {
+                let mut groups = whitelist.groups.write().unwrap();
+                if !f(&mut groups) {
+                    return Ok(false);
+                }
+                let mut groups: Vec<_> = groups.iter().cloned().collect();
+                groups.sort();
+                groups
+            };
+            whitelist.save(&groups)?;
+            Ok(true)
+        })
+        .await?
+    }
+
+    fn save(&self, groups: &[String]) -> std::io::Result<()> {
+        let Some(file) = &self.file else {
+            return Ok(());
+        };
+        std::fs::write(file.as_ref(), serde_json::to_vec_pretty(groups)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use temp_dir::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn persists_changes() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("whitelist.json");
+        let whitelist = Whitelist::new(["a"], ["admin"]).with_file(&file).unwrap();
+        assert_eq!(whitelist.groups(), ["a"]);
+        assert!(whitelist.add("b").await.unwrap());
+        assert!(!whitelist.add("b").await.unwrap());
+        assert!(whitelist.remove("a").await.unwrap());
+
+        let reloaded = Whitelist::new(Vec::<String>::new(), Vec::<String>::new())
+            .with_file(&file)
+            .unwrap();
+        assert_eq!(reloaded.groups(), ["b"]);
+        assert!(whitelist.is_admin("admin") && !reloaded.is_admin("admin"));
+
+        // Groups removed at runtime stay removed when the same config is loaded again.
+        let restarted = Whitelist::new(["a"], ["admin"]).with_file(&file).unwrap();
+        assert_eq!(restarted.groups(), ["b"]);
+    }
+}
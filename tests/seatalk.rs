@@ -0,0 +1,541 @@
+use std::time::Duration;
+
+use base64::{engine::general_purpose, Engine};
+use futures_util::TryStreamExt;
+use http::StatusCode;
+use seatalk_tgs::{
+    employees::EmployeeDirectory,
+    seatalk_api::{
+        api::{
+            common::{MessageType, MAX_FILE_SIZE},
+            error::BodyError,
+            get_group_info::GroupInfoResponse,
+            ApiError, ChatMessage, DownloadFile, EmployeeCodes, EmployeeProfiles, GetEmployeeCode,
+            GetEmployeeProfile, GetGroupInfo, GetGroupMembers, GetJoinedGroupChats, GetMessage,
+            GetThread, GroupMember, GroupMembers, InteractiveMessage, JoinedGroupChats,
+            RecallMessage, SeatalkErrorCode, SendGroupMessage, SendSubscriberMessage,
+            ThreadMessages, UpdateInteractiveMessage,
+        },
+        auth::{Auth, AuthError},
+        download, ignore, paged,
+        query::{AsyncQuery, Query, REQUEST_ID_HEADER},
+        raw,
+        retry::RetryPolicy,
+        seatalk::{RestError, SeatalkBuilder, SeatalkError},
+    },
+    testing::{FakeError, FakeSeatalk},
+};
+use serde_json::json;
+
+fn text_message(text: &str) -> SendGroupMessage {
+    SendGroupMessage::new("group", None, text, MessageType::Text, None)
+}
+
+#[tokio::test]
+async fn sends_group_message() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let seatalk = fake.builder().build_async().await.unwrap();
+
+    ignore(text_message("hello"))
+        .query_async(&seatalk)
+        .await
+        .unwrap();
+
+    let messages = fake.messages();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].endpoint, "messaging/v2/group_chat");
+    assert_eq!(messages[0].target, "group");
+    assert_eq!(messages[0].text(), Some("hello"));
+}
+
+#[tokio::test]
+async fn raw_returns_whole_response() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let seatalk = fake.builder().build_async().await.unwrap();
+
+    let rsp = raw(text_message("hello"))
+        .query_async(&seatalk)
+        .await
+        .unwrap();
+    assert_eq!(rsp.status(), StatusCode::OK);
+    assert!(rsp.headers().contains_key(REQUEST_ID_HEADER));
+    let body: serde_json::Value = serde_json::from_slice(rsp.body()).unwrap();
+    assert_eq!(body["message_id"], fake.messages()[0].message_id.as_str());
+
+    fake.inject_error("messaging/v2/group_chat", FakeError::code(7001));
+    let err = raw(text_message("hello"))
+        .query_async(&seatalk)
+        .await
+        .unwrap_err();
+    assert_eq!(err.seatalk_code(), Some(SeatalkErrorCode::BotNotInGroup));
+}
+
+#[tokio::test]
+async fn sends_file_message() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let seatalk = fake.builder().build_async().await.unwrap();
+
+    ignore(SendSubscriberMessage::new_file_message(
+        "employee",
+        "pack.zip",
+        general_purpose::STANDARD.encode(b"zip"),
+    ))
+    .query_async(&seatalk)
+    .await
+    .unwrap();
+    assert_eq!(fake.messages()[0].filename(), Some("pack.zip"));
+
+    let err = ignore(SendGroupMessage::new_file_message(
+        "group",
+        None,
+        "pack.zip",
+        general_purpose::STANDARD.encode(vec![0; MAX_FILE_SIZE + 1]),
+        None,
+    ))
+    .query_async(&seatalk)
+    .await
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        ApiError::Body {
+            source: BodyError::FileTooLarge { .. }
+        }
+    ));
+    assert_eq!(fake.messages().len(), 1);
+}
+
+#[tokio::test]
+async fn updates_and_recalls_messages() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let seatalk = fake.builder().build_async().await.unwrap();
+
+    ignore(SendGroupMessage::new_interactive_message(
+        "group",
+        None,
+        InteractiveMessage::new().title("Converting"),
+        None,
+    ))
+    .query_async(&seatalk)
+    .await
+    .unwrap();
+    let message_id = fake.messages()[0].message_id.clone();
+
+    ignore(UpdateInteractiveMessage::new(
+        &message_id,
+        InteractiveMessage::new().title("Done"),
+    ))
+    .query_async(&seatalk)
+    .await
+    .unwrap();
+    let message = &fake.messages()[0];
+    assert_eq!(message.updates, 1);
+    assert_eq!(
+        message.message["interactive_message"]["elements"][0]["title"]["text"],
+        "Done"
+    );
+
+    ignore(RecallMessage::new(&message_id))
+        .query_async(&seatalk)
+        .await
+        .unwrap();
+    assert!(fake.messages()[0].recalled);
+    let err = ignore(RecallMessage::new(&message_id))
+        .query_async(&seatalk)
+        .await
+        .unwrap_err();
+    assert_eq!(err.seatalk_code(), Some(SeatalkErrorCode::ResourceNotFound));
+}
+
+#[tokio::test]
+async fn pages_through_groups() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    fake.add_group("a", "A", &["e1", "e2", "e3"]);
+    fake.add_group("b", "B", &[]);
+    let seatalk = fake.builder().build_async().await.unwrap();
+
+    let first: JoinedGroupChats = GetJoinedGroupChats::new()
+        .page_size(1)
+        .query_async(&seatalk)
+        .await
+        .unwrap();
+    assert_eq!(first.group_ids(), ["a"]);
+    let second: JoinedGroupChats = GetJoinedGroupChats::new()
+        .page_size(1)
+        .cursor(first.next_cursor().unwrap())
+        .query_async(&seatalk)
+        .await
+        .unwrap();
+    assert_eq!(second.group_ids(), ["b"]);
+    assert_eq!(second.next_cursor(), None);
+
+    let info: GroupInfoResponse = GetGroupInfo::new("a").query_async(&seatalk).await.unwrap();
+    assert_eq!(info.group.group_name, "A");
+    assert_eq!(info.group.group_user_total, 3);
+
+    let members: GroupMembers = GetGroupMembers::new("a")
+        .page_size(2)
+        .query_async(&seatalk)
+        .await
+        .unwrap();
+    assert_eq!(members.group_user_list.len(), 2);
+    assert_eq!(
+        members.group_user_list[0].employee_code.as_deref(),
+        Some("e1")
+    );
+    assert!(members.next_cursor().is_some());
+
+    let err = GetGroupInfo::new("missing")
+        .query_async(&seatalk)
+        .await
+        .map(|_: GroupInfoResponse| ())
+        .unwrap_err();
+    assert_eq!(err.seatalk_code(), Some(SeatalkErrorCode::GroupNotFound));
+}
+
+#[tokio::test]
+async fn streams_every_page() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    fake.add_group("a", "A", &["e1", "e2", "e3"]);
+    let seatalk = fake.builder().build_async().await.unwrap();
+
+    let members: Vec<GroupMember> = paged(GetGroupMembers::new("a").page_size(2))
+        .stream(&seatalk)
+        .try_collect()
+        .await
+        .unwrap();
+    let codes: Vec<_> = members
+        .iter()
+        .map(|m| m.employee_code.as_deref().unwrap())
+        .collect();
+    assert_eq!(codes, ["e1", "e2", "e3"]);
+    assert_eq!(fake.lookups("messaging/v2/group_chat/members"), 2);
+
+    let members: Vec<GroupMember> = paged(GetGroupMembers::new("a").page_size(1))
+        .limit(2)
+        .stream(&seatalk)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(members.len(), 2);
+    assert_eq!(fake.lookups("messaging/v2/group_chat/members"), 4);
+
+    fake.inject_error("messaging/v2/group_chat/members", FakeError::code(7000));
+    let err = paged(GetGroupMembers::new("a"))
+        .stream(&seatalk)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap_err();
+    assert_eq!(err.seatalk_code(), Some(SeatalkErrorCode::GroupNotFound));
+}
+
+#[tokio::test]
+async fn stops_paging_when_cursor_does_not_advance() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let seatalk = fake.builder().build_async().await.unwrap();
+    let endpoint = "messaging/v2/group_chat/joined";
+    let page = |ids: &[&str], next_cursor: &str| json!({"joined_group_chats": {"group_id": ids}, "next_cursor": next_cursor});
+
+    fake.inject_response(endpoint, page(&["a"], "1"));
+    fake.inject_response(endpoint, page(&["b"], "1"));
+    let groups: Vec<String> = paged(GetJoinedGroupChats::new())
+        .stream(&seatalk)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(groups, ["a", "b"]);
+    assert_eq!(fake.lookups(endpoint), 2);
+
+    fake.inject_response(endpoint, page(&[], "1"));
+    fake.inject_response(endpoint, page(&["a"], ""));
+    let groups: Vec<String> = paged(GetJoinedGroupChats::new())
+        .stream(&seatalk)
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(groups.is_empty());
+    assert_eq!(fake.lookups(endpoint), 3);
+}
+
+#[tokio::test]
+async fn looks_up_employees() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    fake.add_employee("e1", "Alice");
+    let seatalk = fake.builder().build_async().await.unwrap();
+
+    let profiles: EmployeeProfiles = GetEmployeeProfile::employees(["e1", "missing"])
+        .query_async(&seatalk)
+        .await
+        .unwrap();
+    assert_eq!(profiles.employees.len(), 1);
+    assert_eq!(profiles.employees[0].name, "Alice");
+    assert_eq!(profiles.employees[0].display_name(), "Alice");
+
+    let codes: EmployeeCodes = GetEmployeeCode::seatalk_ids(["seatalk-e1", "seatalk-missing"])
+        .query_async(&seatalk)
+        .await
+        .unwrap();
+    assert_eq!(codes.get("seatalk-e1"), Some("e1"));
+    assert_eq!(codes.get("seatalk-missing"), None);
+
+    let directory = EmployeeDirectory::default();
+    let employee = directory
+        .employee_by_seatalk_id(&seatalk, "seatalk-e1")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(employee.employee_code, "e1");
+    assert_eq!(directory.describe(&seatalk, "e1").await, "Alice (e1)");
+    assert_eq!(directory.describe(&seatalk, "missing").await, "missing");
+    assert_eq!(fake.lookups("contacts/v2/profile"), 3);
+    assert_eq!(
+        fake.lookups("contacts/v2/get_employee_code_with_seatalk_id"),
+        2
+    );
+}
+
+#[tokio::test]
+async fn reads_messages_and_threads() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let root = fake.post_message("group", None, "e1", "https://t.me/addstickers/Cats");
+    fake.post_message("group", Some(&root), "e2", "cute");
+    fake.post_message("group", Some(&root), "e1", "very");
+    let seatalk = fake.builder().build_async().await.unwrap();
+
+    let message: ChatMessage = GetMessage::new(&root).query_async(&seatalk).await.unwrap();
+    assert_eq!(message.text(), Some("https://t.me/addstickers/Cats"));
+    assert_eq!(message.sender.employee_code.as_deref(), Some("e1"));
+
+    let first: ThreadMessages = GetThread::new("group", &root)
+        .page_size(2)
+        .query_async(&seatalk)
+        .await
+        .unwrap();
+    let texts: Vec<_> = first.thread_messages.iter().map(|m| m.text()).collect();
+    assert_eq!(texts, [Some("https://t.me/addstickers/Cats"), Some("cute")]);
+    let second: ThreadMessages = GetThread::new("group", &root)
+        .page_size(2)
+        .cursor(first.next_cursor().unwrap())
+        .query_async(&seatalk)
+        .await
+        .unwrap();
+    assert_eq!(second.thread_messages[0].text(), Some("very"));
+    assert_eq!(second.next_cursor(), None);
+
+    let err = GetMessage::new("missing")
+        .query_async(&seatalk)
+        .await
+        .map(|_: ChatMessage| ())
+        .unwrap_err();
+    assert_eq!(err.seatalk_code(), Some(SeatalkErrorCode::ResourceNotFound));
+}
+
+#[tokio::test]
+async fn downloads_received_files() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let url = fake.add_file(b"sticker");
+    let seatalk = fake.builder().build_async().await.unwrap();
+
+    let content = download(DownloadFile::new(&url))
+        .query_async(&seatalk)
+        .await
+        .unwrap();
+    assert_eq!(&content[..], b"sticker");
+
+    let missing = url.replace("/file/0", "/file/1");
+    let err = download(DownloadFile::new(&missing))
+        .query_async(&seatalk)
+        .await
+        .unwrap_err();
+    assert_eq!(err.seatalk_code(), Some(SeatalkErrorCode::ResourceNotFound));
+}
+
+#[tokio::test]
+async fn rejects_files_over_max_size() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let url = fake.add_file(b"sticker");
+    let seatalk = fake.builder().build_async().await.unwrap();
+
+    let err = download(DownloadFile::new(&url).max_size(6))
+        .query_async(&seatalk)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ApiError::Client {
+            source: RestError::ResponseTooLarge { max: 6 }
+        }
+    ));
+    let content = download(DownloadFile::new(&url).max_size(7))
+        .query_async(&seatalk)
+        .await
+        .unwrap();
+    assert_eq!(&content[..], b"sticker");
+
+    let builder = fake.builder();
+    tokio::task::spawn_blocking(move || {
+        let seatalk = builder.build().unwrap();
+        let err = download(DownloadFile::new(&url).max_size(6))
+            .query(&seatalk)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ApiError::Client {
+                source: RestError::ResponseTooLarge { max: 6 }
+            }
+        ));
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn rejects_invalid_secret() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let auth = Auth {
+        app_id: FakeSeatalk::APP_ID.into(),
+        app_secret: "wrong".into(),
+    };
+    let err = SeatalkBuilder::new(fake.host(), auth)
+        .insecure()
+        .build_async()
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        SeatalkError::Api {
+            source: ApiError::Auth {
+                source: AuthError::InvalidAppSecret
+            }
+        }
+    ));
+}
+
+#[tokio::test]
+async fn reuses_the_token_fetched_on_build() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let seatalk = fake.builder().build_async().await.unwrap();
+    ignore(text_message("async"))
+        .query_async(&seatalk)
+        .await
+        .unwrap();
+    assert_eq!(fake.tokens_issued(), 1);
+
+    let builder = fake.builder();
+    tokio::task::spawn_blocking(move || {
+        let seatalk = builder.build().unwrap();
+        ignore(text_message("blocking")).query(&seatalk).unwrap();
+    })
+    .await
+    .unwrap();
+    assert_eq!(fake.tokens_issued(), 2);
+}
+
+#[tokio::test]
+async fn refreshes_revoked_token_once() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let seatalk = fake.builder().build_async().await.unwrap();
+    ignore(text_message("first"))
+        .query_async(&seatalk)
+        .await
+        .unwrap();
+    let issued = fake.tokens_issued();
+
+    fake.revoke_tokens();
+    let seatalk = &seatalk;
+    let sends = (0..5).map(|i| async move {
+        ignore(text_message(&format!("message {}", i)))
+            .query_async(seatalk)
+            .await
+    });
+    for result in futures_util::future::join_all(sends).await {
+        result.unwrap();
+    }
+
+    assert_eq!(fake.tokens_issued(), issued + 1);
+    assert_eq!(fake.messages().len(), 6);
+}
+
+#[tokio::test]
+async fn retries_transient_errors() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let seatalk = fake.builder().build_async().await.unwrap();
+    fake.inject_error(
+        "messaging/v2/group_chat",
+        FakeError::status(StatusCode::TOO_MANY_REQUESTS),
+    );
+    fake.inject_error(
+        "messaging/v2/group_chat",
+        FakeError::code(101).with_retry_after(0),
+    );
+
+    ignore(text_message("hello"))
+        .query_async(&seatalk)
+        .await
+        .unwrap();
+    assert_eq!(fake.messages().len(), 1);
+
+    fake.add_group("a", "A", &["e1"]);
+    fake.inject_error(
+        "messaging/v2/group_chat/info",
+        FakeError::status(StatusCode::SERVICE_UNAVAILABLE).with_retry_after(0),
+    );
+    let info: GroupInfoResponse = GetGroupInfo::new("a").query_async(&seatalk).await.unwrap();
+    assert_eq!(info.group.group_name, "A");
+}
+
+#[tokio::test]
+async fn does_not_resend_messages_after_server_errors() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let seatalk = fake
+        .builder()
+        .retry_policy(RetryPolicy::default().with_delay(Duration::ZERO, Duration::ZERO))
+        .build_async()
+        .await
+        .unwrap();
+    fake.inject_error(
+        "messaging/v2/group_chat",
+        FakeError::status(StatusCode::INTERNAL_SERVER_ERROR).after_processing(),
+    );
+
+    ignore(text_message("hello"))
+        .query_async(&seatalk)
+        .await
+        .unwrap_err();
+    assert_eq!(fake.messages().len(), 1);
+}
+
+#[tokio::test]
+async fn does_not_retry_permanent_errors() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let seatalk = fake
+        .builder()
+        .retry_policy(RetryPolicy::default().with_delay(Duration::ZERO, Duration::ZERO))
+        .build_async()
+        .await
+        .unwrap();
+    fake.inject_error("messaging/v2/group_chat", FakeError::code(7001));
+
+    let err = ignore(text_message("hello"))
+        .query_async(&seatalk)
+        .await
+        .unwrap_err();
+    assert_eq!(err.seatalk_code(), Some(SeatalkErrorCode::BotNotInGroup));
+    assert!(err
+        .request_id()
+        .is_some_and(|id| id.starts_with("fake-request-")));
+    assert!(fake.messages().is_empty());
+}
+
+#[tokio::test]
+async fn blocking_client_sends_group_message() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let builder = fake.builder();
+    tokio::task::spawn_blocking(move || {
+        let seatalk = builder.build().unwrap();
+        ignore(text_message("hello")).query(&seatalk).unwrap();
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(fake.messages()[0].text(), Some("hello"));
+}
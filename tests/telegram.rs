@@ -0,0 +1,406 @@
+use std::{
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use axum::body::Body;
+use axum::Router;
+use http::{Request, StatusCode};
+use seatalk_tgs::{
+    app::{router, AppState},
+    convert::{convert_tgs, convert_webm, convert_webp, zip_dir},
+    dedupe::Dedupe,
+    employees::EmployeeDirectory,
+    seatalk_api::webhooks::WebhookVerifier,
+    telegram::TelegramStickerDownloader,
+    testing::{FakeError, FakeSeatalk, FakeTelegram},
+    webhook::Conversions,
+    whitelist::Whitelist,
+};
+use serde_json::{json, Value};
+use teloxide::{requests::Requester, types::StickerType};
+use temp_dir::TempDir;
+use tower::ServiceExt;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/telegram");
+const WHITELISTED_GROUP: &str = "ODI2OTIxNTk5OTQ0";
+
+/// A new event id, events are only dropped as duplicates when their id was seen before.
+fn next_event_id() -> String {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    format!("event-{}", NEXT.fetch_add(1, Ordering::Relaxed))
+}
+
+async fn downloader(fake: &FakeTelegram) -> TelegramStickerDownloader {
+    TelegramStickerDownloader::new(FakeTelegram::TOKEN, Some(fake.api_url()))
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn rejects_invalid_token() {
+    let fake = FakeTelegram::start(FIXTURES).await.unwrap();
+    assert!(
+        TelegramStickerDownloader::new("1:wrong", Some(fake.api_url()))
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn serves_sticker_sets_from_fixtures() {
+    let fake = FakeTelegram::start(FIXTURES).await.unwrap();
+    let telegram = downloader(&fake).await;
+
+    let set = telegram.get_sticker_set("TestStickers").await.unwrap();
+    assert_eq!(set.title, "Test Stickers");
+    assert_eq!(set.stickers.len(), 3);
+    assert!(!set.stickers[0].flags.is_animated && !set.stickers[0].flags.is_video);
+    assert!(set.stickers[1].flags.is_animated);
+    assert!(set.stickers[2].flags.is_video);
+
+    assert!(telegram.get_sticker_set("Missing").await.is_err());
+}
+
+#[tokio::test]
+async fn downloads_fixture_files() {
+    let fake = FakeTelegram::start(FIXTURES).await.unwrap();
+    let telegram = downloader(&fake).await;
+    let set = telegram.get_sticker_set("TestStickers").await.unwrap();
+    let dir = TempDir::new().unwrap();
+
+    for (sticker, fixture) in set.stickers.iter().zip(["01.webp", "02.tgs", "03.webm"]) {
+        let path = dir.path().join(&sticker.file.id);
+        telegram.download_sticker(sticker, &path).await.unwrap();
+        assert_eq!(
+            std::fs::read(path).unwrap(),
+            std::fs::read(Path::new(FIXTURES).join("TestStickers").join(fixture)).unwrap()
+        );
+    }
+}
+
+#[tokio::test]
+async fn serves_custom_emoji() {
+    let fake = FakeTelegram::start(FIXTURES).await.unwrap();
+    let bot = fake.bot();
+
+    let set = bot.get_sticker_set("TestEmoji").await.unwrap();
+    assert_eq!(set.kind, StickerType::CustomEmoji);
+    let stickers = bot
+        .get_custom_emoji_stickers(["TestEmoji-01".to_owned()])
+        .await
+        .unwrap();
+    assert_eq!(stickers.len(), 1);
+}
+
+#[test]
+#[ignore = "needs ffmpeg, gifsicle and lottie_to_png"]
+fn converts_fixtures() {
+    let dir = TempDir::new().unwrap();
+    let fixtures = Path::new(FIXTURES).join("TestStickers");
+    convert_webp(fixtures.join("01.webp"), dir.path().join("01.png")).unwrap();
+    convert_tgs(fixtures.join("02.tgs"), dir.path().join("02.gif")).unwrap();
+    convert_webm(fixtures.join("03.webm"), dir.path().join("03.gif")).unwrap();
+}
+
+#[test]
+fn zips_fixtures() {
+    let dir = TempDir::new().unwrap();
+    let fixtures = Path::new(FIXTURES).join("TestStickers");
+    let archive = dir.path().join("TestStickers.zip");
+    zip_dir(&fixtures, &archive).unwrap();
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(archive).unwrap()).unwrap();
+    let names: Vec<_> = archive.file_names().collect();
+    assert_eq!(names, ["01.webp", "02.tgs", "03.webm", "set.json"]);
+    let mut webp = Vec::new();
+    std::io::Read::read_to_end(&mut archive.by_name("01.webp").unwrap(), &mut webp).unwrap();
+    assert_eq!(webp, std::fs::read(fixtures.join("01.webp")).unwrap());
+}
+
+async fn app(telegram: &FakeTelegram, seatalk: &FakeSeatalk) -> Router {
+    router(AppState {
+        telegram: Arc::new(downloader(telegram).await),
+        seatalk: Arc::new(seatalk.builder().build_async().await.unwrap()),
+        verifier: WebhookVerifier::new("secret"),
+        conversions: Conversions::default(),
+        employees: EmployeeDirectory::default(),
+        dedupe: Dedupe::default(),
+        whitelist: Whitelist::new([WHITELISTED_GROUP], Vec::<String>::new()),
+    })
+}
+
+async fn post_event(app: &Router, event_type: &str, event: Value) {
+    let body = serde_json::to_vec(&json!({
+        "event_id": next_event_id(),
+        "event_type": event_type,
+        "timestamp": 1727676000,
+        "app_id": FakeSeatalk::APP_ID,
+        "event": event,
+    }))
+    .unwrap();
+    let request = Request::builder()
+        .method("POST")
+        .uri("/")
+        .header("signature", WebhookVerifier::new("secret").sign(&body))
+        .body(Body::from(body))
+        .unwrap();
+    let rsp = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(rsp.status(), StatusCode::OK);
+}
+
+async fn mention(app: &Router, plain_text: &str) {
+    mention_quoting(app, plain_text, "").await;
+}
+
+async fn mention_quoting(app: &Router, plain_text: &str, quoted_message_id: &str) {
+    let event = json!({
+        "group_id": WHITELISTED_GROUP,
+        "message": {
+            "message_id": "message",
+            "quoted_message_id": quoted_message_id,
+            "thread_id": "",
+            "sender": {"seatalk_id": "1", "employee_code": "e1", "sender_type": 1},
+            "message_sent_time": 1727676000,
+            "tag": "text",
+            "text": {"plain_text": plain_text, "mentioned_list": []}
+        }
+    });
+    post_event(app, "new_mentioned_message_received_from_group_chat", event).await;
+}
+
+async fn click(app: &Router, message_id: &str, value: &str) {
+    let event = json!({
+        "message_id": message_id,
+        "employee_code": "e1",
+        "seatalk_id": "1",
+        "value": value,
+        "group_id": WHITELISTED_GROUP,
+    });
+    post_event(app, "interactive_message_click", event).await;
+}
+
+/// Waits until the progress card at `index` shows the result of the conversion.
+async fn wait_for_result(seatalk: &FakeSeatalk, index: usize) -> String {
+    tokio::time::timeout(Duration::from_secs(30), async {
+        loop {
+            let messages = seatalk.messages();
+            if let Some(card) = messages.get(index) {
+                let elements = card.message["interactive_message"]["elements"]
+                    .as_array()
+                    .unwrap();
+                if !elements.iter().any(|e| e["element_type"] == "button") {
+                    return card.description().unwrap().to_owned();
+                }
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .unwrap()
+}
+
+fn assert_finished(result: &str) {
+    assert_eq!(result.lines().nth(1), Some("Done"), "{}", result);
+}
+
+#[tokio::test]
+#[ignore = "needs ffmpeg, gifsicle and lottie_to_png"]
+async fn converts_sticker_set_from_mention() {
+    let telegram = FakeTelegram::start(FIXTURES).await.unwrap();
+    let seatalk = FakeSeatalk::start().await.unwrap();
+    let app = app(&telegram, &seatalk).await;
+
+    mention(
+        &app,
+        "@Stickers /convert https://t.me/addstickers/TestStickers",
+    )
+    .await;
+
+    let messages = seatalk.wait_for_messages(1, Duration::from_secs(5)).await;
+    assert_eq!(messages[0].tag(), Some("interactive_message"));
+    assert!(messages[0]
+        .description()
+        .unwrap()
+        .starts_with("Found 3 stickers in sticker set: **TestStickers**\n"));
+    assert_eq!(
+        messages[0].message["interactive_message"]["elements"][1]["description"]["format"],
+        1
+    );
+    assert_finished(&wait_for_result(&seatalk, 0).await);
+    let messages = seatalk.messages();
+    assert!(messages[0].updates >= 1);
+    assert_eq!(
+        messages[1..]
+            .iter()
+            .filter(|message| message.tag() == Some("image"))
+            .count(),
+        3
+    );
+}
+
+#[tokio::test]
+async fn converts_sticker_set_from_quoted_message() {
+    let telegram = FakeTelegram::start(FIXTURES).await.unwrap();
+    let seatalk = FakeSeatalk::start().await.unwrap();
+    let app = app(&telegram, &seatalk).await;
+    let quoted = seatalk.post_message(
+        WHITELISTED_GROUP,
+        None,
+        "e2",
+        "so cute https://t.me/addstickers/TestStickers",
+    );
+
+    mention_quoting(&app, "@Stickers /convert", &quoted).await;
+
+    let messages = seatalk.wait_for_messages(1, Duration::from_secs(5)).await;
+    assert!(messages[0]
+        .description()
+        .unwrap()
+        .starts_with("Found 3 stickers in sticker set: **TestStickers**\n"));
+    wait_for_result(&seatalk, 0).await;
+}
+
+#[tokio::test]
+async fn offers_formats_for_sticker_set_url() {
+    let telegram = FakeTelegram::start(FIXTURES).await.unwrap();
+    let seatalk = FakeSeatalk::start().await.unwrap();
+    let app = app(&telegram, &seatalk).await;
+
+    mention(&app, "@Stickers https://t.me/addstickers/TestStickers").await;
+    let messages = seatalk.wait_for_messages(1, Duration::from_secs(5)).await;
+    assert_eq!(messages[0].tag(), Some("interactive_message"));
+    let values: Vec<_> = messages[0]
+        .message
+        .pointer("/interactive_message/elements/2/button_group")
+        .and_then(Value::as_array)
+        .unwrap()
+        .iter()
+        .map(|button| button["value"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        values,
+        [
+            "convert:images:TestStickers",
+            "convert:zip:TestStickers",
+            "cancel"
+        ]
+    );
+}
+
+#[tokio::test]
+#[ignore = "needs ffmpeg, gifsicle and lottie_to_png"]
+async fn sends_offered_set_as_zip() {
+    let telegram = FakeTelegram::start(FIXTURES).await.unwrap();
+    let seatalk = FakeSeatalk::start().await.unwrap();
+    let app = app(&telegram, &seatalk).await;
+
+    mention(&app, "@Stickers https://t.me/addstickers/TestStickers").await;
+    let messages = seatalk.wait_for_messages(1, Duration::from_secs(5)).await;
+    click(&app, &messages[0].message_id, "convert:zip:TestStickers").await;
+
+    assert_finished(&wait_for_result(&seatalk, 1).await);
+    assert!(seatalk
+        .messages()
+        .iter()
+        .any(|message| message.filename() == Some("TestStickers.zip")));
+}
+
+#[tokio::test]
+#[ignore = "needs ffmpeg, gifsicle and lottie_to_png"]
+async fn recalls_stickers_when_cancelled() {
+    let telegram = FakeTelegram::start(FIXTURES).await.unwrap();
+    let seatalk = FakeSeatalk::start().await.unwrap();
+    // Leaves time to cancel before the next sticker is converted.
+    seatalk.set_group_message_latency(Duration::from_secs(1));
+    let app = app(&telegram, &seatalk).await;
+
+    mention(
+        &app,
+        "@Stickers /convert https://t.me/addstickers/TestStickers",
+    )
+    .await;
+    let messages = seatalk.wait_for_messages(2, Duration::from_secs(30)).await;
+    assert_eq!(messages[1].tag(), Some("image"));
+    click(&app, &messages[0].message_id, "cancel").await;
+
+    let result = wait_for_result(&seatalk, 0).await;
+    assert_eq!(result.lines().nth(1), Some("Cancelled"), "{}", result);
+    let images: Vec<_> = seatalk
+        .messages()
+        .into_iter()
+        .filter(|message| message.tag() == Some("image"))
+        .collect();
+    assert!(!images.is_empty() && images.len() < 3);
+    assert!(images.iter().all(|image| image.recalled));
+}
+
+#[tokio::test]
+#[ignore = "needs ffmpeg, gifsicle and lottie_to_png"]
+async fn closes_progress_card_when_sending_fails() {
+    let telegram = FakeTelegram::start(FIXTURES).await.unwrap();
+    let seatalk = FakeSeatalk::start().await.unwrap();
+    // Leaves time to inject the error before the first sticker is sent.
+    seatalk.set_group_message_latency(Duration::from_millis(500));
+    let app = app(&telegram, &seatalk).await;
+
+    mention(
+        &app,
+        "@Stickers /convert https://t.me/addstickers/TestStickers",
+    )
+    .await;
+    seatalk.wait_for_messages(1, Duration::from_secs(5)).await;
+    seatalk.inject_error("messaging/v2/group_chat", FakeError::code(7001));
+
+    let result = wait_for_result(&seatalk, 0).await;
+    assert_eq!(
+        result.lines().nth(1),
+        Some("Stopped, something went wrong"),
+        "{}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn converts_offered_set_once() {
+    let telegram = FakeTelegram::start(FIXTURES).await.unwrap();
+    let seatalk = FakeSeatalk::start().await.unwrap();
+    let app = app(&telegram, &seatalk).await;
+
+    mention(&app, "@Stickers https://t.me/addstickers/TestStickers").await;
+    let offer = seatalk.wait_for_messages(1, Duration::from_secs(5)).await[0]
+        .message_id
+        .clone();
+    // Keeps the first conversion running while the button is clicked again.
+    seatalk.set_group_message_latency(Duration::from_millis(500));
+    click(&app, &offer, "convert:images:TestStickers").await;
+    click(&app, &offer, "convert:zip:TestStickers").await;
+
+    let messages = seatalk.wait_for_messages(3, Duration::from_secs(5)).await;
+    assert!(messages
+        .iter()
+        .any(|message| message.text() == Some("Already converting this sticker set")));
+    assert_eq!(
+        messages
+            .iter()
+            .filter(|message| message.tag() == Some("interactive_message"))
+            .count(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn cancels_offer() {
+    let telegram = FakeTelegram::start(FIXTURES).await.unwrap();
+    let seatalk = FakeSeatalk::start().await.unwrap();
+    let app = app(&telegram, &seatalk).await;
+
+    click(&app, "card", "cancel").await;
+    let messages = seatalk.messages();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].text(), Some("Cancelled"));
+}
@@ -0,0 +1,525 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use axum::{body::Body, Router};
+use http::{Request, StatusCode};
+use seatalk_tgs::{
+    app::{router, AppState},
+    dedupe::Dedupe,
+    employees::EmployeeDirectory,
+    seatalk_api::{
+        api::{common::MAX_FILE_SIZE, SeatalkErrorCode},
+        webhooks::WebhookVerifier,
+    },
+    telegram::TelegramStickerDownloader,
+    testing::{FakeError, FakeSeatalk},
+    webhook::Conversions,
+    whitelist::Whitelist,
+};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+const SIGNING_SECRET: &str = "signing-secret";
+const WHITELISTED_GROUP: &str = "ODI2OTIxNTk5OTQ0";
+const ADMIN: &str = "admin";
+
+/// A new event id, events are only dropped as duplicates when their id was seen before.
+fn next_event_id() -> String {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    format!("event-{}", NEXT.fetch_add(1, Ordering::Relaxed))
+}
+
+async fn app(fake: &FakeSeatalk) -> Router {
+    let seatalk = fake.builder().build_async().await.unwrap();
+    router(AppState {
+        telegram: Arc::new(TelegramStickerDownloader::from_bot(teloxide::Bot::new(
+            "unused",
+        ))),
+        seatalk: Arc::new(seatalk),
+        verifier: WebhookVerifier::new(SIGNING_SECRET),
+        conversions: Conversions::default(),
+        employees: EmployeeDirectory::default(),
+        dedupe: Dedupe::default(),
+        whitelist: Whitelist::new([WHITELISTED_GROUP], [ADMIN]),
+    })
+}
+
+fn signed_request(event: Value) -> Request<Body> {
+    let body = serde_json::to_vec(&event).unwrap();
+    Request::builder()
+        .method("POST")
+        .uri("/")
+        .header("content-type", "application/json")
+        .header(
+            "signature",
+            WebhookVerifier::new(SIGNING_SECRET).sign(&body),
+        )
+        .body(Body::from(body))
+        .unwrap()
+}
+
+fn mention(group_id: &str, plain_text: &str) -> Value {
+    json!({
+        "event_id": next_event_id(),
+        "event_type": "new_mentioned_message_received_from_group_chat",
+        "timestamp": 1727676000,
+        "app_id": FakeSeatalk::APP_ID,
+        "event": {
+            "group_id": group_id,
+            "message": {
+                "message_id": "message",
+                "quoted_message_id": "",
+                "thread_id": "",
+                "sender": {"seatalk_id": "1", "employee_code": "e1", "sender_type": 1},
+                "message_sent_time": 1727676000,
+                "tag": "text",
+                "text": {"plain_text": plain_text, "mentioned_list": []}
+            }
+        }
+    })
+}
+
+fn direct_message(employee_code: &str, content: &str) -> Value {
+    json!({
+        "event_id": next_event_id(),
+        "event_type": "message_from_bot_subscriber",
+        "timestamp": 1727676000,
+        "app_id": FakeSeatalk::APP_ID,
+        "event": {
+            "employee_code": employee_code,
+            "message": {"tag": "text", "text": {"content": content}}
+        }
+    })
+}
+
+#[tokio::test]
+async fn answers_event_verification() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let rsp = app(&fake)
+        .await
+        .oneshot(signed_request(json!({
+            "event_id": next_event_id(),
+            "event_type": "event_verification",
+            "timestamp": 1727676000,
+            "app_id": FakeSeatalk::APP_ID,
+            "event": {"seatalk_challenge": "challenge"}
+        })))
+        .await
+        .unwrap();
+
+    assert_eq!(rsp.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(rsp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body, json!({"seatalk_challenge": "challenge"}));
+}
+
+#[tokio::test]
+async fn invites_subscribers_to_group() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let rsp = app(&fake)
+        .await
+        .oneshot(signed_request(direct_message("e1", "stickers please")))
+        .await
+        .unwrap();
+
+    assert_eq!(rsp.status(), StatusCode::OK);
+    let messages = fake.messages();
+    assert_eq!(messages.len(), 2);
+    assert!(messages
+        .iter()
+        .all(|m| m.endpoint == "messaging/v2/single_chat" && m.target == "e1"));
+    assert_eq!(
+        messages[0].text(),
+        Some("Join my group to convert Telegram stickers!")
+    );
+    assert_eq!(messages[1].tag(), Some("image"));
+}
+
+#[tokio::test]
+async fn greets_subscribers_by_name() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    fake.add_employee("e1", "Alice");
+    let app = app(&fake).await;
+    for _ in 0..2 {
+        app.clone()
+            .oneshot(signed_request(direct_message("e1", "hi")))
+            .await
+            .unwrap();
+    }
+
+    let messages = fake.messages();
+    assert_eq!(
+        messages[0].text(),
+        Some("Hi Alice! Join my group to convert Telegram stickers!")
+    );
+    assert_eq!(messages[2].text(), messages[0].text());
+    assert_eq!(fake.lookups("contacts/v2/profile"), 1);
+}
+
+#[tokio::test]
+async fn invites_other_groups_to_group() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let rsp = app(&fake)
+        .await
+        .oneshot(signed_request(mention("other", "@Stickers hi")))
+        .await
+        .unwrap();
+
+    assert_eq!(rsp.status(), StatusCode::OK);
+    let messages = fake.messages();
+    assert_eq!(messages.len(), 2);
+    assert!(messages.iter().all(|m| m.target == "other"));
+}
+
+#[tokio::test]
+async fn replies_to_invalid_convert_command() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let rsp = app(&fake)
+        .await
+        .oneshot(signed_request(mention(
+            WHITELISTED_GROUP,
+            "@Stickers /convert",
+        )))
+        .await
+        .unwrap();
+
+    assert_eq!(rsp.status(), StatusCode::OK);
+    let messages = fake.wait_for_messages(1, Duration::from_secs(5)).await;
+    assert_eq!(messages[0].target, WHITELISTED_GROUP);
+    assert!(messages[0]
+        .text()
+        .unwrap()
+        .starts_with("Invalid Telegram sticker set URL"));
+}
+
+#[tokio::test]
+async fn rejects_unsigned_events() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let mut request = signed_request(mention("other", "@Stickers hi"));
+    request.headers_mut().remove("signature");
+    let rsp = app(&fake).await.oneshot(request).await.unwrap();
+
+    assert_eq!(rsp.status(), StatusCode::FORBIDDEN);
+    assert!(fake.messages().is_empty());
+}
+
+#[tokio::test]
+async fn admins_whitelist_groups_by_name() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    fake.add_group(WHITELISTED_GROUP, "Stickers", &["e1"]);
+    fake.add_group("lounge", "Lounge", &["e1", "e2"]);
+    let app = app(&fake).await;
+
+    let rsp = app
+        .clone()
+        .oneshot(signed_request(direct_message(ADMIN, "/groups")))
+        .await
+        .unwrap();
+    assert_eq!(rsp.status(), StatusCode::OK);
+    assert_eq!(
+        fake.messages()[0].text(),
+        Some("Groups I'm in:\n\\- **Stickers** `ODI2OTIxNTk5OTQ0` (whitelisted)\n\\- **Lounge** `lounge`")
+    );
+
+    app.clone()
+        .oneshot(signed_request(direct_message(ADMIN, "/whitelist lounge")))
+        .await
+        .unwrap();
+    assert_eq!(fake.messages()[1].text(), Some("Whitelisted **Lounge**"));
+
+    app.clone()
+        .oneshot(signed_request(mention("lounge", "@Stickers /convert")))
+        .await
+        .unwrap();
+    let messages = fake.wait_for_messages(3, Duration::from_secs(5)).await;
+    assert!(messages[2]
+        .text()
+        .unwrap()
+        .starts_with("Invalid Telegram sticker set URL"));
+}
+
+#[tokio::test]
+async fn routes_commands_without_mentions() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let app = app(&fake).await;
+    let mut event = mention(WHITELISTED_GROUP, "@Sticker Bot /stickers @Alice");
+    event["event"]["message"]["text"]["mentioned_list"] = json!([
+        {"username": "Sticker Bot", "seatalk_id": "1"},
+        {"username": "Alice", "seatalk_id": "2"}
+    ]);
+
+    app.clone().oneshot(signed_request(event)).await.unwrap();
+    let messages = fake.wait_for_messages(1, Duration::from_secs(5)).await;
+    assert_eq!(
+        messages[0].text(),
+        Some("Unknown command `/stickers`, send `/help` to see every command")
+    );
+
+    app.clone()
+        .oneshot(signed_request(direct_message(ADMIN, "/whitelist")))
+        .await
+        .unwrap();
+    assert!(fake.messages()[1]
+        .text()
+        .unwrap()
+        .starts_with("Missing group name\nUsage: `/whitelist <group name or id>`"));
+
+    app.clone()
+        .oneshot(signed_request(direct_message("e1", "/groups")))
+        .await
+        .unwrap();
+    let messages = fake.messages();
+    assert_eq!(messages.len(), 3);
+    assert_eq!(
+        messages[2].text(),
+        Some("Unknown command `/groups`, send `/help` to see every command")
+    );
+}
+
+#[tokio::test]
+async fn lists_commands_and_formats() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let app = app(&fake).await;
+
+    app.clone()
+        .oneshot(signed_request(direct_message("e1", "/help")))
+        .await
+        .unwrap();
+    let help = fake.messages()[0].text().unwrap().to_owned();
+    assert!(help.contains("`/convert [sticker set URL] [zip]` (mention me in a group)"));
+    assert!(help.contains("`/formats`"));
+    assert!(!help.contains("/groups"));
+
+    app.clone()
+        .oneshot(signed_request(direct_message(ADMIN, "/help")))
+        .await
+        .unwrap();
+    assert!(fake.messages()[1]
+        .text()
+        .unwrap()
+        .contains("`/whitelist <group name or id>`"));
+
+    app.clone()
+        .oneshot(signed_request(mention(
+            WHITELISTED_GROUP,
+            "@Stickers /formats",
+        )))
+        .await
+        .unwrap();
+    let messages = fake.wait_for_messages(3, Duration::from_secs(5)).await;
+    assert_eq!(messages[2].target, WHITELISTED_GROUP);
+    assert!(messages[2]
+        .text()
+        .unwrap()
+        .contains("**Animated stickers** (.tgs) to .gif"));
+}
+
+#[tokio::test]
+async fn invites_users_entering_chatroom() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let rsp = app(&fake)
+        .await
+        .oneshot(signed_request(json!({
+            "event_id": next_event_id(),
+            "event_type": "user_enter_chatroom_with_bot",
+            "timestamp": 1727676000,
+            "app_id": FakeSeatalk::APP_ID,
+            "event": {"employee_code": "e1", "seatalk_id": "1", "email": "e1@example.com"}
+        })))
+        .await
+        .unwrap();
+
+    assert_eq!(rsp.status(), StatusCode::OK);
+    let messages = fake.messages();
+    assert_eq!(messages.len(), 2);
+    assert!(messages.iter().all(|m| m.target == "e1"));
+}
+
+#[tokio::test]
+async fn acknowledges_group_membership_and_unknown_events() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let app = app(&fake).await;
+    for event in [
+        json!({
+            "event_id": next_event_id(),
+            "event_type": "bot_added_to_group_chat",
+            "timestamp": 1727676000,
+            "app_id": FakeSeatalk::APP_ID,
+            "event": {
+                "group": {"group_id": "other", "group_name": "Other", "group_settings": {}},
+                "inviter": {"seatalk_id": "1", "employee_code": "e1", "email": "e1@example.com"}
+            }
+        }),
+        json!({
+            "event_id": next_event_id(),
+            "event_type": "bot_removed_from_group_chat",
+            "timestamp": 1727676000,
+            "app_id": FakeSeatalk::APP_ID,
+            "event": {
+                "group_id": "other",
+                "remover": {"seatalk_id": "1", "employee_code": "e1", "email": "e1@example.com"}
+            }
+        }),
+        json!({
+            "event_id": next_event_id(),
+            "event_type": "new_message_received_from_thread",
+            "timestamp": 1727676000,
+            "app_id": FakeSeatalk::APP_ID,
+            "event": {
+                "group_id": WHITELISTED_GROUP,
+                "message": {
+                    "message_id": "message",
+                    "thread_id": "thread",
+                    "sender": {"seatalk_id": "1", "employee_code": "e1", "sender_type": 1},
+                    "message_sent_time": 1727676000,
+                    "tag": "text",
+                    "text": {"plain_text": "thanks"}
+                }
+            }
+        }),
+        json!({
+            "event_id": next_event_id(),
+            "event_type": "some_future_event",
+            "timestamp": 1727676000,
+            "app_id": FakeSeatalk::APP_ID,
+            "event": {"anything": true}
+        }),
+    ] {
+        let rsp = app.clone().oneshot(signed_request(event)).await.unwrap();
+        assert_eq!(rsp.status(), StatusCode::OK);
+    }
+    assert!(fake.messages().is_empty());
+}
+
+fn direct_file(employee_code: &str, url: &str, filename: &str) -> Value {
+    json!({
+        "event_id": next_event_id(),
+        "event_type": "message_from_bot_subscriber",
+        "timestamp": 1727676000,
+        "app_id": FakeSeatalk::APP_ID,
+        "event": {
+            "employee_code": employee_code,
+            "message": {"tag": "file", "file": {"content": url, "filename": filename}}
+        }
+    })
+}
+
+#[tokio::test]
+#[ignore = "needs ffmpeg"]
+async fn converts_files_sent_directly() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let webp = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/telegram/TestStickers/01.webp"
+    ))
+    .unwrap();
+    let url = fake.add_file(&webp);
+    let app = app(&fake).await;
+
+    let rsp = app
+        .oneshot(signed_request(direct_file("e1", &url, "cat.webp")))
+        .await
+        .unwrap();
+    assert_eq!(rsp.status(), StatusCode::OK);
+    let messages = fake.wait_for_messages(1, Duration::from_secs(10)).await;
+    assert_eq!(messages[0].target, "e1");
+    assert_eq!(messages[0].tag(), Some("image"), "{:?}", messages[0]);
+}
+
+#[tokio::test]
+async fn rejects_unsupported_files() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let url = fake.add_file(b"%PDF");
+    let app = app(&fake).await;
+
+    app.oneshot(signed_request(direct_file("e1", &url, "notes.pdf")))
+        .await
+        .unwrap();
+    let messages = fake.wait_for_messages(1, Duration::from_secs(5)).await;
+    assert_eq!(
+        messages[0].text(),
+        Some("I can only convert images and .webp, .tgs or .webm sticker files")
+    );
+}
+
+#[tokio::test]
+async fn rejects_large_files() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let url = fake.add_file(&vec![0; MAX_FILE_SIZE + 1]);
+    let app = app(&fake).await;
+
+    app.oneshot(signed_request(direct_file("e1", &url, "sticker.webp")))
+        .await
+        .unwrap();
+    let messages = fake.wait_for_messages(1, Duration::from_secs(5)).await;
+    assert_eq!(
+        messages[0].text(),
+        Some("Sorry, that file is too large, send files of up to 5 MB")
+    );
+}
+
+#[tokio::test]
+async fn ignores_redelivered_events() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let app = app(&fake).await;
+    let event = direct_message("e1", "hi");
+    for _ in 0..2 {
+        let rsp = app
+            .clone()
+            .oneshot(signed_request(event.clone()))
+            .await
+            .unwrap();
+        assert_eq!(rsp.status(), StatusCode::OK);
+    }
+    assert_eq!(fake.messages().len(), 2);
+}
+
+#[tokio::test]
+async fn asks_to_redeliver_events_being_handled() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    fake.set_group_message_latency(Duration::from_millis(500));
+    let app = app(&fake).await;
+    let event = mention("other-group", "@Stickers hi");
+
+    let first = tokio::spawn(app.clone().oneshot(signed_request(event.clone())));
+    fake.wait_for_messages(1, Duration::from_secs(5)).await;
+    let rsp = app
+        .clone()
+        .oneshot(signed_request(event.clone()))
+        .await
+        .unwrap();
+    assert_eq!(rsp.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+    assert_eq!(first.await.unwrap().unwrap().status(), StatusCode::OK);
+    let rsp = app.oneshot(signed_request(event)).await.unwrap();
+    assert_eq!(rsp.status(), StatusCode::OK);
+    assert_eq!(fake.messages().len(), 2);
+}
+
+#[tokio::test]
+async fn handles_events_again_after_failures() {
+    let fake = FakeSeatalk::start().await.unwrap();
+    let app = app(&fake).await;
+    let event = direct_message("e1", "hi");
+    fake.inject_error(
+        "messaging/v2/single_chat",
+        FakeError::code(SeatalkErrorCode::PermissionDenied.code()),
+    );
+
+    let rsp = app
+        .clone()
+        .oneshot(signed_request(event.clone()))
+        .await
+        .unwrap();
+    assert_eq!(rsp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    let rsp = app.oneshot(signed_request(event)).await.unwrap();
+    assert_eq!(rsp.status(), StatusCode::OK);
+    assert_eq!(fake.messages().len(), 2);
+}